@@ -1,5 +1,6 @@
-#![allow(clippy::collapsible_if, clippy::collapsible_else_if)]
+//! Thin wrapper around Ruff's own `flake8_to_ruff` module, kept as a
+//! separate binary for users who don't want to install the full `ruff` CLI
+//! just to migrate a config. See `--migrate-config` on the `ruff` binary
+//! for the same conversion built in.
 
-pub mod converter;
-mod parser;
-pub mod plugin;
+pub use ruff::flake8_to_ruff::{converter, plugin};
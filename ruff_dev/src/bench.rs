@@ -0,0 +1,129 @@
+//! Run Ruff over a corpus of real-world repositories and report timing and
+//! diagnostic-count deltas against a previous run, so performance and
+//! false-positive regressions are measurable from the crate itself.
+//!
+//! Each entry in the corpus is an already-checked-out directory (typically a
+//! shallow clone of a real project); this doesn't clone repositories itself,
+//! to avoid making the benchmark dependent on network access.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use anyhow::Result;
+use clap::Args;
+use ruff::autofix::fixer;
+use ruff::cache;
+use ruff::fs::iter_python_files;
+use ruff::linter::lint_path;
+use ruff::settings::configuration::Configuration;
+use ruff::settings::pyproject;
+use ruff::settings::Settings;
+use serde::{Deserialize, Serialize};
+
+#[derive(Args)]
+pub struct Cli {
+    /// Directories containing the repositories to benchmark. Each is linted
+    /// independently, using its own `pyproject.toml` if it has one, and
+    /// reported as its own row.
+    #[arg(required = true)]
+    corpus: Vec<PathBuf>,
+    /// Write per-`CheckCode` diagnostic counts for this run to `PATH`, for
+    /// use as `--compare-against` on a subsequent run.
+    #[arg(long)]
+    save_baseline: Option<PathBuf>,
+    /// A file written by a previous `--save-baseline` run. Diagnostic counts
+    /// per repository and `CheckCode` are diffed against it and reported.
+    #[arg(long)]
+    compare_against: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Baseline {
+    /// Repository name (its directory's file name) to diagnostic count by
+    /// `CheckCode`.
+    repos: BTreeMap<String, BTreeMap<String, usize>>,
+}
+
+pub fn main(cli: &Cli) -> Result<()> {
+    let compare_against = match &cli.compare_against {
+        Some(path) => serde_json::from_str(&fs::read_to_string(path)?)?,
+        None => Baseline::default(),
+    };
+    let mut baseline = Baseline::default();
+
+    for repo in &cli.corpus {
+        let name = repo.file_name().map_or_else(
+            || repo.to_string_lossy().to_string(),
+            |name| name.to_string_lossy().to_string(),
+        );
+
+        let project_root = pyproject::find_project_root(std::slice::from_ref(repo));
+        let pyproject_path = pyproject::find_pyproject_toml(&project_root);
+        let configuration = Configuration::from_pyproject(&pyproject_path, &project_root)?;
+        let cache_dir = configuration.cache_dir.clone();
+        let settings = Settings::from_configuration(configuration);
+
+        let paths = iter_python_files(
+            repo,
+            &settings.exclude,
+            &settings.extend_exclude,
+            settings.follow_symlinks,
+        );
+
+        let start = Instant::now();
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        let mut num_files = 0;
+        for entry in &paths {
+            let Ok(entry) = entry else { continue };
+            num_files += 1;
+            let messages = lint_path(
+                entry.path(),
+                &settings,
+                &cache::Mode::None,
+                &fixer::Mode::None,
+                &cache_dir,
+                false,
+                false,
+            )?;
+            for message in messages {
+                *counts
+                    .entry(message.kind.code().as_ref().to_string())
+                    .or_insert(0) += 1;
+            }
+        }
+        let elapsed = start.elapsed();
+        let total: usize = counts.values().sum();
+
+        println!("{name}: {num_files} file(s), {total} diagnostic(s) in {elapsed:?}");
+        if let Some(previous) = compare_against.repos.get(&name) {
+            report_diff(previous, &counts);
+        }
+
+        baseline.repos.insert(name, counts);
+    }
+
+    if let Some(path) = &cli.save_baseline {
+        fs::write(path, serde_json::to_string_pretty(&baseline)?)?;
+    }
+
+    Ok(())
+}
+
+/// Print the per-`CheckCode` diagnostic-count deltas between `previous` and
+/// `current`, skipping codes whose count hasn't changed.
+fn report_diff(previous: &BTreeMap<String, usize>, current: &BTreeMap<String, usize>) {
+    let mut codes: Vec<&String> = previous.keys().chain(current.keys()).collect();
+    codes.sort_unstable();
+    codes.dedup();
+
+    for code in codes {
+        let before = previous.get(code).copied().unwrap_or(0);
+        let after = current.get(code).copied().unwrap_or(0);
+        if before != after {
+            let delta = after as isize - before as isize;
+            println!("  {code}: {before} -> {after} ({delta:+})");
+        }
+    }
+}
@@ -52,12 +52,18 @@ pub fn main(cli: &Cli) -> Result<()> {
         .derive("Ord")
         .derive("Clone")
         .derive("Serialize")
-        .derive("Deserialize");
+        .derive("Deserialize")
+        .derive("JsonSchema");
     for prefix in prefix_to_codes.keys() {
         gen = gen.push_variant(Variant::new(prefix.to_string()));
     }
+    // `All` selects every implemented rule, rather than a code prefix, so it's
+    // appended by hand instead of being derived from `prefix_to_codes`.
+    gen.push_variant(Variant::new("All"));
 
-    // Create the `PrefixSpecificity` definition.
+    // Create the `PrefixSpecificity` definition. `All` sits below `Category`,
+    // the least specific numbered tier, so that any more targeted select or
+    // ignore always wins over it.
     scope
         .new_enum("PrefixSpecificity")
         .vis("pub")
@@ -65,6 +71,7 @@ pub fn main(cli: &Cli) -> Result<()> {
         .derive("Eq")
         .derive("PartialOrd")
         .derive("Ord")
+        .push_variant(Variant::new("All"))
         .push_variant(Variant::new("Category"))
         .push_variant(Variant::new("Hundreds"))
         .push_variant(Variant::new("Tens"))
@@ -87,6 +94,7 @@ pub fn main(cli: &Cli) -> Result<()> {
                 .join(", ")
         ));
     }
+    gen = gen.line("CheckCodePrefix::All => CheckCode::iter().collect(),");
     gen.line("}");
 
     // Create the `match` statement, to map from definition to specificity.
@@ -111,6 +119,7 @@ pub fn main(cli: &Cli) -> Result<()> {
             specificity
         ));
     }
+    gen = gen.line("CheckCodePrefix::All => PrefixSpecificity::All,");
     gen.line("}");
 
     // Construct the output contents.
@@ -118,8 +127,12 @@ pub fn main(cli: &Cli) -> Result<()> {
     output.push_str("//! File automatically generated by examples/generate_check_code_prefix.rs.");
     output.push('\n');
     output.push('\n');
+    output.push_str("use schemars::JsonSchema;");
+    output.push('\n');
     output.push_str("use serde::{{Serialize, Deserialize}};");
     output.push('\n');
+    output.push_str("use strum::IntoEnumIterator;");
+    output.push('\n');
     output.push_str("use strum_macros::EnumString;");
     output.push('\n');
     output.push('\n');
@@ -1,3 +1,4 @@
+pub mod bench;
 pub mod generate_check_code_prefix;
 pub mod generate_rules_table;
 pub mod generate_source_code;
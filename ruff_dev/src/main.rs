@@ -1,8 +1,8 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use ruff_dev::{
-    generate_check_code_prefix, generate_rules_table, generate_source_code, print_ast, print_cst,
-    print_tokens,
+    bench, generate_check_code_prefix, generate_rules_table, generate_source_code, print_ast,
+    print_cst, print_tokens,
 };
 
 #[derive(Parser)]
@@ -15,6 +15,9 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Run Ruff over a corpus of repositories and report timing and
+    /// diagnostic-count deltas against a previous run.
+    Bench(bench::Cli),
     /// Generate the `CheckCodePrefix` enum.
     GenerateCheckCodePrefix(generate_check_code_prefix::Cli),
     /// Generate a Markdown-compatible table of supported lint rules.
@@ -32,6 +35,7 @@ enum Commands {
 fn main() -> Result<()> {
     let cli = Cli::parse();
     match &cli.command {
+        Commands::Bench(args) => bench::main(args)?,
         Commands::GenerateCheckCodePrefix(args) => generate_check_code_prefix::main(args)?,
         Commands::GenerateRulesTable(args) => generate_rules_table::main(args)?,
         Commands::GenerateSourceCode(args) => generate_source_code::main(args)?,
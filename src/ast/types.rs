@@ -54,6 +54,14 @@ pub struct Scope<'a> {
     pub id: usize,
     pub kind: ScopeKind<'a>,
     pub import_starred: bool,
+    // TODO(charlie): `values` is keyed by owned `String`, so every binding
+    // allocates and hashes a fresh copy of an identifier that already exists
+    // as a `&'a str` borrowed from the source. Interning identifiers (here,
+    // in `python::typing`'s lookup tables, and in import resolution, as one
+    // shared symbol table) would let all of these compare small integers
+    // instead. Doing it well means auditing every read/write site across
+    // `check_ast.rs` for whether it holds a borrowed or owned identifier at
+    // that point, which is a bigger, riskier change than this struct alone.
     pub values: BTreeMap<String, Binding>,
 }
 
@@ -78,7 +86,7 @@ pub struct BindingContext {
 pub enum BindingKind {
     Annotation,
     Argument,
-    Assignment,
+    Assignment(BindingContext),
     Binding,
     LoopVar,
     Builtin,
@@ -86,6 +94,8 @@ pub enum BindingKind {
     Definition,
     Export(Vec<String>),
     FutureImportation,
+    Global,
+    Nonlocal,
     StarImportation,
     Importation(String, String, BindingContext),
     FromImportation(String, String, BindingContext),
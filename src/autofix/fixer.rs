@@ -2,11 +2,12 @@ use std::borrow::Cow;
 use std::collections::BTreeSet;
 
 use itertools::Itertools;
+use log::warn;
 use ropey::RopeBuilder;
 use rustpython_parser::ast::Location;
 
 use crate::ast::types::Range;
-use crate::autofix::{Fix, Patch};
+use crate::autofix::{Applicability, Fix, Patch};
 use crate::checks::Check;
 use crate::source_code_locator::SourceCodeLocator;
 
@@ -40,64 +41,157 @@ impl From<bool> for Mode {
 }
 
 /// Auto-fix errors in a file, and write the fixed source code to disk.
+///
+/// Only `Applicability::Safe` fixes are applied unless `unsafe_fixes` is set,
+/// in which case potentially behavior-changing fixes are applied too.
+///
+/// The merged output is re-parsed before it's returned, since patches are
+/// computed independently and can conflict in ways that produce invalid
+/// Python even though each patch's own `Range` is well-formed (e.g. two
+/// fixes that each rewrite a half of the same expression). If re-parsing
+/// fails, each candidate fix is re-tried in isolation against the original
+/// source to find which one is individually responsible; any such fix is
+/// dropped (and logged) and the remainder is retried. If no single fix can
+/// be blamed — the breakage only appears once fixes are combined — autofix
+/// is abandoned for the file rather than attempting a full combinatorial
+/// search.
 pub fn fix_file<'a>(
     checks: &'a mut [Check],
     locator: &'a SourceCodeLocator<'a>,
+    unsafe_fixes: bool,
 ) -> Option<Cow<'a, str>> {
-    if checks.iter().all(|check| check.fix.is_none()) {
+    let applicable = |fix: &Fix| unsafe_fixes || fix.applicability == Applicability::Safe;
+    if !checks
+        .iter()
+        .any(|check| check.fix.as_ref().map_or(false, applicable))
+    {
         return None;
     }
 
-    Some(apply_fixes(
-        checks.iter_mut().filter_map(|check| check.fix.as_mut()),
-        locator,
-    ))
+    let mut excluded: BTreeSet<usize> = BTreeSet::new();
+    loop {
+        let indices: BTreeSet<usize> = checks
+            .iter()
+            .enumerate()
+            .filter(|(i, check)| {
+                !excluded.contains(i) && check.fix.as_ref().map_or(false, applicable)
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if indices.is_empty() {
+            return None;
+        }
+
+        let contents = merge_patches(
+            indices
+                .iter()
+                .map(|&i| &checks[i].fix.as_ref().unwrap().patch),
+            locator,
+        );
+        if rustpython_parser::parser::parse_program(&contents, "<filename>").is_ok() {
+            return Some(apply_fixes(
+                checks
+                    .iter_mut()
+                    .enumerate()
+                    .filter(|(i, _)| indices.contains(i))
+                    .filter_map(|(_, check)| check.fix.as_mut()),
+                locator,
+            ));
+        }
+
+        let mut found_offender = false;
+        for &i in &indices {
+            let solo = merge_patches(
+                std::iter::once(&checks[i].fix.as_ref().unwrap().patch),
+                locator,
+            );
+            if rustpython_parser::parser::parse_program(&solo, "<filename>").is_err() {
+                warn!(
+                    "Fix for {} would produce invalid syntax; dropping it",
+                    checks[i].kind.code().as_ref()
+                );
+                excluded.insert(i);
+                found_offender = true;
+            }
+        }
+        if !found_offender {
+            warn!("Autofix would produce invalid syntax; skipping autofix for this file");
+            return None;
+        }
+    }
 }
 
-/// Apply a series of fixes.
-fn apply_fixes<'a>(
-    fixes: impl Iterator<Item = &'a mut Fix>,
-    locator: &'a SourceCodeLocator<'a>,
-) -> Cow<'a, str> {
+/// Merge a set of non-overlapping patches into the resulting file contents,
+/// without marking any `Fix` as applied. Used both to speculatively validate
+/// a candidate set of fixes (by re-parsing the result) before committing to
+/// it, and to build the final output once a set is known to be safe.
+fn merge_patches<'a>(
+    patches: impl Iterator<Item = &'a Patch>,
+    locator: &SourceCodeLocator,
+) -> String {
     let mut output = RopeBuilder::new();
     let mut last_pos: Location = Location::new(1, 0);
     let mut applied: BTreeSet<&Patch> = BTreeSet::new();
 
-    for fix in fixes.sorted_by_key(|fix| fix.patch.location) {
-        // If we already applied an identical fix as part of another correction, skip
+    for patch in patches.sorted_by_key(|patch| patch.location) {
+        // If we already applied an identical patch as part of another correction, skip
         // any re-application.
-        if applied.contains(&fix.patch) {
-            fix.applied = true;
+        if applied.contains(&patch) {
             continue;
         }
 
-        // Best-effort approach: if this fix overlaps with a fix we've already applied,
+        // Best-effort approach: if this patch overlaps with a patch we've already applied,
         // skip it.
-        if last_pos > fix.patch.location {
+        if last_pos > patch.location {
             continue;
         }
 
-        // Add all contents from `last_pos` to `fix.patch.location`.
+        // Add all contents from `last_pos` to `patch.location`.
         let slice = locator.slice_source_code_range(&Range {
             location: last_pos,
-            end_location: fix.patch.location,
+            end_location: patch.location,
         });
         output.append(&slice);
 
         // Add the patch itself.
-        output.append(&fix.patch.content);
+        output.append(&patch.content);
 
-        // Track that the fix was applied.
-        last_pos = fix.patch.end_location;
-        applied.insert(&fix.patch);
-        fix.applied = true;
+        // Track that the patch was applied.
+        last_pos = patch.end_location;
+        applied.insert(patch);
     }
 
     // Add the remaining content.
     let slice = locator.slice_source_code_at(&last_pos);
     output.append(&slice);
 
-    Cow::from(output.finish())
+    output.finish()
+}
+
+/// Apply a series of fixes, marking each as applied.
+fn apply_fixes<'a>(
+    fixes: impl Iterator<Item = &'a mut Fix>,
+    locator: &'a SourceCodeLocator<'a>,
+) -> Cow<'a, str> {
+    let mut fixes: Vec<&mut Fix> = fixes.sorted_by_key(|fix| fix.patch.location).collect();
+    let contents = merge_patches(fixes.iter().map(|fix| &fix.patch), locator);
+
+    let mut applied: BTreeSet<&Patch> = BTreeSet::new();
+    let mut last_pos: Location = Location::new(1, 0);
+    for fix in fixes.iter_mut() {
+        if applied.contains(&fix.patch) {
+            fix.applied = true;
+            continue;
+        }
+        if last_pos > fix.patch.location {
+            continue;
+        }
+        last_pos = fix.patch.end_location;
+        applied.insert(&fix.patch);
+        fix.applied = true;
+    }
+
+    Cow::from(contents)
 }
 
 #[cfg(test)]
@@ -106,7 +200,7 @@ mod tests {
     use rustpython_parser::ast::Location;
 
     use crate::autofix::fixer::apply_fixes;
-    use crate::autofix::{Fix, Patch};
+    use crate::autofix::{Applicability, Fix, Patch};
     use crate::SourceCodeLocator;
 
     #[test]
@@ -130,6 +224,7 @@ mod tests {
                 end_location: Location::new(1, 14),
             },
             applied: false,
+            applicability: Applicability::Safe,
         }];
         let locator = SourceCodeLocator::new(
             "class A(object):
@@ -156,6 +251,7 @@ mod tests {
                 end_location: Location::new(1, 15),
             },
             applied: false,
+            applicability: Applicability::Safe,
         }];
         let locator = SourceCodeLocator::new(
             "class A(object):
@@ -183,6 +279,7 @@ mod tests {
                     end_location: Location::new(1, 16),
                 },
                 applied: false,
+                applicability: Applicability::Safe,
             },
             Fix {
                 patch: Patch {
@@ -191,6 +288,7 @@ mod tests {
                     end_location: Location::new(1, 23),
                 },
                 applied: false,
+                applicability: Applicability::Safe,
             },
         ];
         let locator = SourceCodeLocator::new(
@@ -219,6 +317,7 @@ mod tests {
                     end_location: Location::new(1, 15),
                 },
                 applied: false,
+                applicability: Applicability::Safe,
             },
             Fix {
                 patch: Patch {
@@ -227,6 +326,7 @@ mod tests {
                     end_location: Location::new(1, 11),
                 },
                 applied: false,
+                applicability: Applicability::Safe,
             },
         ];
         let locator = SourceCodeLocator::new(
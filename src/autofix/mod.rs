@@ -11,10 +11,21 @@ pub struct Patch {
     pub end_location: Location,
 }
 
+/// Whether a `Fix` is guaranteed to preserve program behavior, or whether it
+/// can change it (e.g. by dropping a side-effectful expression). Unsafe
+/// fixes are still generated and reported, but `--fix` only applies them
+/// when the user opts in with `--unsafe-fixes`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Applicability {
+    Safe,
+    Unsafe,
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Fix {
     pub patch: Patch,
     pub applied: bool,
+    pub applicability: Applicability,
 }
 
 impl Fix {
@@ -26,6 +37,7 @@ impl Fix {
                 end_location: end,
             },
             applied: false,
+            applicability: Applicability::Safe,
         }
     }
 
@@ -37,6 +49,7 @@ impl Fix {
                 end_location: end,
             },
             applied: false,
+            applicability: Applicability::Safe,
         }
     }
 
@@ -48,6 +61,15 @@ impl Fix {
                 end_location: at,
             },
             applied: false,
+            applicability: Applicability::Safe,
         }
     }
+
+    /// Mark this fix as potentially behavior-changing (e.g. it may drop a
+    /// side-effectful expression), so `--fix` won't apply it unless the user
+    /// passes `--unsafe-fixes`.
+    pub fn unsafe_(mut self) -> Self {
+        self.applicability = Applicability::Unsafe;
+        self
+    }
 }
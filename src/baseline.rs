@@ -0,0 +1,75 @@
+//! Baseline files for suppressing pre-existing violations, so that CI only
+//! fails on violations introduced after the baseline was captured.
+//!
+//! Fingerprints are derived from the violated line's own (trimmed) text
+//! rather than its line number, so that unrelated edits elsewhere in the
+//! file -- which shift line numbers without changing the violation itself --
+//! don't cause a baselined violation to reappear as "new".
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::message::Message;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    fingerprints: HashSet<u64>,
+}
+
+/// Capture `messages` as a baseline and write it to `path` as JSON.
+pub fn write(path: &Path, messages: &[Message]) -> Result<()> {
+    let baseline = Baseline {
+        fingerprints: messages.iter().map(fingerprint).collect(),
+    };
+    fs::write(path, serde_json::to_string_pretty(&baseline)?)?;
+    Ok(())
+}
+
+/// Read a baseline previously written by [`write`].
+pub fn read(path: &Path) -> Result<Baseline> {
+    let contents = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+/// Drop any message whose fingerprint is already present in `baseline`.
+pub fn filter(messages: Vec<Message>, baseline: &Baseline) -> Vec<Message> {
+    messages
+        .into_iter()
+        .filter(|message| !baseline.fingerprints.contains(&fingerprint(message)))
+        .collect()
+}
+
+/// A stable identifier for a violation: derived from the file, the check,
+/// its column, and the line it was reported on, rather than the line number
+/// itself.
+fn fingerprint(message: &Message) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    message.filename.hash(&mut hasher);
+    message.kind.code().hash(&mut hasher);
+    message.location.column().hash(&mut hasher);
+    line_text(message).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The trimmed text of the line a message was reported on, read directly
+/// from the file on disk. Falls back to an empty string, rather than
+/// failing, if the file can't be re-read, since a lint run has already
+/// succeeded in reading it once.
+fn line_text(message: &Message) -> String {
+    fs::read_to_string(&message.filename)
+        .ok()
+        .and_then(|contents| {
+            contents
+                .lines()
+                .nth(message.location.row().saturating_sub(1))
+                .map(str::trim)
+                .map(str::to_string)
+        })
+        .unwrap_or_default()
+}
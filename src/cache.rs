@@ -6,7 +6,7 @@
 )]
 
 use std::collections::hash_map::DefaultHasher;
-use std::fs::{create_dir_all, File, Metadata};
+use std::fs::{create_dir_all, remove_dir_all, File, Metadata};
 use std::hash::{Hash, Hasher};
 use std::io::Write;
 use std::path::Path;
@@ -78,10 +78,6 @@ impl From<bool> for Mode {
     }
 }
 
-fn cache_dir() -> &'static str {
-    "./.ruff_cache"
-}
-
 fn cache_key(path: &Path, settings: &Settings, autofix: &fixer::Mode) -> String {
     let mut hasher = DefaultHasher::new();
     settings.hash(&mut hasher);
@@ -94,29 +90,40 @@ fn cache_key(path: &Path, settings: &Settings, autofix: &fixer::Mode) -> String
     )
 }
 
-pub fn init() -> Result<()> {
-    let gitignore_path = Path::new(cache_dir()).join(".gitignore");
+pub fn init(cache_dir: &Path) -> Result<()> {
+    let gitignore_path = cache_dir.join(".gitignore");
     if gitignore_path.exists() {
         return Ok(());
     }
-    create_dir_all(cache_dir())?;
+    create_dir_all(cache_dir)?;
     let mut file = File::create(gitignore_path)?;
     file.write_all(b"*").map_err(|e| e.into())
 }
 
+/// Remove the resolved cache directory, if it exists. Returns `true` if a
+/// directory was actually removed.
+pub fn clean(cache_dir: &Path) -> Result<bool> {
+    if !cache_dir.exists() {
+        return Ok(false);
+    }
+    remove_dir_all(cache_dir)?;
+    Ok(true)
+}
+
 pub fn get(
     path: &Path,
     metadata: &Metadata,
     settings: &Settings,
     autofix: &fixer::Mode,
     mode: &Mode,
+    cache_dir: &Path,
 ) -> Option<Vec<Message>> {
     if !mode.allow_read() {
         return None;
     };
 
     #[cfg(not(target_family = "wasm"))] // cacache needs async-std which doesn't support wasm
-    match cacache::read_sync(cache_dir(), cache_key(path, settings, autofix)) {
+    match cacache::read_sync(cache_dir, cache_key(path, settings, autofix)) {
         Ok(encoded) => match bincode::deserialize::<CheckResult>(&encoded[..]) {
             Ok(CheckResult {
                 metadata: CacheMetadata { mtime },
@@ -141,6 +148,7 @@ pub fn set(
     autofix: &fixer::Mode,
     messages: &[Message],
     mode: &Mode,
+    cache_dir: &Path,
 ) {
     if !mode.allow_write() {
         return;
@@ -155,7 +163,7 @@ pub fn set(
     };
     #[cfg(not(target_family = "wasm"))] // cacache needs async-std which doesn't support wasm
     if let Err(e) = cacache::write_sync(
-        cache_dir(),
+        cache_dir,
         cache_key(path, settings, autofix),
         bincode::serialize(&check_result).unwrap(),
     ) {
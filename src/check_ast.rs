@@ -8,7 +8,7 @@ use fnv::{FnvHashMap, FnvHashSet};
 use log::error;
 use rustpython_parser::ast::{
     Arg, Arguments, Constant, Excepthandler, ExcepthandlerKind, Expr, ExprContext, ExprKind,
-    KeywordData, Operator, Stmt, StmtKind, Suite,
+    KeywordData, Location, Operator, Stmt, StmtKind, Suite,
 };
 use rustpython_parser::parser;
 
@@ -22,23 +22,31 @@ use crate::ast::types::{
 use crate::ast::visitor::{walk_excepthandler, Visitor};
 use crate::ast::{helpers, operations, visitor};
 use crate::autofix::fixer;
+use crate::autofix::Fix;
 use crate::checks::{Check, CheckCode, CheckKind};
 use crate::docstrings::definition::{Definition, DefinitionKind, Documentable};
 use crate::python::builtins::{BUILTINS, MAGIC_GLOBALS};
 use crate::python::future::ALL_FEATURE_NAMES;
 use crate::python::typing;
 use crate::python::typing::SubscriptKind;
-use crate::settings::types::PythonVersion;
 use crate::settings::Settings;
 use crate::source_code_locator::SourceCodeLocator;
 use crate::visibility::{module_visibility, transition_scope, Modifier, Visibility, VisibleScope};
 use crate::{
     docstrings, flake8_2020, flake8_annotations, flake8_bandit, flake8_bugbear, flake8_builtins,
-    flake8_comprehensions, flake8_print, pep8_naming, pycodestyle, pydocstyle, pyflakes, pyupgrade,
+    flake8_comprehensions, flake8_print, pep8_naming, pycodestyle, pydocstyle, pyflakes, pylint,
+    pyupgrade, rules,
 };
 
 const GLOBAL_SCOPE_INDEX: usize = 0;
 
+// Note: `checks`, `scopes`, and the `deferred_*` buffers below are all
+// heap-allocated per file and dropped with the `Checker` once `check_ast`
+// returns. Arena-allocating them would only pay off if the AST they borrow
+// from were arena-allocated too, since most of the allocator pressure here
+// comes from `rustpython-parser`'s per-node `Box`/`Vec` allocations, not
+// from these buffers themselves. Doing that would mean forking the parser's
+// tree representation, which is a much bigger change than this struct.
 pub struct Checker<'a> {
     // Input data.
     path: &'a Path,
@@ -76,6 +84,9 @@ pub struct Checker<'a> {
     futures_allowed: bool,
     annotations_future_enabled: bool,
     except_handlers: Vec<Vec<String>>,
+    // Cache of first-party star-import sources resolved under `settings.src`, to
+    // avoid re-parsing the same module for every name it might have exported.
+    resolved_star_imports: FnvHashMap<String, Option<Vec<String>>>,
 }
 
 impl<'a> Checker<'a> {
@@ -117,6 +128,7 @@ impl<'a> Checker<'a> {
             futures_allowed: true,
             annotations_future_enabled: Default::default(),
             except_handlers: Default::default(),
+            resolved_star_imports: Default::default(),
         }
     }
 
@@ -153,6 +165,22 @@ impl<'a> Checker<'a> {
         self.autofix.patch() && self.in_f_string.is_none()
     }
 
+    /// Return `true` if `module_name` is a first-party module reachable under
+    /// `settings.src` whose resolved public names include `id`. Resolutions
+    /// (and misses) are cached per module for the lifetime of the `Checker`.
+    fn star_import_defines(&mut self, module_name: &str, id: &str) -> bool {
+        let public_names = self
+            .resolved_star_imports
+            .entry(module_name.to_string())
+            .or_insert_with(|| {
+                pyflakes::module_resolver::resolve_module(&self.settings.src, module_name)
+                    .and_then(|path| pyflakes::module_resolver::public_names(&path))
+            });
+        public_names
+            .as_ref()
+            .map_or(false, |names| names.iter().any(|name| name == id))
+    }
+
     /// Return `true` if the `Expr` is a reference to `typing.${target}`.
     pub fn match_typing_module(&self, expr: &Expr, target: &str) -> bool {
         match_module_member(expr, &format!("typing.{target}"), &self.from_imports)
@@ -162,6 +190,9 @@ impl<'a> Checker<'a> {
                     &format!("typing_extensions.{target}"),
                     &self.from_imports,
                 ))
+            || self.settings.typing_modules.iter().any(|module| {
+                match_module_member(expr, &format!("{module}.{target}"), &self.from_imports)
+            })
     }
 }
 
@@ -208,6 +239,11 @@ where
         match &stmt.node {
             StmtKind::Global { names } | StmtKind::Nonlocal { names } => {
                 let global_scope_id = self.scopes[GLOBAL_SCOPE_INDEX].id;
+                let kind = if matches!(stmt.node, StmtKind::Global { .. }) {
+                    BindingKind::Global
+                } else {
+                    BindingKind::Nonlocal
+                };
 
                 let current_scope = self.current_scope();
                 let current_scope_id = current_scope.id;
@@ -217,7 +253,7 @@ where
                             scope.values.insert(
                                 name.to_string(),
                                 Binding {
-                                    kind: BindingKind::Assignment,
+                                    kind: kind.clone(),
                                     used: Some((global_scope_id, Range::from_located(stmt))),
                                     range: Range::from_located(stmt),
                                 },
@@ -330,7 +366,7 @@ where
                 }
 
                 if self.settings.enabled.contains(&CheckCode::U011)
-                    && self.settings.target_version >= PythonVersion::Py38
+                    && self.settings.target_version >= CheckCode::U011.minimum_version().unwrap()
                 {
                     pyupgrade::plugins::unnecessary_lru_cache_params(self, decorator_list);
                 }
@@ -348,6 +384,37 @@ where
                     );
                 }
 
+                if self.settings.enabled.contains(&CheckCode::RUF005) {
+                    rules::checks::implicit_optional(self, args);
+                }
+
+                if self.settings.enabled.contains(&CheckCode::RUF010) {
+                    let max_cognitive_complexity = self.settings.max_cognitive_complexity;
+                    rules::checks::function_is_too_complex(
+                        self,
+                        stmt,
+                        name,
+                        body,
+                        max_cognitive_complexity,
+                    );
+                }
+
+                if self.settings.enabled.contains(&CheckCode::PLR0913) {
+                    pylint::plugins::too_many_arguments(self, stmt, args);
+                }
+                if self.settings.enabled.contains(&CheckCode::PLR0911) {
+                    pylint::plugins::too_many_return_statements(self, stmt, body);
+                }
+                if self.settings.enabled.contains(&CheckCode::PLR0912) {
+                    pylint::plugins::too_many_branches(self, stmt, body);
+                }
+                if self.settings.enabled.contains(&CheckCode::PLR0915) {
+                    pylint::plugins::too_many_statements(self, stmt, body);
+                }
+                if self.settings.enabled.contains(&CheckCode::PLR1702) {
+                    pylint::plugins::too_many_nested_blocks(self, stmt, body);
+                }
+
                 self.check_builtin_shadowing(name, Range::from_located(stmt), true);
 
                 // Visit the decorators and arguments, but avoid the body, which will be
@@ -452,6 +519,17 @@ where
                     flake8_bugbear::plugins::useless_expression(self, body);
                 }
 
+                if self.settings.enabled.contains(&CheckCode::RUF004) {
+                    self.add_checks(
+                        rules::checks::mutable_dataclass_default(
+                            decorator_list,
+                            body,
+                            &self.from_imports,
+                        )
+                        .into_iter(),
+                    );
+                }
+
                 self.check_builtin_shadowing(name, Range::from_located(stmt), false);
 
                 for expr in bases {
@@ -480,6 +558,12 @@ where
                     }
                 }
 
+                if self.settings.enabled.contains(&CheckCode::RUF007) {
+                    for alias in names {
+                        rules::checks::unresolved_import(self, stmt, &alias.node.name, None);
+                    }
+                }
+
                 for alias in names {
                     if alias.node.name.contains('.') && alias.node.asname.is_none() {
                         // Given `import foo.bar`, `name` would be "foo", and `full_name` would be
@@ -602,6 +686,12 @@ where
                     }
                 }
 
+                if self.settings.enabled.contains(&CheckCode::RUF007) {
+                    if let Some(module) = module {
+                        rules::checks::unresolved_import(self, stmt, module, *level);
+                    }
+                }
+
                 if let Some("__future__") = module.as_deref() {
                     if self.settings.enabled.contains(&CheckCode::U010) {
                         pyupgrade::plugins::unnecessary_future_import(self, stmt, names);
@@ -811,6 +901,14 @@ where
                 if self.settings.enabled.contains(&CheckCode::F634) {
                     pyflakes::plugins::if_tuple(self, stmt, test);
                 }
+                if self.settings.enabled.contains(&CheckCode::PLR0916) {
+                    pylint::plugins::too_many_boolean_expressions(self, stmt, test);
+                }
+            }
+            StmtKind::While { test, .. } => {
+                if self.settings.enabled.contains(&CheckCode::PLR0916) {
+                    pylint::plugins::too_many_boolean_expressions(self, stmt, test);
+                }
             }
             StmtKind::Assert { test, msg } => {
                 if self.settings.enabled.contains(&CheckCode::F631) {
@@ -887,6 +985,9 @@ where
                 if self.settings.enabled.contains(&CheckCode::B015) {
                     flake8_bugbear::plugins::useless_comparison(self, value)
                 }
+                if self.settings.enabled.contains(&CheckCode::RUF006) {
+                    rules::checks::asyncio_dangling_task(self, value);
+                }
             }
             _ => {}
         }
@@ -1014,7 +1115,7 @@ where
             ExprKind::Subscript { value, slice, .. } => {
                 // Ex) typing.List[...]
                 if self.settings.enabled.contains(&CheckCode::U007)
-                    && self.settings.target_version >= PythonVersion::Py39
+                    && self.settings.target_version >= CheckCode::U007.minimum_version().unwrap()
                 {
                     pyupgrade::plugins::use_pep604_annotation(self, expr, value, slice);
                 }
@@ -1052,8 +1153,12 @@ where
                     ExprContext::Load => {
                         // Ex) List[...]
                         if self.settings.enabled.contains(&CheckCode::U006)
-                            && self.settings.target_version >= PythonVersion::Py39
-                            && typing::is_pep585_builtin(expr, &self.from_imports)
+                            && self.settings.target_version >= CheckCode::U006.minimum_version().unwrap()
+                            && typing::is_pep585_builtin(
+                                expr,
+                                &self.from_imports,
+                                &self.settings.typing_modules,
+                            )
                         {
                             pyupgrade::plugins::use_pep585_annotation(self, expr, id);
                         }
@@ -1084,8 +1189,12 @@ where
             ExprKind::Attribute { attr, .. } => {
                 // Ex) typing.List[...]
                 if self.settings.enabled.contains(&CheckCode::U006)
-                    && self.settings.target_version >= PythonVersion::Py39
-                    && typing::is_pep585_builtin(expr, &self.from_imports)
+                    && self.settings.target_version >= CheckCode::U006.minimum_version().unwrap()
+                    && typing::is_pep585_builtin(
+                        expr,
+                        &self.from_imports,
+                        &self.settings.typing_modules,
+                    )
                 {
                     pyupgrade::plugins::use_pep585_annotation(self, expr, attr);
                 }
@@ -1365,7 +1474,7 @@ where
 
                 // pyupgrade
                 if self.settings.enabled.contains(&CheckCode::U002)
-                    && self.settings.target_version >= PythonVersion::Py310
+                    && self.settings.target_version >= CheckCode::U002.minimum_version().unwrap()
                 {
                     pyupgrade::plugins::unnecessary_abspath(self, expr, func, args);
                 }
@@ -1443,8 +1552,16 @@ where
                 let check_not_is = self.settings.enabled.contains(&CheckCode::E714);
                 if check_not_in || check_not_is {
                     self.add_checks(
-                        pycodestyle::checks::not_tests(op, operand, check_not_in, check_not_is)
-                            .into_iter(),
+                        pycodestyle::checks::not_tests(
+                            expr,
+                            op,
+                            operand,
+                            check_not_in,
+                            check_not_is,
+                            self.locator,
+                            self.patch(),
+                        )
+                        .into_iter(),
                     );
                 }
 
@@ -1467,6 +1584,8 @@ where
                             comparators,
                             check_none_comparisons,
                             check_true_false_comparisons,
+                            self.locator,
+                            self.patch(),
                         )
                         .into_iter(),
                     );
@@ -1705,7 +1824,11 @@ where
                     visitor::walk_expr(self, expr);
                 } else {
                     self.in_subscript = true;
-                    match typing::match_annotated_subscript(value, &self.from_imports) {
+                    match typing::match_annotated_subscript(
+                        value,
+                        &self.from_imports,
+                        &self.settings.typing_modules,
+                    ) {
                         Some(subscript) => {
                             match subscript {
                                 // Ex) Optional[int]
@@ -1826,10 +1949,19 @@ where
                         } {
                             if binding.used.is_none() {
                                 if self.settings.enabled.contains(&CheckCode::F841) {
-                                    self.add_check(Check::new(
+                                    let mut check = Check::new(
                                         CheckKind::UnusedVariable(name.to_string()),
                                         Range::from_located(excepthandler),
-                                    ));
+                                    );
+                                    if self.patch() {
+                                        if let Some((removal, rename)) =
+                                            except_handler_name_fixes(self.locator, excepthandler.location, name)
+                                        {
+                                            check.amend(removal);
+                                            check.amend_alternative(rename);
+                                        }
+                                    }
+                                    self.add_check(check);
                                 }
                             }
                         }
@@ -1899,9 +2031,11 @@ where
         }
 
         if self.settings.enabled.contains(&CheckCode::N803) {
-            if let Some(check) =
-                pep8_naming::checks::invalid_argument_name(&arg.node.arg, Range::from_located(arg))
-            {
+            if let Some(check) = pep8_naming::checks::invalid_argument_name(
+                &arg.node.arg,
+                Range::from_located(arg),
+                &self.settings.pep8_naming,
+            ) {
                 self.add_check(check);
             }
         }
@@ -1910,6 +2044,54 @@ where
     }
 }
 
+/// Candidate fixes for an unused `except ... as {name}:` binding: deleting
+/// the `as {name}` clause entirely (the primary fix, since the binding is
+/// provably unused), and renaming `name` to `_{name}` in place (an
+/// alternative that keeps the binding around, e.g. for a reader who finds
+/// the original name documenting intent). Returns `None` if the `as {name}`
+/// text can't be found on the handler's header line, which shouldn't happen
+/// for valid syntax but isn't worth panicking over.
+fn except_handler_name_fixes(
+    locator: &SourceCodeLocator<'_>,
+    handler_location: Location,
+    name: &str,
+) -> Option<(Fix, Fix)> {
+    let header = locator.slice_source_code_at(&handler_location);
+    let header = header.lines().next()?;
+    let needle = format!("as {name}");
+    let offset = header.find(&needle)?;
+    let row = handler_location.row();
+    let as_start = handler_location.column() + offset;
+    let as_end = as_start + needle.len();
+
+    let removal = Fix::deletion(
+        Location::new(row, handler_location.column() + header[..offset].trim_end().len()),
+        Location::new(row, as_end),
+    );
+    let name_start = as_start + "as ".len();
+    let rename = Fix::replacement(
+        format!("_{name}"),
+        Location::new(row, name_start),
+        Location::new(row, name_start + name.len()),
+    );
+    Some((removal, rename))
+}
+
+/// Returns `true` if `kind` is a `def`, `class`, or `import` binding — the
+/// forms of redefinition that F811 ("redefinition of unused name") cares
+/// about, as opposed to a plain reassignment.
+fn is_redefinable_definition(kind: &BindingKind) -> bool {
+    matches!(
+        kind,
+        BindingKind::Definition
+            | BindingKind::ClassDefinition
+            | BindingKind::Importation(_, _, _)
+            | BindingKind::FromImportation(_, _, _)
+            | BindingKind::SubmoduleImportation(_, _, _)
+            | BindingKind::FutureImportation
+    )
+}
+
 fn try_mark_used(scope: &mut Scope, scope_id: usize, id: &str, expr: &Expr) -> bool {
     let alias = if let Some(binding) = scope.values.get_mut(id) {
         // Mark the binding as used.
@@ -2000,12 +2182,34 @@ impl<'a> Checker<'a> {
                 },
             );
         }
+        for builtin in &self.settings.builtins {
+            scope.values.insert(
+                builtin.clone(),
+                Binding {
+                    kind: BindingKind::Builtin,
+                    range: Default::default(),
+                    used: None,
+                },
+            );
+        }
     }
 
     pub fn current_scope(&self) -> &Scope {
         &self.scopes[*(self.scope_stack.last().expect("No current scope found."))]
     }
 
+    /// Returns `true` if the file under check is a `.pyi` stub, where
+    /// conventions like omitting docstrings don't carry the same meaning
+    /// they do in a regular module.
+    pub fn is_stub_file(&self) -> bool {
+        self.path.extension().map_or(false, |ext| ext == "pyi")
+    }
+
+    /// The path of the file currently under analysis.
+    pub fn path(&self) -> &Path {
+        self.path
+    }
+
     pub fn current_parent(&self) -> &'a Stmt {
         self.parents[*(self.parent_stack.last().expect("No parent found."))]
     }
@@ -2034,13 +2238,35 @@ impl<'a> Checker<'a> {
                             | BindingKind::FutureImportation
                     )
                 {
-                    self.add_check(Check::new(
+                    let mut check = Check::new(
                         CheckKind::ImportShadowedByLoopVar(
                             name.clone(),
                             existing.range.location.row(),
                         ),
                         binding.range,
-                    ));
+                    );
+                    check.annotate("shadowed import", existing.range);
+                    self.add_check(check);
+                }
+            }
+        }
+
+        if self.settings.enabled.contains(&CheckCode::F811) {
+            let scope = &self.scopes[*(self.scope_stack.last().expect("No current scope found."))];
+            if let Some(existing) = scope.values.get(&name) {
+                if existing.used.is_none()
+                    && is_redefinable_definition(&existing.kind)
+                    && is_redefinable_definition(&binding.kind)
+                {
+                    let mut check = Check::new(
+                        CheckKind::RedefinedWhileUnused(
+                            name.clone(),
+                            existing.range.location.row(),
+                        ),
+                        binding.range,
+                    );
+                    check.annotate("first definition here", existing.range);
+                    self.add_check(check);
                 }
             }
         }
@@ -2101,10 +2327,18 @@ impl<'a> Checker<'a> {
                     }
                     from_list.sort();
 
-                    self.add_check(Check::new(
-                        CheckKind::ImportStarUsage(id.clone(), from_list),
-                        Range::from_located(expr),
-                    ));
+                    // If we can resolve one of the star-imported modules as a
+                    // first-party file under `src`, and it genuinely exports `id`,
+                    // treat the name as defined rather than merely possible.
+                    if !from_list
+                        .iter()
+                        .any(|module_name| self.star_import_defines(module_name, id))
+                    {
+                        self.add_check(Check::new(
+                            CheckKind::ImportStarUsage(id.clone(), from_list),
+                            Range::from_located(expr),
+                        ));
+                    }
                 }
                 return;
             }
@@ -2146,9 +2380,12 @@ impl<'a> Checker<'a> {
             if self.settings.enabled.contains(&CheckCode::N806) {
                 let current =
                     &self.scopes[*(self.scope_stack.last().expect("No current scope found."))];
-                if let Some(check) =
-                    pep8_naming::checks::non_lowercase_variable_in_function(current, expr, id)
-                {
+                if let Some(check) = pep8_naming::checks::non_lowercase_variable_in_function(
+                    current,
+                    expr,
+                    id,
+                    &self.settings.pep8_naming,
+                ) {
                     self.add_check(check);
                 }
             }
@@ -2238,7 +2475,7 @@ impl<'a> Checker<'a> {
             self.add_binding(
                 id.to_string(),
                 Binding {
-                    kind: BindingKind::Assignment,
+                    kind: BindingKind::Assignment(self.binding_context()),
                     used: None,
                     range: Range::from_located(expr),
                 },
@@ -2368,13 +2605,33 @@ impl<'a> Checker<'a> {
     fn check_deferred_assignments(&mut self) {
         if self.settings.enabled.contains(&CheckCode::F841) {
             while let Some(index) = self.deferred_assignments.pop() {
-                self.add_checks(
-                    pyflakes::checks::unused_variables(
-                        &self.scopes[index],
-                        &self.settings.dummy_variable_rgx,
-                    )
-                    .into_iter(),
-                );
+                let scope = &self.scopes[index];
+                let mut checks = pyflakes::checks::unused_variables(scope, &self.settings.dummy_variable_rgx);
+                if self.patch() {
+                    for check in &mut checks {
+                        let CheckKind::UnusedVariable(name) = &check.kind else {
+                            continue;
+                        };
+                        let Some(BindingKind::Assignment(context)) =
+                            scope.values.get(name).map(|binding| &binding.kind)
+                        else {
+                            continue;
+                        };
+                        let child = self.parents[context.defined_by];
+                        let parent = context.defined_in.map(|defined_in| self.parents[defined_in]);
+                        match pyflakes::fixes::remove_unused_variable(
+                            self.locator,
+                            child,
+                            parent,
+                            &[],
+                        ) {
+                            Ok(Some(fix)) => check.amend(fix),
+                            Ok(None) => {}
+                            Err(e) => error!("Failed to remove unused variable: {}", e),
+                        }
+                    }
+                }
+                self.add_checks(checks.into_iter());
             }
         }
     }
@@ -2477,57 +2734,98 @@ impl<'a> Checker<'a> {
                     }
                 }
 
-                for ((kind, defined_by, defined_in), full_names) in unused {
-                    let child = self.parents[defined_by];
-                    let parent = defined_in.map(|defined_in| self.parents[defined_in]);
-
-                    let fix = if self.patch() {
-                        let deleted: Vec<&Stmt> = self
-                            .deletions
-                            .iter()
-                            .map(|index| self.parents[*index])
-                            .collect();
-                        match match kind {
-                            ImportKind::Import => pyflakes::fixes::remove_unused_imports,
-                            ImportKind::ImportFrom => pyflakes::fixes::remove_unused_import_froms,
-                        }(
-                            self.locator, &full_names, child, parent, &deleted
-                        ) {
-                            Ok(fix) => {
-                                if fix.patch.content.is_empty() || fix.patch.content == "pass" {
-                                    self.deletions.insert(defined_by);
+                let is_init = self.path.ends_with("__init__.py");
+                if is_init && self.settings.ignore_init_module_imports {
+                    // `__init__.py` is conventionally used to re-export
+                    // names for a package's public API; don't flag those
+                    // imports as unused at all under this setting.
+                } else {
+                    for ((kind, defined_by, defined_in), full_names) in unused {
+                        let child = self.parents[defined_by];
+                        let parent = defined_in.map(|defined_in| self.parents[defined_in]);
+
+                        if is_init {
+                            let mut check = Check::new(
+                                CheckKind::UnusedImport(
+                                    full_names.iter().map(|name| name.to_string()).collect(),
+                                    true,
+                                ),
+                                Range::from_located(child),
+                            );
+                            if self.patch() {
+                                // Rather than deleting what may be a
+                                // deliberate re-export, offer to make the
+                                // re-export explicit, which silences F401
+                                // on a subsequent run.
+                                let fix = match kind {
+                                    ImportKind::Import => {
+                                        pyflakes::fixes::add_redundant_aliases(
+                                            self.locator,
+                                            &full_names,
+                                            child,
+                                        )
+                                    }
+                                    ImportKind::ImportFrom => {
+                                        pyflakes::fixes::add_redundant_aliases_from(
+                                            self.locator,
+                                            &full_names,
+                                            child,
+                                        )
+                                    }
+                                };
+                                match fix {
+                                    Ok(fix) => check.amend(fix),
+                                    Err(e) => error!(
+                                        "Failed to add redundant import aliases: {}",
+                                        e
+                                    ),
                                 }
-                                Some(fix)
                             }
-                            Err(e) => {
-                                error!("Failed to remove unused imports: {}", e);
+                            checks.push(check);
+                        } else {
+                            let fix = if self.patch() {
+                                let deleted: Vec<&Stmt> = self
+                                    .deletions
+                                    .iter()
+                                    .map(|index| self.parents[*index])
+                                    .collect();
+                                match match kind {
+                                    ImportKind::Import => pyflakes::fixes::remove_unused_imports,
+                                    ImportKind::ImportFrom => {
+                                        pyflakes::fixes::remove_unused_import_froms
+                                    }
+                                }(
+                                    self.locator, &full_names, child, parent, &deleted
+                                ) {
+                                    Ok(fix) => {
+                                        if fix.patch.content.is_empty()
+                                            || fix.patch.content == "pass"
+                                        {
+                                            self.deletions.insert(defined_by);
+                                        }
+                                        Some(fix)
+                                    }
+                                    Err(e) => {
+                                        error!("Failed to remove unused imports: {}", e);
+                                        None
+                                    }
+                                }
+                            } else {
                                 None
-                            }
-                        }
-                    } else {
-                        None
-                    };
+                            };
 
-                    if self.path.ends_with("__init__.py") {
-                        checks.push(Check::new(
-                            CheckKind::UnusedImport(
-                                full_names.into_iter().map(String::from).collect(),
-                                true,
-                            ),
-                            Range::from_located(child),
-                        ));
-                    } else {
-                        let mut check = Check::new(
-                            CheckKind::UnusedImport(
-                                full_names.into_iter().map(String::from).collect(),
-                                false,
-                            ),
-                            Range::from_located(child),
-                        );
-                        if let Some(fix) = fix {
-                            check.amend(fix);
+                            let mut check = Check::new(
+                                CheckKind::UnusedImport(
+                                    full_names.into_iter().map(String::from).collect(),
+                                    false,
+                                ),
+                                Range::from_located(child),
+                            );
+                            if let Some(fix) = fix {
+                                check.amend(fix);
+                            }
+                            checks.push(check);
                         }
-                        checks.push(check);
                     }
                 }
             }
@@ -2557,7 +2855,10 @@ impl<'a> Checker<'a> {
             if !pydocstyle::plugins::not_empty(self, &definition) {
                 continue;
             }
-            if !pydocstyle::plugins::not_missing(self, &definition, &visibility) {
+            // Stub files conventionally omit docstrings; don't require them.
+            if !self.is_stub_file()
+                && !pydocstyle::plugins::not_missing(self, &definition, &visibility)
+            {
                 continue;
             }
             if self.settings.enabled.contains(&CheckCode::D200) {
@@ -2643,6 +2944,7 @@ impl<'a> Checker<'a> {
                     name,
                     location,
                     flake8_builtins::types::ShadowingType::Attribute,
+                    &self.settings.builtins,
                 ) {
                     self.add_check(check);
                 }
@@ -2653,6 +2955,7 @@ impl<'a> Checker<'a> {
                     name,
                     location,
                     flake8_builtins::types::ShadowingType::Variable,
+                    &self.settings.builtins,
                 ) {
                     self.add_check(check);
                 }
@@ -2666,6 +2969,7 @@ impl<'a> Checker<'a> {
                 name,
                 location,
                 flake8_builtins::types::ShadowingType::Argument,
+                &self.settings.builtins,
             ) {
                 self.add_check(check);
             }
@@ -1,6 +1,6 @@
 //! Lint rules based on checking raw physical lines.
 
-use nohash_hasher::IntMap;
+use nohash_hasher::{IntMap, IntSet};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rustpython_parser::ast::Location;
@@ -16,19 +16,79 @@ use crate::settings::Settings;
 static CODING_COMMENT_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^[ \t\f]*#.*?coding[:=][ \t]*utf-?8").expect("Invalid regex"));
 
+/// The visual width of `line`, expanding each tab to the next `tab_size`-
+/// column stop rather than counting it as a single column, so `E501` reports
+/// how wide a tab-indented line actually renders.
+fn expanded_line_length(line: &str, tab_size: usize) -> usize {
+    let mut width = 0;
+    for char in line.chars() {
+        if char == '\t' && tab_size > 0 {
+            width += tab_size - (width % tab_size);
+        } else {
+            width += 1;
+        }
+    }
+    width
+}
+
 /// Whether the given line is too long and should be reported.
+///
+/// Lines that overflow the limit because of a single unsplittable token
+/// (e.g. a long URL in a comment) are exempted, mirroring pycodestyle: if
+/// removing that trailing token still wouldn't bring the line under the
+/// limit, splitting it wouldn't have helped anyway.
 fn should_enforce_line_length(line: &str, length: usize, limit: usize) -> bool {
-    if length > limit {
-        let mut chunks = line.split_whitespace();
-        if let (Some(first), Some(_)) = (chunks.next(), chunks.next()) {
-            // Do not enforce the line length for commented lines with a single word
-            !(first == "#" && chunks.next().is_none())
-        } else {
-            // Single word / no printable chars - no way to make the line shorter
-            false
+    if length <= limit {
+        return false;
+    }
+
+    let mut chunks = line.split_whitespace();
+    match (chunks.next(), chunks.next()) {
+        (Some(chunk), None) => {
+            // The line is a single word (e.g. a bare URL).
+            length - chunk.chars().count() < limit.saturating_sub(7)
+        }
+        (Some("#"), Some(chunk)) if chunks.next().is_none() => {
+            // A comment consisting of a single word.
+            length - chunk.chars().count() < limit.saturating_sub(7)
         }
+        (Some(_), Some(_)) => true,
+        (None, _) => false,
+    }
+}
+
+/// Extract the `# noqa` directive on `line`, or pretend there isn't one when
+/// `--ignore-noqa` is in effect (used to audit how much would be suppressed).
+fn noqa_directive_for(line: &str, ignore_noqa: bool) -> Directive {
+    if ignore_noqa {
+        Directive::None
     } else {
-        false
+        noqa::extract_noqa_directive(line)
+    }
+}
+
+/// Add `check` to `line_checks`, unless it's suppressed by a `noqa` directive on its line.
+fn push_blank_lines_check<'a>(
+    check: Check,
+    lines: &[&'a str],
+    noqa_lineno: usize,
+    ignore_noqa: bool,
+    noqa_directives: &mut IntMap<usize, (Directive, Vec<&'a str>)>,
+    line_checks: &mut Vec<Check>,
+) {
+    let noqa = noqa_directives
+        .entry(noqa_lineno)
+        .or_insert_with(|| (noqa_directive_for(lines[noqa_lineno], ignore_noqa), vec![]));
+    match noqa {
+        (Directive::All(..), matches) => matches.push(check.kind.code().as_ref()),
+        (Directive::Codes(_, _, codes), matches) => {
+            if codes.contains(&check.kind.code().as_ref()) {
+                matches.push(check.kind.code().as_ref());
+            } else {
+                line_checks.push(check);
+            }
+        }
+        (Directive::None, _) => line_checks.push(check),
     }
 }
 
@@ -36,17 +96,49 @@ pub fn check_lines(
     checks: &mut Vec<Check>,
     contents: &str,
     noqa_line_for: &IntMap<usize, usize>,
+    fmt_exclusions: &IntSet<usize>,
     settings: &Settings,
     autofix: &fixer::Mode,
+    ignore_noqa: bool,
 ) {
     let enforce_unnecessary_coding_comment = settings.enabled.contains(&CheckCode::U009);
     let enforce_line_too_long = settings.enabled.contains(&CheckCode::E501);
-    let enforce_noqa = settings.enabled.contains(&CheckCode::M001);
+    let enforce_tab_indentation = settings.enabled.contains(&CheckCode::W191);
+    let enforce_mixed_spaces_and_tabs = settings.enabled.contains(&CheckCode::E101);
+    let enforce_blank_lines = settings.enabled.contains(&CheckCode::E301)
+        || settings.enabled.contains(&CheckCode::E302)
+        || settings.enabled.contains(&CheckCode::E303)
+        || settings.enabled.contains(&CheckCode::E304)
+        || settings.enabled.contains(&CheckCode::E305);
+    let enforce_noqa = settings.enabled.contains(&CheckCode::RUF100);
+
+    // Don't let the blank-line fixes touch a `# fmt: off` block any more than
+    // Black would.
+    let patch_blank_lines =
+        |lineno: usize| autofix.patch() && !fmt_exclusions.contains(&(lineno + 1));
 
     let mut noqa_directives: IntMap<usize, (Directive, Vec<&str>)> = IntMap::default();
     let mut line_checks = vec![];
     let mut ignored = vec![];
 
+    // The indentation character used by the first indented line in the file, against
+    // which all subsequent indentation is compared (mirroring pycodestyle, which infers
+    // this from the file itself rather than the configured indent style).
+    let mut indent_char: Option<char> = None;
+
+    // State for the blank-line rules (E301-E305), tracking the previous non-blank,
+    // non-comment ("logical") line, since these rules only care about the layout
+    // between statements, not raw physical lines.
+    let mut blank_lines: usize = 0;
+    let mut prev_indent: Option<usize> = None;
+    let mut prev_is_class_header = false;
+    let mut prev_was_decorator = false;
+    let mut saw_toplevel_block = false;
+    // Stack of (body indent, is the enclosing block a class body?) for the blocks we're
+    // currently nested inside, used to tell an under-indented method (E301) from an
+    // under-indented statement in any other kind of block.
+    let mut indent_stack: Vec<(usize, bool)> = Vec::new();
+
     checks.sort_by_key(|check| check.location);
     let mut checks_iter = checks.iter().enumerate().peekable();
     if let Some((_index, check)) = checks_iter.peek() {
@@ -90,7 +182,7 @@ pub fn check_lines(
         if enforce_noqa {
             noqa_directives
                 .entry(noqa_lineno)
-                .or_insert_with(|| (noqa::extract_noqa_directive(lines[noqa_lineno]), vec![]));
+                .or_insert_with(|| (noqa_directive_for(lines[noqa_lineno], ignore_noqa), vec![]));
         }
 
         // Remove any ignored checks.
@@ -99,7 +191,7 @@ pub fn check_lines(
         {
             let noqa = noqa_directives
                 .entry(noqa_lineno)
-                .or_insert_with(|| (noqa::extract_noqa_directive(lines[noqa_lineno]), vec![]));
+                .or_insert_with(|| (noqa_directive_for(lines[noqa_lineno], ignore_noqa), vec![]));
 
             match noqa {
                 (Directive::All(..), matches) => {
@@ -116,13 +208,316 @@ pub fn check_lines(
             }
         }
 
+        // Enforce blank-line violations (E301-E305).
+        if enforce_blank_lines {
+            let stripped = line.trim_start();
+            if stripped.is_empty() {
+                blank_lines += 1;
+            } else if !stripped.starts_with('#') {
+                let indent_level = line.len() - stripped.len();
+                let is_first_logical_line = prev_indent.is_none();
+                let is_first_in_block = prev_indent.map_or(true, |prev| indent_level > prev);
+
+                while let Some(&(body_indent, _)) = indent_stack.last() {
+                    if indent_level < body_indent {
+                        indent_stack.pop();
+                    } else {
+                        break;
+                    }
+                }
+
+                let is_decorator = stripped.starts_with('@');
+                let is_block_header = stripped.starts_with("def ")
+                    || stripped.starts_with("async def ")
+                    || stripped.starts_with("class ");
+
+                if settings.enabled.contains(&CheckCode::E303)
+                    && !is_first_logical_line
+                    && blank_lines > 2
+                {
+                    let mut check = Check::new(
+                        CheckKind::TooManyBlankLines(blank_lines),
+                        Range {
+                            location: Location::new(lineno + 1, 0),
+                            end_location: Location::new(lineno + 1, indent_level),
+                        },
+                    );
+                    if patch_blank_lines(lineno) {
+                        check.amend(Fix::deletion(
+                            Location::new(lineno + 1 - (blank_lines - 2), 0),
+                            Location::new(lineno + 1, 0),
+                        ));
+                    }
+                    push_blank_lines_check(
+                        check,
+                        &lines,
+                        noqa_lineno,
+                        ignore_noqa,
+                        &mut noqa_directives,
+                        &mut line_checks,
+                    );
+                }
+
+                if indent_level == 0 {
+                    if is_decorator {
+                        if settings.enabled.contains(&CheckCode::E302)
+                            && !prev_was_decorator
+                            && !is_first_logical_line
+                            && blank_lines < 2
+                        {
+                            let mut check = Check::new(
+                                CheckKind::BlankLinesTopLevel(blank_lines),
+                                Range {
+                                    location: Location::new(lineno + 1, 0),
+                                    end_location: Location::new(lineno + 1, indent_level),
+                                },
+                            );
+                            if patch_blank_lines(lineno) {
+                                check.amend(Fix::insertion(
+                                    "\n".repeat(2 - blank_lines),
+                                    Location::new(lineno + 1, 0),
+                                ));
+                            }
+                            push_blank_lines_check(
+                                check,
+                                &lines,
+                                noqa_lineno,
+                                ignore_noqa,
+                                &mut noqa_directives,
+                                &mut line_checks,
+                            );
+                        }
+                    } else if is_block_header {
+                        if prev_was_decorator {
+                            if settings.enabled.contains(&CheckCode::E304) && blank_lines > 0 {
+                                let mut check = Check::new(
+                                    CheckKind::BlankLineAfterDecorator(blank_lines),
+                                    Range {
+                                        location: Location::new(lineno + 1, 0),
+                                        end_location: Location::new(lineno + 1, indent_level),
+                                    },
+                                );
+                                if patch_blank_lines(lineno) {
+                                    check.amend(Fix::deletion(
+                                        Location::new(lineno + 1 - blank_lines, 0),
+                                        Location::new(lineno + 1, 0),
+                                    ));
+                                }
+                                push_blank_lines_check(
+                                    check,
+                                    &lines,
+                                    noqa_lineno,
+                                    ignore_noqa,
+                                    &mut noqa_directives,
+                                    &mut line_checks,
+                                );
+                            }
+                        } else if settings.enabled.contains(&CheckCode::E302)
+                            && !is_first_logical_line
+                            && blank_lines < 2
+                        {
+                            let mut check = Check::new(
+                                CheckKind::BlankLinesTopLevel(blank_lines),
+                                Range {
+                                    location: Location::new(lineno + 1, 0),
+                                    end_location: Location::new(lineno + 1, indent_level),
+                                },
+                            );
+                            if patch_blank_lines(lineno) {
+                                check.amend(Fix::insertion(
+                                    "\n".repeat(2 - blank_lines),
+                                    Location::new(lineno + 1, 0),
+                                ));
+                            }
+                            push_blank_lines_check(
+                                check,
+                                &lines,
+                                noqa_lineno,
+                                ignore_noqa,
+                                &mut noqa_directives,
+                                &mut line_checks,
+                            );
+                        }
+                        saw_toplevel_block = true;
+                    } else {
+                        if settings.enabled.contains(&CheckCode::E305)
+                            && saw_toplevel_block
+                            && blank_lines < 2
+                        {
+                            let mut check = Check::new(
+                                CheckKind::BlankLinesAfterFunctionOrClass(blank_lines),
+                                Range {
+                                    location: Location::new(lineno + 1, 0),
+                                    end_location: Location::new(lineno + 1, indent_level),
+                                },
+                            );
+                            if patch_blank_lines(lineno) {
+                                check.amend(Fix::insertion(
+                                    "\n".repeat(2 - blank_lines),
+                                    Location::new(lineno + 1, 0),
+                                ));
+                            }
+                            push_blank_lines_check(
+                                check,
+                                &lines,
+                                noqa_lineno,
+                                ignore_noqa,
+                                &mut noqa_directives,
+                                &mut line_checks,
+                            );
+                        }
+                        saw_toplevel_block = false;
+                    }
+                } else if is_decorator || is_block_header {
+                    if prev_was_decorator {
+                        if settings.enabled.contains(&CheckCode::E304) && blank_lines > 0 {
+                            let mut check = Check::new(
+                                CheckKind::BlankLineAfterDecorator(blank_lines),
+                                Range {
+                                    location: Location::new(lineno + 1, 0),
+                                    end_location: Location::new(lineno + 1, indent_level),
+                                },
+                            );
+                            if patch_blank_lines(lineno) {
+                                check.amend(Fix::deletion(
+                                    Location::new(lineno + 1 - blank_lines, 0),
+                                    Location::new(lineno + 1, 0),
+                                ));
+                            }
+                            push_blank_lines_check(
+                                check,
+                                &lines,
+                                noqa_lineno,
+                                ignore_noqa,
+                                &mut noqa_directives,
+                                &mut line_checks,
+                            );
+                        }
+                    } else if settings.enabled.contains(&CheckCode::E301)
+                        && !is_first_in_block
+                        && blank_lines == 0
+                    {
+                        if let Some(&(_, is_class)) = indent_stack.last() {
+                            if is_class {
+                                let mut check = Check::new(
+                                    CheckKind::BlankLineBetweenMethods,
+                                    Range {
+                                        location: Location::new(lineno + 1, 0),
+                                        end_location: Location::new(lineno + 1, indent_level),
+                                    },
+                                );
+                                if patch_blank_lines(lineno) {
+                                    check.amend(Fix::insertion(
+                                        "\n".to_string(),
+                                        Location::new(lineno + 1, 0),
+                                    ));
+                                }
+                                push_blank_lines_check(
+                                    check,
+                                    &lines,
+                                    noqa_lineno,
+                                    ignore_noqa,
+                                    &mut noqa_directives,
+                                    &mut line_checks,
+                                );
+                            }
+                        }
+                    }
+                }
+
+                if is_first_in_block {
+                    indent_stack.push((indent_level, prev_is_class_header));
+                }
+
+                blank_lines = 0;
+                prev_indent = Some(indent_level);
+                prev_is_class_header = stripped.starts_with("class ");
+                prev_was_decorator = is_decorator;
+            }
+        }
+
+        // Enforce tab/space indentation violations (E101, W191).
+        if enforce_tab_indentation || enforce_mixed_spaces_and_tabs {
+            let indent_end = line
+                .find(|char| char != ' ' && char != '\t')
+                .unwrap_or(line.len());
+            let indent = &line[..indent_end];
+            let current_indent_char =
+                *indent_char.get_or_insert_with(|| indent.chars().next().unwrap_or(' '));
+
+            if enforce_tab_indentation && indent.contains('\t') {
+                let noqa = noqa_directives
+                    .entry(noqa_lineno)
+                    .or_insert_with(|| {
+                        (noqa_directive_for(lines[noqa_lineno], ignore_noqa), vec![])
+                    });
+
+                let check = Check::new(
+                    CheckKind::TabIndentation,
+                    Range {
+                        location: Location::new(lineno + 1, 0),
+                        end_location: Location::new(lineno + 1, indent_end),
+                    },
+                );
+
+                match noqa {
+                    (Directive::All(..), matches) => {
+                        matches.push(check.kind.code().as_ref());
+                    }
+                    (Directive::Codes(_, _, codes), matches) => {
+                        if codes.contains(&check.kind.code().as_ref()) {
+                            matches.push(check.kind.code().as_ref());
+                        } else {
+                            line_checks.push(check);
+                        }
+                    }
+                    (Directive::None, _) => line_checks.push(check),
+                }
+            }
+
+            if enforce_mixed_spaces_and_tabs
+                && !indent.is_empty()
+                && indent.chars().any(|char| char != current_indent_char)
+            {
+                let noqa = noqa_directives
+                    .entry(noqa_lineno)
+                    .or_insert_with(|| {
+                        (noqa_directive_for(lines[noqa_lineno], ignore_noqa), vec![])
+                    });
+
+                let check = Check::new(
+                    CheckKind::MixedSpacesAndTabs,
+                    Range {
+                        location: Location::new(lineno + 1, 0),
+                        end_location: Location::new(lineno + 1, indent_end),
+                    },
+                );
+
+                match noqa {
+                    (Directive::All(..), matches) => {
+                        matches.push(check.kind.code().as_ref());
+                    }
+                    (Directive::Codes(_, _, codes), matches) => {
+                        if codes.contains(&check.kind.code().as_ref()) {
+                            matches.push(check.kind.code().as_ref());
+                        } else {
+                            line_checks.push(check);
+                        }
+                    }
+                    (Directive::None, _) => line_checks.push(check),
+                }
+            }
+        }
+
         // Enforce line length violations (E501).
         if enforce_line_too_long {
-            let line_length = line.chars().count();
+            let line_length = expanded_line_length(line, settings.tab_size);
             if should_enforce_line_length(line, line_length, settings.line_length) {
                 let noqa = noqa_directives
                     .entry(noqa_lineno)
-                    .or_insert_with(|| (noqa::extract_noqa_directive(lines[noqa_lineno]), vec![]));
+                    .or_insert_with(|| {
+                        (noqa_directive_for(lines[noqa_lineno], ignore_noqa), vec![])
+                    });
 
                 let check = Check::new(
                     CheckKind::LineTooLong(line_length, settings.line_length),
@@ -162,7 +557,7 @@ pub fn check_lines(
 
             let noqa = noqa_directives
                 .entry(noqa_lineno)
-                .or_insert_with(|| (noqa::extract_noqa_directive(lines[noqa_lineno]), vec![]));
+                .or_insert_with(|| (noqa_directive_for(lines[noqa_lineno], ignore_noqa), vec![]));
 
             let check = Check::new(
                 CheckKind::NoNewLineAtEndOfFile,
@@ -260,7 +655,7 @@ pub fn check_lines(
 
 #[cfg(test)]
 mod tests {
-    use nohash_hasher::IntMap;
+    use nohash_hasher::{IntMap, IntSet};
 
     use super::check_lines;
     use crate::autofix::fixer;
@@ -271,21 +666,50 @@ mod tests {
     fn e501_non_ascii_char() {
         let line = "'\u{4e9c}' * 2"; // 7 in UTF-32, 9 in UTF-8.
         let noqa_line_for: IntMap<usize, usize> = Default::default();
+        let fmt_exclusions: IntSet<usize> = Default::default();
         let check_with_max_line_length = |line_length: usize| {
             let mut checks: Vec<Check> = vec![];
             check_lines(
                 &mut checks,
                 line,
                 &noqa_line_for,
+                &fmt_exclusions,
                 &Settings {
                     line_length,
                     ..Settings::for_rule(CheckCode::E501)
                 },
                 &fixer::Mode::Generate,
+                false,
             );
             checks
         };
         assert!(!check_with_max_line_length(6).is_empty());
         assert!(check_with_max_line_length(7).is_empty());
     }
+
+    #[test]
+    fn e501_expands_tabs() {
+        let line = "\tpass"; // 1 char, but 8 columns wide at the default tab size.
+        let noqa_line_for: IntMap<usize, usize> = Default::default();
+        let fmt_exclusions: IntSet<usize> = Default::default();
+        let check_with_tab_size = |tab_size: usize| {
+            let mut checks: Vec<Check> = vec![];
+            check_lines(
+                &mut checks,
+                line,
+                &noqa_line_for,
+                &fmt_exclusions,
+                &Settings {
+                    line_length: 10,
+                    tab_size,
+                    ..Settings::for_rule(CheckCode::E501)
+                },
+                &fixer::Mode::Generate,
+                false,
+            );
+            checks
+        };
+        assert!(check_with_tab_size(1).is_empty());
+        assert!(!check_with_tab_size(8).is_empty());
+    }
 }
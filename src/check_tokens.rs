@@ -1,7 +1,9 @@
 //! Lint rules based on token traversal.
 
+use rustpython_parser::ast::Location;
 use rustpython_parser::lexer::{LexResult, Tok};
 
+use crate::ast::types::Range;
 use crate::autofix::fixer;
 use crate::checks::{Check, CheckCode};
 use crate::lex::docstring_detection::StateMachine;
@@ -24,10 +26,23 @@ pub fn check_tokens(
         || settings.enabled.contains(&CheckCode::Q002)
         || settings.enabled.contains(&CheckCode::Q003);
     let enforce_invalid_escape_sequence = settings.enabled.contains(&CheckCode::W605);
+    let enforce_doc_line_too_long = settings.enabled.contains(&CheckCode::W505);
+    let enforce_extraneous_whitespace = settings.enabled.contains(&CheckCode::E201)
+        || settings.enabled.contains(&CheckCode::E202);
+    let enforce_whitespace_before_parameters = settings.enabled.contains(&CheckCode::E211);
+    let enforce_whitespace_before_comment = settings.enabled.contains(&CheckCode::E261)
+        || settings.enabled.contains(&CheckCode::E262)
+        || settings.enabled.contains(&CheckCode::E265);
 
     let mut state_machine: StateMachine = Default::default();
+    let mut prev_tok: Option<&Tok> = None;
+    let mut prev_prev_tok: Option<&Tok> = None;
+    let mut prev_end: Location = Location::new(0, 0);
     for (start, tok, end) in tokens.iter().flatten() {
-        let is_docstring = if enforce_ambiguous_unicode_character || enforce_quotes {
+        let is_docstring = if enforce_ambiguous_unicode_character
+            || enforce_quotes
+            || enforce_doc_line_too_long
+        {
             state_machine.consume(tok)
         } else {
             false
@@ -49,6 +64,7 @@ pub fn check_tokens(
                     } else {
                         Context::Comment
                     },
+                    &settings.allowed_confusables,
                     autofix.patch(),
                 ) {
                     if settings.enabled.contains(check.kind.code()) {
@@ -67,6 +83,7 @@ pub fn check_tokens(
                     end,
                     is_docstring,
                     &settings.flake8_quotes,
+                    autofix.patch(),
                 ) {
                     if settings.enabled.contains(check.kind.code()) {
                         checks.push(check);
@@ -83,5 +100,86 @@ pub fn check_tokens(
                 ));
             }
         }
+
+        // W505
+        if enforce_doc_line_too_long {
+            if matches!(tok, Tok::Comment) || (is_docstring && matches!(tok, Tok::String { .. })) {
+                let max_doc_length = settings
+                    .pycodestyle
+                    .max_doc_length
+                    .unwrap_or(settings.line_length);
+                checks.extend(pycodestyle::checks::doc_line_too_long(
+                    locator,
+                    start,
+                    end,
+                    max_doc_length,
+                ));
+            }
+        }
+
+        // E201, E202
+        if enforce_extraneous_whitespace {
+            if let Some(prev_tok) = prev_tok {
+                if let Some(check) = pycodestyle::checks::extraneous_whitespace(
+                    tok,
+                    prev_tok,
+                    prev_end,
+                    *start,
+                    autofix.patch(),
+                ) {
+                    if settings.enabled.contains(check.kind.code()) {
+                        checks.push(check);
+                    }
+                }
+            }
+        }
+
+        // E211
+        if enforce_whitespace_before_parameters {
+            if let Some(prev_tok) = prev_tok {
+                if let Some(check) = pycodestyle::checks::whitespace_before_parameters(
+                    tok,
+                    prev_tok,
+                    prev_prev_tok,
+                    prev_end,
+                    *start,
+                ) {
+                    checks.push(check);
+                }
+            }
+        }
+
+        // E261, E262, E265
+        if enforce_whitespace_before_comment {
+            if matches!(tok, Tok::Comment) {
+                let text = locator.slice_source_code_range(&Range {
+                    location: *start,
+                    end_location: *end,
+                });
+                for check in pycodestyle::checks::whitespace_before_comment(
+                    &text,
+                    *start,
+                    *end,
+                    prev_end,
+                    autofix.patch(),
+                ) {
+                    if settings.enabled.contains(check.kind.code()) {
+                        checks.push(check);
+                    }
+                }
+            }
+        }
+
+        // Track the previous two "real" tokens (mirroring pycodestyle, which
+        // ignores comments and (non-)logical newlines for this purpose) so
+        // that the whitespace rules above can look behind the current token.
+        if !matches!(
+            tok,
+            Tok::Comment | Tok::Newline | Tok::NonLogicalNewline | Tok::Indent | Tok::Dedent
+        ) {
+            prev_prev_tok = prev_tok;
+            prev_tok = Some(tok);
+            prev_end = *end;
+        }
     }
 }
@@ -9,6 +9,7 @@ use crate::ast::types::Range;
 use crate::autofix::Fix;
 use crate::flake8_quotes::settings::Quote;
 use crate::pyupgrade::types::Primitive;
+use crate::settings::types::PythonVersion;
 
 #[derive(
     AsRefStr,
@@ -26,6 +27,18 @@ use crate::pyupgrade::types::Primitive;
 )]
 pub enum CheckCode {
     // pycodestyle errors
+    E101,
+    E201,
+    E202,
+    E211,
+    E261,
+    E262,
+    E265,
+    E301,
+    E302,
+    E303,
+    E304,
+    E305,
     E402,
     E501,
     E711,
@@ -41,7 +54,9 @@ pub enum CheckCode {
     E902,
     E999,
     // pycodestyle warnings
+    W191,
     W292,
+    W505,
     W605,
     // pyflakes
     F401,
@@ -66,6 +81,7 @@ pub enum CheckCode {
     F706,
     F707,
     F722,
+    F811,
     F821,
     F822,
     F823,
@@ -229,14 +245,34 @@ pub enum CheckCode {
     S105,
     S106,
     S107,
+    // pylint
+    PLR0911,
+    PLR0912,
+    PLR0913,
+    PLR0915,
+    PLR0916,
+    PLR1702,
     // Ruff
     RUF001,
     RUF002,
     RUF003,
-    // Meta
-    M001,
+    RUF004,
+    RUF005,
+    RUF006,
+    RUF007,
+    RUF008,
+    RUF009,
+    RUF010,
+    RUF100,
 }
 
+// TODO(charlie): A minimal type-inference layer (literal types, builtin
+// constructor calls, annotated assignments) was requested to sharpen
+// annotation-driven rules such as pandas-vet, PERF, and RET. Checked against
+// `CheckCategory` below and there's no pandas-vet/PERF/RET variant — none of
+// those plugin families exist in this codebase yet, so there's nothing for an
+// inference layer to serve; building it now would be dead code. Revisit once
+// one of those plugins actually lands.
 #[derive(EnumIter, Debug, PartialEq, Eq)]
 pub enum CheckCategory {
     Pyflakes,
@@ -253,8 +289,8 @@ pub enum CheckCategory {
     Flake8Quotes,
     Flake8Annotations,
     Flake82020,
+    Pylint,
     Ruff,
-    Meta,
 }
 
 impl CheckCategory {
@@ -274,8 +310,8 @@ impl CheckCategory {
             CheckCategory::Pyupgrade => "pyupgrade",
             CheckCategory::Pydocstyle => "pydocstyle",
             CheckCategory::PEP8Naming => "pep8-naming",
+            CheckCategory::Pylint => "pylint",
             CheckCategory::Ruff => "Ruff-specific rules",
-            CheckCategory::Meta => "Meta rules",
         }
     }
 
@@ -303,12 +339,13 @@ impl CheckCategory {
             CheckCategory::Pydocstyle => Some("https://pypi.org/project/pydocstyle/6.1.1/"),
             CheckCategory::PEP8Naming => Some("https://pypi.org/project/pep8-naming/0.13.2/"),
             CheckCategory::Flake8Bandit => Some("https://pypi.org/project/flake8-bandit/4.1.1/"),
+            CheckCategory::Pylint => Some("https://pypi.org/project/pylint/2.15.5/"),
             CheckCategory::Ruff => None,
-            CheckCategory::Meta => None,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum LintSource {
     AST,
@@ -330,20 +367,34 @@ pub enum CheckKind {
     AmbiguousClassName(String),
     AmbiguousFunctionName(String),
     AmbiguousVariableName(String),
+    BlankLineAfterDecorator(usize),
+    BlankLineBetweenMethods,
+    BlankLinesAfterFunctionOrClass(usize),
+    BlankLinesTopLevel(usize),
+    BlockCommentShouldStartWithSpace,
     DoNotAssignLambda,
     DoNotUseBareExcept,
+    InlineCommentShouldStartWithSpace,
     IOError(String),
     LineTooLong(usize, usize),
+    MixedSpacesAndTabs,
     ModuleImportNotAtTopOfFile,
     NoneComparison(RejectedCmpop),
     NotInTest,
     NotIsTest,
     SyntaxError(String),
+    TooFewSpacesBeforeInlineComment,
+    TooManyBlankLines(usize),
     TrueFalseComparison(bool, RejectedCmpop),
     TypeComparison,
+    WhitespaceAfterOpenBracket(char),
+    WhitespaceBeforeCloseBracket(char),
+    WhitespaceBeforeParameters(char),
     // pycodestyle warnings
+    DocLineTooLong(usize, usize),
     NoNewLineAtEndOfFile,
     InvalidEscapeSequence(char),
+    TabIndentation,
     // pyflakes
     AssertTuple,
     BreakOutsideLoop,
@@ -365,6 +416,7 @@ pub enum CheckKind {
     MultiValueRepeatedKeyLiteral,
     MultiValueRepeatedKeyVariable(String),
     RaiseNotImplemented,
+    RedefinedWhileUnused(String, usize),
     ReturnOutsideFunction,
     TwoStarredExpressions,
     UndefinedExport(String),
@@ -530,11 +582,24 @@ pub enum CheckKind {
     HardcodedPasswordString(String),
     HardcodedPasswordFuncArg(String),
     HardcodedPasswordDefault(String),
+    // pylint
+    TooManyReturnStatements(usize, usize),
+    TooManyBranches(usize, usize),
+    TooManyArguments(usize, usize),
+    TooManyStatements(usize, usize),
+    TooManyBooleanExpressions(usize, usize),
+    TooManyNestedBlocks(usize, usize),
     // Ruff
     AmbiguousUnicodeCharacterString(char, char),
     AmbiguousUnicodeCharacterDocstring(char, char),
     AmbiguousUnicodeCharacterComment(char, char),
-    // Meta
+    MutableDataclassDefault,
+    ImplicitOptional,
+    AsyncioDanglingTask,
+    UnresolvedImport(String),
+    TooManyViolations(usize),
+    DuplicateCode(usize, String),
+    FunctionIsTooComplex(String, usize, usize),
     UnusedNOQA(Option<Vec<String>>),
 }
 
@@ -543,18 +608,33 @@ impl CheckCode {
     /// physical lines).
     pub fn lint_source(&self) -> &'static LintSource {
         match self {
-            CheckCode::E501 | CheckCode::W292 | CheckCode::M001 | CheckCode::U009 => {
-                &LintSource::Lines
-            }
-            CheckCode::Q000
+            CheckCode::E101
+            | CheckCode::E301
+            | CheckCode::E302
+            | CheckCode::E303
+            | CheckCode::E304
+            | CheckCode::E305
+            | CheckCode::E501
+            | CheckCode::W191
+            | CheckCode::W292
+            | CheckCode::RUF100
+            | CheckCode::U009 => &LintSource::Lines,
+            CheckCode::E201
+            | CheckCode::E202
+            | CheckCode::E211
+            | CheckCode::E261
+            | CheckCode::E262
+            | CheckCode::E265
+            | CheckCode::Q000
             | CheckCode::Q001
             | CheckCode::Q002
             | CheckCode::Q003
+            | CheckCode::W505
             | CheckCode::W605
             | CheckCode::RUF001
             | CheckCode::RUF002
             | CheckCode::RUF003 => &LintSource::Tokens,
-            CheckCode::E902 => &LintSource::FileSystem,
+            CheckCode::E902 | CheckCode::RUF008 | CheckCode::RUF009 => &LintSource::FileSystem,
             CheckCode::I001 => &LintSource::Imports,
             _ => &LintSource::AST,
         }
@@ -564,6 +644,18 @@ impl CheckCode {
     pub fn kind(&self) -> CheckKind {
         match self {
             // pycodestyle errors
+            CheckCode::E101 => CheckKind::MixedSpacesAndTabs,
+            CheckCode::E201 => CheckKind::WhitespaceAfterOpenBracket('('),
+            CheckCode::E202 => CheckKind::WhitespaceBeforeCloseBracket(')'),
+            CheckCode::E211 => CheckKind::WhitespaceBeforeParameters('('),
+            CheckCode::E261 => CheckKind::TooFewSpacesBeforeInlineComment,
+            CheckCode::E262 => CheckKind::InlineCommentShouldStartWithSpace,
+            CheckCode::E265 => CheckKind::BlockCommentShouldStartWithSpace,
+            CheckCode::E301 => CheckKind::BlankLineBetweenMethods,
+            CheckCode::E302 => CheckKind::BlankLinesTopLevel(0),
+            CheckCode::E303 => CheckKind::TooManyBlankLines(3),
+            CheckCode::E304 => CheckKind::BlankLineAfterDecorator(1),
+            CheckCode::E305 => CheckKind::BlankLinesAfterFunctionOrClass(0),
             CheckCode::E402 => CheckKind::ModuleImportNotAtTopOfFile,
             CheckCode::E501 => CheckKind::LineTooLong(89, 88),
             CheckCode::E711 => CheckKind::NoneComparison(RejectedCmpop::Eq),
@@ -579,7 +671,9 @@ impl CheckCode {
             CheckCode::E902 => CheckKind::IOError("IOError: `...`".to_string()),
             CheckCode::E999 => CheckKind::SyntaxError("`...`".to_string()),
             // pycodestyle warnings
+            CheckCode::W191 => CheckKind::TabIndentation,
             CheckCode::W292 => CheckKind::NoNewLineAtEndOfFile,
+            CheckCode::W505 => CheckKind::DocLineTooLong(89, 88),
             CheckCode::W605 => CheckKind::InvalidEscapeSequence('c'),
             // pyflakes
             CheckCode::F401 => CheckKind::UnusedImport(vec!["...".to_string()], false),
@@ -606,6 +700,7 @@ impl CheckCode {
             CheckCode::F706 => CheckKind::ReturnOutsideFunction,
             CheckCode::F707 => CheckKind::DefaultExceptNotLast,
             CheckCode::F722 => CheckKind::ForwardAnnotationSyntaxError("...".to_string()),
+            CheckCode::F811 => CheckKind::RedefinedWhileUnused("...".to_string(), 1),
             CheckCode::F821 => CheckKind::UndefinedName("...".to_string()),
             CheckCode::F822 => CheckKind::UndefinedExport("...".to_string()),
             CheckCode::F823 => CheckKind::UndefinedLocal("...".to_string()),
@@ -805,17 +900,42 @@ impl CheckCode {
             CheckCode::S105 => CheckKind::HardcodedPasswordString("...".to_string()),
             CheckCode::S106 => CheckKind::HardcodedPasswordFuncArg("...".to_string()),
             CheckCode::S107 => CheckKind::HardcodedPasswordDefault("...".to_string()),
+            // pylint
+            CheckCode::PLR0911 => CheckKind::TooManyReturnStatements(10, 6),
+            CheckCode::PLR0912 => CheckKind::TooManyBranches(15, 12),
+            CheckCode::PLR0913 => CheckKind::TooManyArguments(8, 5),
+            CheckCode::PLR0915 => CheckKind::TooManyStatements(60, 50),
+            CheckCode::PLR0916 => CheckKind::TooManyBooleanExpressions(8, 5),
+            CheckCode::PLR1702 => CheckKind::TooManyNestedBlocks(8, 5),
             // Ruff
             CheckCode::RUF001 => CheckKind::AmbiguousUnicodeCharacterString('𝐁', 'B'),
             CheckCode::RUF002 => CheckKind::AmbiguousUnicodeCharacterDocstring('𝐁', 'B'),
             CheckCode::RUF003 => CheckKind::AmbiguousUnicodeCharacterComment('𝐁', 'B'),
-            // Meta
-            CheckCode::M001 => CheckKind::UnusedNOQA(None),
+            CheckCode::RUF004 => CheckKind::MutableDataclassDefault,
+            CheckCode::RUF005 => CheckKind::ImplicitOptional,
+            CheckCode::RUF006 => CheckKind::AsyncioDanglingTask,
+            CheckCode::RUF007 => CheckKind::UnresolvedImport("...".to_string()),
+            CheckCode::RUF008 => CheckKind::TooManyViolations(1),
+            CheckCode::RUF009 => CheckKind::DuplicateCode(6, "...:1".to_string()),
+            CheckCode::RUF010 => CheckKind::FunctionIsTooComplex("...".to_string(), 25, 15),
+            CheckCode::RUF100 => CheckKind::UnusedNOQA(None),
         }
     }
 
     pub fn category(&self) -> CheckCategory {
         match self {
+            CheckCode::E101 => CheckCategory::Pycodestyle,
+            CheckCode::E201 => CheckCategory::Pycodestyle,
+            CheckCode::E202 => CheckCategory::Pycodestyle,
+            CheckCode::E211 => CheckCategory::Pycodestyle,
+            CheckCode::E261 => CheckCategory::Pycodestyle,
+            CheckCode::E262 => CheckCategory::Pycodestyle,
+            CheckCode::E265 => CheckCategory::Pycodestyle,
+            CheckCode::E301 => CheckCategory::Pycodestyle,
+            CheckCode::E302 => CheckCategory::Pycodestyle,
+            CheckCode::E303 => CheckCategory::Pycodestyle,
+            CheckCode::E304 => CheckCategory::Pycodestyle,
+            CheckCode::E305 => CheckCategory::Pycodestyle,
             CheckCode::E402 => CheckCategory::Pycodestyle,
             CheckCode::E501 => CheckCategory::Pycodestyle,
             CheckCode::E711 => CheckCategory::Pycodestyle,
@@ -830,7 +950,9 @@ impl CheckCode {
             CheckCode::E743 => CheckCategory::Pycodestyle,
             CheckCode::E902 => CheckCategory::Pycodestyle,
             CheckCode::E999 => CheckCategory::Pycodestyle,
+            CheckCode::W191 => CheckCategory::Pycodestyle,
             CheckCode::W292 => CheckCategory::Pycodestyle,
+            CheckCode::W505 => CheckCategory::Pycodestyle,
             CheckCode::W605 => CheckCategory::Pycodestyle,
             CheckCode::F401 => CheckCategory::Pyflakes,
             CheckCode::F402 => CheckCategory::Pyflakes,
@@ -854,6 +976,7 @@ impl CheckCode {
             CheckCode::F706 => CheckCategory::Pyflakes,
             CheckCode::F707 => CheckCategory::Pyflakes,
             CheckCode::F722 => CheckCategory::Pyflakes,
+            CheckCode::F811 => CheckCategory::Pyflakes,
             CheckCode::F821 => CheckCategory::Pyflakes,
             CheckCode::F822 => CheckCategory::Pyflakes,
             CheckCode::F823 => CheckCategory::Pyflakes,
@@ -1005,12 +1128,57 @@ impl CheckCode {
             CheckCode::S105 => CheckCategory::Flake8Bandit,
             CheckCode::S106 => CheckCategory::Flake8Bandit,
             CheckCode::S107 => CheckCategory::Flake8Bandit,
+            CheckCode::PLR0911 => CheckCategory::Pylint,
+            CheckCode::PLR0912 => CheckCategory::Pylint,
+            CheckCode::PLR0913 => CheckCategory::Pylint,
+            CheckCode::PLR0915 => CheckCategory::Pylint,
+            CheckCode::PLR0916 => CheckCategory::Pylint,
+            CheckCode::PLR1702 => CheckCategory::Pylint,
             CheckCode::RUF001 => CheckCategory::Ruff,
             CheckCode::RUF002 => CheckCategory::Ruff,
             CheckCode::RUF003 => CheckCategory::Ruff,
-            CheckCode::M001 => CheckCategory::Meta,
+            CheckCode::RUF004 => CheckCategory::Ruff,
+            CheckCode::RUF005 => CheckCategory::Ruff,
+            CheckCode::RUF006 => CheckCategory::Ruff,
+            CheckCode::RUF007 => CheckCategory::Ruff,
+            CheckCode::RUF008 => CheckCategory::Ruff,
+            CheckCode::RUF009 => CheckCategory::Ruff,
+            CheckCode::RUF010 => CheckCategory::Ruff,
+            CheckCode::RUF100 => CheckCategory::Ruff,
+        }
+    }
+
+    /// The minimum Python version a fix produced by this check assumes the
+    /// target code already runs on, if any. Callers gating a check on
+    /// `--target-version` should compare against this rather than
+    /// hardcoding a version at the call site, so that there's a single
+    /// source of truth as these gates accumulate.
+    pub fn minimum_version(&self) -> Option<PythonVersion> {
+        match self {
+            // `__file__` is only guaranteed absolute as of Python 3.9
+            // (bpo-20443), so `os.path.abspath(__file__)` is only redundant
+            // from that version on.
+            CheckCode::U002 => Some(PythonVersion::Py39),
+            // PEP 585 (builtin generics, e.g. `list[int]`) landed in 3.9.
+            CheckCode::U006 => Some(PythonVersion::Py39),
+            // PEP 604 (`X | Y` union syntax) landed in 3.10.
+            CheckCode::U007 => Some(PythonVersion::Py310),
+            // `functools.lru_cache` became usable without parentheses in 3.8.
+            CheckCode::U011 => Some(PythonVersion::Py38),
+            _ => None,
         }
     }
+
+    /// Whether the check is still under active development and excluded from
+    /// `--select ALL` unless `--preview` is also passed.
+    pub fn is_preview(&self) -> bool {
+        matches!(
+            self,
+            // The ambiguous-unicode-character checks are prone to false
+            // positives on legitimate non-ASCII identifiers and comments.
+            CheckCode::RUF001 | CheckCode::RUF002 | CheckCode::RUF003
+        )
+    }
 }
 
 impl CheckKind {
@@ -1022,6 +1190,11 @@ impl CheckKind {
             CheckKind::AmbiguousFunctionName(_) => &CheckCode::E743,
             CheckKind::AmbiguousVariableName(_) => &CheckCode::E741,
             CheckKind::AssertTuple => &CheckCode::F631,
+            CheckKind::BlankLineAfterDecorator(_) => &CheckCode::E304,
+            CheckKind::BlankLineBetweenMethods => &CheckCode::E301,
+            CheckKind::BlankLinesAfterFunctionOrClass(_) => &CheckCode::E305,
+            CheckKind::BlankLinesTopLevel(_) => &CheckCode::E302,
+            CheckKind::BlockCommentShouldStartWithSpace => &CheckCode::E265,
             CheckKind::BreakOutsideLoop => &CheckCode::F701,
             CheckKind::ContinueOutsideLoop => &CheckCode::F702,
             CheckKind::DefaultExceptNotLast => &CheckCode::F707,
@@ -1037,10 +1210,12 @@ impl CheckKind {
             CheckKind::ImportStarNotPermitted(_) => &CheckCode::F406,
             CheckKind::ImportStarUsage(..) => &CheckCode::F405,
             CheckKind::ImportStarUsed(_) => &CheckCode::F403,
+            CheckKind::InlineCommentShouldStartWithSpace => &CheckCode::E262,
             CheckKind::InvalidPrintSyntax => &CheckCode::F633,
             CheckKind::IsLiteral => &CheckCode::F632,
             CheckKind::LateFutureImport => &CheckCode::F404,
             CheckKind::LineTooLong(..) => &CheckCode::E501,
+            CheckKind::MixedSpacesAndTabs => &CheckCode::E101,
             CheckKind::ModuleImportNotAtTopOfFile => &CheckCode::E402,
             CheckKind::MultiValueRepeatedKeyLiteral => &CheckCode::F601,
             CheckKind::MultiValueRepeatedKeyVariable(_) => &CheckCode::F602,
@@ -1048,9 +1223,12 @@ impl CheckKind {
             CheckKind::NotInTest => &CheckCode::E713,
             CheckKind::NotIsTest => &CheckCode::E714,
             CheckKind::RaiseNotImplemented => &CheckCode::F901,
+            CheckKind::RedefinedWhileUnused(..) => &CheckCode::F811,
             CheckKind::ReturnOutsideFunction => &CheckCode::F706,
             CheckKind::SyntaxError(_) => &CheckCode::E999,
             CheckKind::ExpressionsInStarAssignment => &CheckCode::F621,
+            CheckKind::TooFewSpacesBeforeInlineComment => &CheckCode::E261,
+            CheckKind::TooManyBlankLines(_) => &CheckCode::E303,
             CheckKind::TrueFalseComparison(..) => &CheckCode::E712,
             CheckKind::TwoStarredExpressions => &CheckCode::F622,
             CheckKind::TypeComparison => &CheckCode::E721,
@@ -1059,10 +1237,15 @@ impl CheckKind {
             CheckKind::UndefinedName(_) => &CheckCode::F821,
             CheckKind::UnusedImport(..) => &CheckCode::F401,
             CheckKind::UnusedVariable(_) => &CheckCode::F841,
+            CheckKind::WhitespaceAfterOpenBracket(_) => &CheckCode::E201,
+            CheckKind::WhitespaceBeforeCloseBracket(_) => &CheckCode::E202,
+            CheckKind::WhitespaceBeforeParameters(_) => &CheckCode::E211,
             CheckKind::YieldOutsideFunction => &CheckCode::F704,
             // pycodestyle warnings
+            CheckKind::DocLineTooLong(..) => &CheckCode::W505,
             CheckKind::NoNewLineAtEndOfFile => &CheckCode::W292,
             CheckKind::InvalidEscapeSequence(_) => &CheckCode::W605,
+            CheckKind::TabIndentation => &CheckCode::W191,
             // flake8-builtins
             CheckKind::BuiltinVariableShadowing(_) => &CheckCode::A001,
             CheckKind::BuiltinArgumentShadowing(_) => &CheckCode::A002,
@@ -1220,12 +1403,24 @@ impl CheckKind {
             CheckKind::HardcodedPasswordString(..) => &CheckCode::S105,
             CheckKind::HardcodedPasswordFuncArg(..) => &CheckCode::S106,
             CheckKind::HardcodedPasswordDefault(..) => &CheckCode::S107,
+            CheckKind::TooManyReturnStatements(..) => &CheckCode::PLR0911,
+            CheckKind::TooManyBranches(..) => &CheckCode::PLR0912,
+            CheckKind::TooManyArguments(..) => &CheckCode::PLR0913,
+            CheckKind::TooManyStatements(..) => &CheckCode::PLR0915,
+            CheckKind::TooManyBooleanExpressions(..) => &CheckCode::PLR0916,
+            CheckKind::TooManyNestedBlocks(..) => &CheckCode::PLR1702,
             // Ruff
             CheckKind::AmbiguousUnicodeCharacterString(..) => &CheckCode::RUF001,
             CheckKind::AmbiguousUnicodeCharacterDocstring(..) => &CheckCode::RUF002,
             CheckKind::AmbiguousUnicodeCharacterComment(..) => &CheckCode::RUF003,
-            // Meta
-            CheckKind::UnusedNOQA(_) => &CheckCode::M001,
+            CheckKind::MutableDataclassDefault => &CheckCode::RUF004,
+            CheckKind::ImplicitOptional => &CheckCode::RUF005,
+            CheckKind::AsyncioDanglingTask => &CheckCode::RUF006,
+            CheckKind::UnresolvedImport(_) => &CheckCode::RUF007,
+            CheckKind::TooManyViolations(_) => &CheckCode::RUF008,
+            CheckKind::DuplicateCode(..) => &CheckCode::RUF009,
+            CheckKind::FunctionIsTooComplex(..) => &CheckCode::RUF010,
+            CheckKind::UnusedNOQA(_) => &CheckCode::RUF100,
         }
     }
 
@@ -1245,6 +1440,22 @@ impl CheckKind {
             CheckKind::AssertTuple => {
                 "Assert test is a non-empty tuple, which is always `True`".to_string()
             }
+            CheckKind::BlankLineAfterDecorator(blank_lines) => {
+                format!("Blank lines found after function decorator ({blank_lines})")
+            }
+            CheckKind::BlankLineBetweenMethods => "Expected 1 blank line, got 0".to_string(),
+            CheckKind::BlankLinesAfterFunctionOrClass(blank_lines) => {
+                format!(
+                    "Expected 2 blank lines after class or function definition, found \
+                     ({blank_lines})"
+                )
+            }
+            CheckKind::BlankLinesTopLevel(blank_lines) => {
+                format!("Expected 2 blank lines, found {blank_lines}")
+            }
+            CheckKind::BlockCommentShouldStartWithSpace => {
+                "Block comment should start with '# '".to_string()
+            }
             CheckKind::BreakOutsideLoop => "`break` outside loop".to_string(),
             CheckKind::ContinueOutsideLoop => "`continue` not properly in loop".to_string(),
             CheckKind::DefaultExceptNotLast => {
@@ -1268,6 +1479,9 @@ impl CheckKind {
             }
             CheckKind::IOError(message) => message.clone(),
             CheckKind::IfTuple => "If test is a tuple, which is always `True`".to_string(),
+            CheckKind::InlineCommentShouldStartWithSpace => {
+                "Inline comment should start with '# '".to_string()
+            }
             CheckKind::InvalidPrintSyntax => {
                 "Use of `>>` is invalid with `print` function".to_string()
             }
@@ -1294,6 +1508,9 @@ impl CheckKind {
             CheckKind::LineTooLong(length, limit) => {
                 format!("Line too long ({length} > {limit} characters)")
             }
+            CheckKind::MixedSpacesAndTabs => {
+                "Indentation contains mixed spaces and tabs".to_string()
+            }
             CheckKind::ModuleImportNotAtTopOfFile => {
                 "Module level import not at top of file".to_string()
             }
@@ -1314,6 +1531,9 @@ impl CheckKind {
             CheckKind::RaiseNotImplemented => {
                 "`raise NotImplemented` should be `raise NotImplementedError`".to_string()
             }
+            CheckKind::RedefinedWhileUnused(name, line) => {
+                format!("Redefinition of unused `{name}` from line {line}")
+            }
             CheckKind::ReturnOutsideFunction => {
                 "`return` statement outside of a function/method".to_string()
             }
@@ -1321,6 +1541,12 @@ impl CheckKind {
             CheckKind::ExpressionsInStarAssignment => {
                 "Too many expressions in star-unpacking assignment".to_string()
             }
+            CheckKind::TooFewSpacesBeforeInlineComment => {
+                "Insert at least two spaces before an inline comment".to_string()
+            }
+            CheckKind::TooManyBlankLines(blank_lines) => {
+                format!("Too many blank lines ({blank_lines})")
+            }
             CheckKind::TrueFalseComparison(value, op) => match *value {
                 true => match op {
                     RejectedCmpop::Eq => {
@@ -1361,14 +1587,27 @@ impl CheckKind {
             CheckKind::UnusedVariable(name) => {
                 format!("Local variable `{name}` is assigned to but never used")
             }
+            CheckKind::WhitespaceAfterOpenBracket(char) => {
+                format!("Whitespace after '{char}'")
+            }
+            CheckKind::WhitespaceBeforeCloseBracket(char) => {
+                format!("Whitespace before '{char}'")
+            }
+            CheckKind::WhitespaceBeforeParameters(char) => {
+                format!("Whitespace before '{char}'")
+            }
             CheckKind::YieldOutsideFunction => {
                 "`yield` or `yield from` statement outside of a function".to_string()
             }
             // pycodestyle warnings
+            CheckKind::DocLineTooLong(length, limit) => {
+                format!("Doc line too long ({length} > {limit} characters)")
+            }
             CheckKind::NoNewLineAtEndOfFile => "No newline at end of file".to_string(),
             CheckKind::InvalidEscapeSequence(char) => {
                 format!("Invalid escape sequence: '\\{char}'")
             }
+            CheckKind::TabIndentation => "Indentation contains tabs".to_string(),
             // flake8-builtins
             CheckKind::BuiltinVariableShadowing(name) => {
                 format!("Variable `{name}` is shadowing a python builtin")
@@ -1851,6 +2090,27 @@ impl CheckKind {
             CheckKind::HardcodedPasswordDefault(string) => {
                 format!("Possible hardcoded password: `\"{string}\"`")
             }
+            CheckKind::TooManyReturnStatements(returns, max_returns) => {
+                format!("Too many return statements ({returns} > {max_returns})")
+            }
+            CheckKind::TooManyBranches(branches, max_branches) => {
+                format!("Too many branches ({branches} > {max_branches})")
+            }
+            CheckKind::TooManyArguments(args, max_args) => {
+                format!("Too many arguments to function call ({args} > {max_args})")
+            }
+            CheckKind::TooManyStatements(statements, max_statements) => {
+                format!("Too many statements ({statements} > {max_statements})")
+            }
+            CheckKind::TooManyBooleanExpressions(expressions, max_expressions) => {
+                format!(
+                    "Too many boolean expressions in if statement ({expressions} > \
+                     {max_expressions})"
+                )
+            }
+            CheckKind::TooManyNestedBlocks(blocks, max_blocks) => {
+                format!("Too many nested blocks ({blocks} > {max_blocks})")
+            }
             // Ruff
             CheckKind::AmbiguousUnicodeCharacterString(confusable, representant) => {
                 format!(
@@ -1870,7 +2130,42 @@ impl CheckKind {
                      '{representant}'?)"
                 )
             }
-            // Meta
+            CheckKind::MutableDataclassDefault => {
+                "Do not use mutable default values for dataclass attributes; use \
+                 `field(default_factory=...)` instead"
+                    .to_string()
+            }
+            CheckKind::ImplicitOptional => {
+                "PEP 484 prohibits implicit `Optional`; use `Optional[T]` or `T | None` for \
+                 arguments that default to `None`"
+                    .to_string()
+            }
+            CheckKind::AsyncioDanglingTask => {
+                "Store a reference to the return value of `asyncio.create_task`; a task can be \
+                 garbage collected mid-execution if no reference is kept"
+                    .to_string()
+            }
+            CheckKind::UnresolvedImport(name) => {
+                format!(
+                    "`{name}` is not resolvable to the standard library, an installed package, \
+                     or a first-party source; check for a typo'd module name"
+                )
+            }
+            CheckKind::TooManyViolations(count) => {
+                format!(
+                    "{count} additional violation(s) suppressed (exceeds the configured \
+                     max-violations-per-file)"
+                )
+            }
+            CheckKind::DuplicateCode(lines, other) => {
+                format!("Duplicate code block ({lines} lines); also found at {other}")
+            }
+            CheckKind::FunctionIsTooComplex(name, complexity, max_complexity) => {
+                format!(
+                    "`{name}` has a cognitive complexity of {complexity} (exceeds the configured \
+                     maximum of {max_complexity})"
+                )
+            }
             CheckKind::UnusedNOQA(codes) => match codes {
                 None => "Unused `noqa` directive".to_string(),
                 Some(codes) => {
@@ -1916,17 +2211,35 @@ impl CheckKind {
             self,
             CheckKind::AmbiguousUnicodeCharacterString(_, _)
                 | CheckKind::AmbiguousUnicodeCharacterDocstring(_, _)
+                | CheckKind::AvoidQuoteEscape
+                | CheckKind::BadQuotesDocstring(_)
+                | CheckKind::BadQuotesInlineString(_)
+                | CheckKind::BadQuotesMultilineString(_)
+                | CheckKind::BlankLineAfterDecorator(_)
                 | CheckKind::BlankLineAfterLastSection(_)
                 | CheckKind::BlankLineAfterSection(_)
                 | CheckKind::BlankLineAfterSummary
                 | CheckKind::BlankLineBeforeSection(_)
+                | CheckKind::BlankLineBetweenMethods
+                | CheckKind::BlankLinesAfterFunctionOrClass(_)
+                | CheckKind::BlankLinesTopLevel(_)
+                | CheckKind::BlockCommentShouldStartWithSpace
                 | CheckKind::CapitalizeSectionName(_)
                 | CheckKind::DashedUnderlineAfterSection(_)
                 | CheckKind::DeprecatedUnittestAlias(_, _)
                 | CheckKind::DoNotAssertFalse
                 | CheckKind::DuplicateHandlerException(_)
+                | CheckKind::EndsInPeriod
+                | CheckKind::EndsInPunctuation
                 | CheckKind::GetAttrWithConstant
+                | CheckKind::ImplicitOptional
+                | CheckKind::InlineCommentShouldStartWithSpace
                 | CheckKind::IsLiteral
+                | CheckKind::MissingReturnTypeClassMethod(_)
+                | CheckKind::MissingReturnTypeMagicMethod(_)
+                | CheckKind::MissingReturnTypePrivateFunction(_)
+                | CheckKind::MissingReturnTypePublicFunction(_)
+                | CheckKind::MissingReturnTypeStaticMethod(_)
                 | CheckKind::NewLineAfterLastParagraph
                 | CheckKind::NewLineAfterSectionName(_)
                 | CheckKind::NoBlankLineAfterFunction(_)
@@ -1936,6 +2249,9 @@ impl CheckKind {
                 | CheckKind::NoOverIndentation
                 | CheckKind::NoSurroundingWhitespace
                 | CheckKind::NoUnderIndentation
+                | CheckKind::NoneComparison(_)
+                | CheckKind::NotInTest
+                | CheckKind::NotIsTest
                 | CheckKind::OneBlankLineAfterClass(_)
                 | CheckKind::OneBlankLineBeforeClass(_)
                 | CheckKind::PEP3120UnnecessaryCodingComment
@@ -1948,6 +2264,9 @@ impl CheckKind {
                 | CheckKind::SectionUnderlineMatchesSectionLength(_)
                 | CheckKind::SectionUnderlineNotOverIndented(_)
                 | CheckKind::SuperCallWithParameters
+                | CheckKind::TooFewSpacesBeforeInlineComment
+                | CheckKind::TooManyBlankLines(_)
+                | CheckKind::TrueFalseComparison(_, _)
                 | CheckKind::TypeOfPrimitive(_)
                 | CheckKind::UnnecessaryAbspath
                 | CheckKind::UnnecessaryCollectionCall(_)
@@ -1972,17 +2291,37 @@ impl CheckKind {
                 | CheckKind::UsePEP585Annotation(_)
                 | CheckKind::UsePEP604Annotation
                 | CheckKind::UselessMetaclassType
+                | CheckKind::UsesTripleQuotes
                 | CheckKind::UselessObjectInheritance(_)
+                | CheckKind::WhitespaceAfterOpenBracket(_)
+                | CheckKind::WhitespaceBeforeCloseBracket(_)
         )
     }
 }
 
+/// A secondary span attached to a `Check`, pointing at a location related to
+/// (but distinct from) the check's own, with a short label explaining the
+/// relation -- e.g. "first definition here" on the prior binding a
+/// redefinition check flags.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Annotation {
+    pub message: String,
+    pub location: Location,
+    pub end_location: Location,
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Check {
     pub kind: CheckKind,
     pub location: Location,
     pub end_location: Location,
     pub fix: Option<Fix>,
+    /// Other fixes that would also resolve this check, beyond `fix` (the one
+    /// `--fix` actually applies). Never applied automatically; carried
+    /// through to `Message` so interactive consumers (editors, LSP clients)
+    /// can offer the user a choice.
+    pub alternatives: Vec<Fix>,
+    pub related: Vec<Annotation>,
 }
 
 impl Check {
@@ -1992,12 +2331,30 @@ impl Check {
             location: range.location,
             end_location: range.end_location,
             fix: None,
+            alternatives: Vec::new(),
+            related: Vec::new(),
         }
     }
 
     pub fn amend(&mut self, fix: Fix) {
         self.fix = Some(fix);
     }
+
+    /// Offer `fix` as an additional candidate fix, alongside (but never in
+    /// place of) the one `amend` sets as primary.
+    pub fn amend_alternative(&mut self, fix: Fix) {
+        self.alternatives.push(fix);
+    }
+
+    /// Attach a secondary, labeled span to this check, e.g. a redefinition
+    /// pointing back at the definition it shadows.
+    pub fn annotate(&mut self, message: impl Into<String>, range: Range) {
+        self.related.push(Annotation {
+            message: message.into(),
+            location: range.location,
+            end_location: range.end_location,
+        });
+    }
 }
 
 #[cfg(test)]
@@ -1,11 +1,15 @@
 //! File automatically generated by examples/generate_check_code_prefix.rs.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 use strum_macros::EnumString;
 
-use crate::checks::CheckCode;
+use crate::checks::{CheckCategory, CheckCode};
 
-#[derive(EnumString, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize)]
+#[derive(
+    EnumString, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize, JsonSchema
+)]
 pub enum CheckCodePrefix {
     A,
     A0,
@@ -135,6 +139,26 @@ pub enum CheckCodePrefix {
     D418,
     D419,
     E,
+    E1,
+    E10,
+    E101,
+    E2,
+    E20,
+    E201,
+    E202,
+    E21,
+    E211,
+    E26,
+    E261,
+    E262,
+    E265,
+    E3,
+    E30,
+    E301,
+    E302,
+    E303,
+    E304,
+    E305,
     E4,
     E40,
     E402,
@@ -196,6 +220,8 @@ pub enum CheckCodePrefix {
     F72,
     F722,
     F8,
+    F81,
+    F811,
     F82,
     F821,
     F822,
@@ -211,10 +237,6 @@ pub enum CheckCodePrefix {
     I0,
     I00,
     I001,
-    M,
-    M0,
-    M00,
-    M001,
     N,
     N8,
     N80,
@@ -234,6 +256,17 @@ pub enum CheckCodePrefix {
     N816,
     N817,
     N818,
+    PLR,
+    PLR0,
+    PLR09,
+    PLR0911,
+    PLR0912,
+    PLR0913,
+    PLR0915,
+    PLR0916,
+    PLR1,
+    PLR17,
+    PLR1702,
     Q,
     Q0,
     Q00,
@@ -247,6 +280,16 @@ pub enum CheckCodePrefix {
     RUF001,
     RUF002,
     RUF003,
+    RUF004,
+    RUF005,
+    RUF006,
+    RUF007,
+    RUF008,
+    RUF009,
+    RUF010,
+    RUF1,
+    RUF10,
+    RUF100,
     S,
     S1,
     S10,
@@ -278,9 +321,15 @@ pub enum CheckCodePrefix {
     U011,
     U012,
     W,
+    W1,
+    W19,
+    W191,
     W2,
     W29,
     W292,
+    W5,
+    W50,
+    W505,
     W6,
     W60,
     W605,
@@ -301,10 +350,28 @@ pub enum CheckCodePrefix {
     YTT301,
     YTT302,
     YTT303,
+    /// pyflakes, pycodestyle, and flake8-bugbear: a sane starting point that
+    /// catches real bugs without requiring any stylistic opinions.
+    #[strum(serialize = "recommended")]
+    #[serde(rename = "recommended")]
+    Recommended,
+    /// `recommended`, plus the rest of the non-docstring, non-import-order
+    /// plugins (flake8-comprehensions, flake8-builtins, flake8-print,
+    /// pep8-naming, pyupgrade, flake8-annotations).
+    #[strum(serialize = "strict")]
+    #[serde(rename = "strict")]
+    Strict,
+    /// Every implemented rule, preview rules included once `--preview` is
+    /// passed. An alias for `ALL` under a friendlier name.
+    #[strum(serialize = "pedantic")]
+    #[serde(rename = "pedantic")]
+    Pedantic,
+    All,
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord)]
 pub enum PrefixSpecificity {
+    All,
     Category,
     Hundreds,
     Tens,
@@ -721,6 +788,18 @@ impl CheckCodePrefix {
             CheckCodePrefix::D418 => vec![CheckCode::D418],
             CheckCodePrefix::D419 => vec![CheckCode::D419],
             CheckCodePrefix::E => vec![
+                CheckCode::E101,
+                CheckCode::E201,
+                CheckCode::E202,
+                CheckCode::E211,
+                CheckCode::E261,
+                CheckCode::E262,
+                CheckCode::E265,
+                CheckCode::E301,
+                CheckCode::E302,
+                CheckCode::E303,
+                CheckCode::E304,
+                CheckCode::E305,
                 CheckCode::E402,
                 CheckCode::E501,
                 CheckCode::E711,
@@ -736,6 +815,45 @@ impl CheckCodePrefix {
                 CheckCode::E902,
                 CheckCode::E999,
             ],
+            CheckCodePrefix::E1 => vec![CheckCode::E101],
+            CheckCodePrefix::E10 => vec![CheckCode::E101],
+            CheckCodePrefix::E101 => vec![CheckCode::E101],
+            CheckCodePrefix::E2 => vec![
+                CheckCode::E201,
+                CheckCode::E202,
+                CheckCode::E211,
+                CheckCode::E261,
+                CheckCode::E262,
+                CheckCode::E265,
+            ],
+            CheckCodePrefix::E20 => vec![CheckCode::E201, CheckCode::E202],
+            CheckCodePrefix::E201 => vec![CheckCode::E201],
+            CheckCodePrefix::E202 => vec![CheckCode::E202],
+            CheckCodePrefix::E21 => vec![CheckCode::E211],
+            CheckCodePrefix::E211 => vec![CheckCode::E211],
+            CheckCodePrefix::E26 => vec![CheckCode::E261, CheckCode::E262, CheckCode::E265],
+            CheckCodePrefix::E261 => vec![CheckCode::E261],
+            CheckCodePrefix::E262 => vec![CheckCode::E262],
+            CheckCodePrefix::E265 => vec![CheckCode::E265],
+            CheckCodePrefix::E3 => vec![
+                CheckCode::E301,
+                CheckCode::E302,
+                CheckCode::E303,
+                CheckCode::E304,
+                CheckCode::E305,
+            ],
+            CheckCodePrefix::E30 => vec![
+                CheckCode::E301,
+                CheckCode::E302,
+                CheckCode::E303,
+                CheckCode::E304,
+                CheckCode::E305,
+            ],
+            CheckCodePrefix::E301 => vec![CheckCode::E301],
+            CheckCodePrefix::E302 => vec![CheckCode::E302],
+            CheckCodePrefix::E303 => vec![CheckCode::E303],
+            CheckCodePrefix::E304 => vec![CheckCode::E304],
+            CheckCodePrefix::E305 => vec![CheckCode::E305],
             CheckCodePrefix::E4 => vec![CheckCode::E402],
             CheckCodePrefix::E40 => vec![CheckCode::E402],
             CheckCodePrefix::E402 => vec![CheckCode::E402],
@@ -801,6 +919,7 @@ impl CheckCodePrefix {
                 CheckCode::F706,
                 CheckCode::F707,
                 CheckCode::F722,
+                CheckCode::F811,
                 CheckCode::F821,
                 CheckCode::F822,
                 CheckCode::F823,
@@ -885,12 +1004,15 @@ impl CheckCodePrefix {
             CheckCodePrefix::F72 => vec![CheckCode::F722],
             CheckCodePrefix::F722 => vec![CheckCode::F722],
             CheckCodePrefix::F8 => vec![
+                CheckCode::F811,
                 CheckCode::F821,
                 CheckCode::F822,
                 CheckCode::F823,
                 CheckCode::F831,
                 CheckCode::F841,
             ],
+            CheckCodePrefix::F81 => vec![CheckCode::F811],
+            CheckCodePrefix::F811 => vec![CheckCode::F811],
             CheckCodePrefix::F82 => vec![CheckCode::F821, CheckCode::F822, CheckCode::F823],
             CheckCodePrefix::F821 => vec![CheckCode::F821],
             CheckCodePrefix::F822 => vec![CheckCode::F822],
@@ -906,10 +1028,6 @@ impl CheckCodePrefix {
             CheckCodePrefix::I0 => vec![CheckCode::I001],
             CheckCodePrefix::I00 => vec![CheckCode::I001],
             CheckCodePrefix::I001 => vec![CheckCode::I001],
-            CheckCodePrefix::M => vec![CheckCode::M001],
-            CheckCodePrefix::M0 => vec![CheckCode::M001],
-            CheckCodePrefix::M00 => vec![CheckCode::M001],
-            CheckCodePrefix::M001 => vec![CheckCode::M001],
             CheckCodePrefix::N => vec![
                 CheckCode::N801,
                 CheckCode::N802,
@@ -978,6 +1096,36 @@ impl CheckCodePrefix {
             CheckCodePrefix::N816 => vec![CheckCode::N816],
             CheckCodePrefix::N817 => vec![CheckCode::N817],
             CheckCodePrefix::N818 => vec![CheckCode::N818],
+            CheckCodePrefix::PLR => vec![
+                CheckCode::PLR0911,
+                CheckCode::PLR0912,
+                CheckCode::PLR0913,
+                CheckCode::PLR0915,
+                CheckCode::PLR0916,
+                CheckCode::PLR1702,
+            ],
+            CheckCodePrefix::PLR0 => vec![
+                CheckCode::PLR0911,
+                CheckCode::PLR0912,
+                CheckCode::PLR0913,
+                CheckCode::PLR0915,
+                CheckCode::PLR0916,
+            ],
+            CheckCodePrefix::PLR09 => vec![
+                CheckCode::PLR0911,
+                CheckCode::PLR0912,
+                CheckCode::PLR0913,
+                CheckCode::PLR0915,
+                CheckCode::PLR0916,
+            ],
+            CheckCodePrefix::PLR0911 => vec![CheckCode::PLR0911],
+            CheckCodePrefix::PLR0912 => vec![CheckCode::PLR0912],
+            CheckCodePrefix::PLR0913 => vec![CheckCode::PLR0913],
+            CheckCodePrefix::PLR0915 => vec![CheckCode::PLR0915],
+            CheckCodePrefix::PLR0916 => vec![CheckCode::PLR0916],
+            CheckCodePrefix::PLR1 => vec![CheckCode::PLR1702],
+            CheckCodePrefix::PLR17 => vec![CheckCode::PLR1702],
+            CheckCodePrefix::PLR1702 => vec![CheckCode::PLR1702],
             CheckCodePrefix::Q => vec![
                 CheckCode::Q000,
                 CheckCode::Q001,
@@ -1000,12 +1148,55 @@ impl CheckCodePrefix {
             CheckCodePrefix::Q001 => vec![CheckCode::Q001],
             CheckCodePrefix::Q002 => vec![CheckCode::Q002],
             CheckCodePrefix::Q003 => vec![CheckCode::Q003],
-            CheckCodePrefix::RUF => vec![CheckCode::RUF001, CheckCode::RUF002, CheckCode::RUF003],
-            CheckCodePrefix::RUF0 => vec![CheckCode::RUF001, CheckCode::RUF002, CheckCode::RUF003],
-            CheckCodePrefix::RUF00 => vec![CheckCode::RUF001, CheckCode::RUF002, CheckCode::RUF003],
+            CheckCodePrefix::RUF => vec![
+                CheckCode::RUF001,
+                CheckCode::RUF002,
+                CheckCode::RUF003,
+                CheckCode::RUF004,
+                CheckCode::RUF005,
+                CheckCode::RUF006,
+                CheckCode::RUF007,
+                CheckCode::RUF008,
+                CheckCode::RUF009,
+                CheckCode::RUF010,
+                CheckCode::RUF100,
+            ],
+            CheckCodePrefix::RUF0 => vec![
+                CheckCode::RUF001,
+                CheckCode::RUF002,
+                CheckCode::RUF003,
+                CheckCode::RUF004,
+                CheckCode::RUF005,
+                CheckCode::RUF006,
+                CheckCode::RUF007,
+                CheckCode::RUF008,
+                CheckCode::RUF009,
+                CheckCode::RUF010,
+            ],
+            CheckCodePrefix::RUF00 => vec![
+                CheckCode::RUF001,
+                CheckCode::RUF002,
+                CheckCode::RUF003,
+                CheckCode::RUF004,
+                CheckCode::RUF005,
+                CheckCode::RUF006,
+                CheckCode::RUF007,
+                CheckCode::RUF008,
+                CheckCode::RUF009,
+            ],
             CheckCodePrefix::RUF001 => vec![CheckCode::RUF001],
             CheckCodePrefix::RUF002 => vec![CheckCode::RUF002],
             CheckCodePrefix::RUF003 => vec![CheckCode::RUF003],
+            CheckCodePrefix::RUF004 => vec![CheckCode::RUF004],
+            CheckCodePrefix::RUF005 => vec![CheckCode::RUF005],
+            CheckCodePrefix::RUF006 => vec![CheckCode::RUF006],
+            CheckCodePrefix::RUF007 => vec![CheckCode::RUF007],
+            CheckCodePrefix::RUF008 => vec![CheckCode::RUF008],
+            CheckCodePrefix::RUF009 => vec![CheckCode::RUF009],
+            CheckCodePrefix::RUF010 => vec![CheckCode::RUF010],
+            CheckCodePrefix::RUF1 => vec![CheckCode::RUF100],
+            CheckCodePrefix::RUF10 => vec![CheckCode::RUF100],
+            CheckCodePrefix::RUF100 => vec![CheckCode::RUF100],
             CheckCodePrefix::S => vec![
                 CheckCode::S101,
                 CheckCode::S102,
@@ -1093,10 +1284,21 @@ impl CheckCodePrefix {
             CheckCodePrefix::U010 => vec![CheckCode::U010],
             CheckCodePrefix::U011 => vec![CheckCode::U011],
             CheckCodePrefix::U012 => vec![CheckCode::U012],
-            CheckCodePrefix::W => vec![CheckCode::W292, CheckCode::W605],
+            CheckCodePrefix::W => vec![
+                CheckCode::W191,
+                CheckCode::W292,
+                CheckCode::W505,
+                CheckCode::W605,
+            ],
+            CheckCodePrefix::W1 => vec![CheckCode::W191],
+            CheckCodePrefix::W19 => vec![CheckCode::W191],
+            CheckCodePrefix::W191 => vec![CheckCode::W191],
             CheckCodePrefix::W2 => vec![CheckCode::W292],
             CheckCodePrefix::W29 => vec![CheckCode::W292],
             CheckCodePrefix::W292 => vec![CheckCode::W292],
+            CheckCodePrefix::W5 => vec![CheckCode::W505],
+            CheckCodePrefix::W50 => vec![CheckCode::W505],
+            CheckCodePrefix::W505 => vec![CheckCode::W505],
             CheckCodePrefix::W6 => vec![CheckCode::W605],
             CheckCodePrefix::W60 => vec![CheckCode::W605],
             CheckCodePrefix::W605 => vec![CheckCode::W605],
@@ -1138,6 +1340,34 @@ impl CheckCodePrefix {
             CheckCodePrefix::YTT301 => vec![CheckCode::YTT301],
             CheckCodePrefix::YTT302 => vec![CheckCode::YTT302],
             CheckCodePrefix::YTT303 => vec![CheckCode::YTT303],
+            CheckCodePrefix::Recommended => CheckCode::iter()
+                .filter(|code| {
+                    matches!(
+                        code.category(),
+                        CheckCategory::Pyflakes
+                            | CheckCategory::Pycodestyle
+                            | CheckCategory::Flake8Bugbear
+                    )
+                })
+                .collect(),
+            CheckCodePrefix::Strict => CheckCode::iter()
+                .filter(|code| {
+                    matches!(
+                        code.category(),
+                        CheckCategory::Pyflakes
+                            | CheckCategory::Pycodestyle
+                            | CheckCategory::Flake8Bugbear
+                            | CheckCategory::Flake8Comprehensions
+                            | CheckCategory::Flake8Builtins
+                            | CheckCategory::Flake8Print
+                            | CheckCategory::PEP8Naming
+                            | CheckCategory::Pyupgrade
+                            | CheckCategory::Flake8Annotations
+                    )
+                })
+                .collect(),
+            CheckCodePrefix::Pedantic => CheckCode::iter().collect(),
+            CheckCodePrefix::All => CheckCode::iter().collect(),
         }
     }
 }
@@ -1273,6 +1503,26 @@ impl CheckCodePrefix {
             CheckCodePrefix::D418 => PrefixSpecificity::Explicit,
             CheckCodePrefix::D419 => PrefixSpecificity::Explicit,
             CheckCodePrefix::E => PrefixSpecificity::Category,
+            CheckCodePrefix::E1 => PrefixSpecificity::Hundreds,
+            CheckCodePrefix::E10 => PrefixSpecificity::Tens,
+            CheckCodePrefix::E101 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E2 => PrefixSpecificity::Hundreds,
+            CheckCodePrefix::E20 => PrefixSpecificity::Tens,
+            CheckCodePrefix::E201 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E202 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E21 => PrefixSpecificity::Tens,
+            CheckCodePrefix::E211 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E26 => PrefixSpecificity::Tens,
+            CheckCodePrefix::E261 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E262 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E265 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E3 => PrefixSpecificity::Hundreds,
+            CheckCodePrefix::E30 => PrefixSpecificity::Tens,
+            CheckCodePrefix::E301 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E302 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E303 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E304 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::E305 => PrefixSpecificity::Explicit,
             CheckCodePrefix::E4 => PrefixSpecificity::Hundreds,
             CheckCodePrefix::E40 => PrefixSpecificity::Tens,
             CheckCodePrefix::E402 => PrefixSpecificity::Explicit,
@@ -1334,6 +1584,8 @@ impl CheckCodePrefix {
             CheckCodePrefix::F72 => PrefixSpecificity::Tens,
             CheckCodePrefix::F722 => PrefixSpecificity::Explicit,
             CheckCodePrefix::F8 => PrefixSpecificity::Hundreds,
+            CheckCodePrefix::F81 => PrefixSpecificity::Tens,
+            CheckCodePrefix::F811 => PrefixSpecificity::Explicit,
             CheckCodePrefix::F82 => PrefixSpecificity::Tens,
             CheckCodePrefix::F821 => PrefixSpecificity::Explicit,
             CheckCodePrefix::F822 => PrefixSpecificity::Explicit,
@@ -1349,10 +1601,6 @@ impl CheckCodePrefix {
             CheckCodePrefix::I0 => PrefixSpecificity::Hundreds,
             CheckCodePrefix::I00 => PrefixSpecificity::Tens,
             CheckCodePrefix::I001 => PrefixSpecificity::Explicit,
-            CheckCodePrefix::M => PrefixSpecificity::Category,
-            CheckCodePrefix::M0 => PrefixSpecificity::Hundreds,
-            CheckCodePrefix::M00 => PrefixSpecificity::Tens,
-            CheckCodePrefix::M001 => PrefixSpecificity::Explicit,
             CheckCodePrefix::N => PrefixSpecificity::Category,
             CheckCodePrefix::N8 => PrefixSpecificity::Hundreds,
             CheckCodePrefix::N80 => PrefixSpecificity::Tens,
@@ -1372,6 +1620,17 @@ impl CheckCodePrefix {
             CheckCodePrefix::N816 => PrefixSpecificity::Explicit,
             CheckCodePrefix::N817 => PrefixSpecificity::Explicit,
             CheckCodePrefix::N818 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::PLR => PrefixSpecificity::Category,
+            CheckCodePrefix::PLR0 => PrefixSpecificity::Hundreds,
+            CheckCodePrefix::PLR09 => PrefixSpecificity::Tens,
+            CheckCodePrefix::PLR0911 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::PLR0912 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::PLR0913 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::PLR0915 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::PLR0916 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::PLR1 => PrefixSpecificity::Hundreds,
+            CheckCodePrefix::PLR17 => PrefixSpecificity::Tens,
+            CheckCodePrefix::PLR1702 => PrefixSpecificity::Explicit,
             CheckCodePrefix::Q => PrefixSpecificity::Category,
             CheckCodePrefix::Q0 => PrefixSpecificity::Hundreds,
             CheckCodePrefix::Q00 => PrefixSpecificity::Tens,
@@ -1385,6 +1644,16 @@ impl CheckCodePrefix {
             CheckCodePrefix::RUF001 => PrefixSpecificity::Explicit,
             CheckCodePrefix::RUF002 => PrefixSpecificity::Explicit,
             CheckCodePrefix::RUF003 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::RUF004 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::RUF005 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::RUF006 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::RUF007 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::RUF008 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::RUF009 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::RUF010 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::RUF1 => PrefixSpecificity::Hundreds,
+            CheckCodePrefix::RUF10 => PrefixSpecificity::Tens,
+            CheckCodePrefix::RUF100 => PrefixSpecificity::Explicit,
             CheckCodePrefix::S => PrefixSpecificity::Category,
             CheckCodePrefix::S1 => PrefixSpecificity::Hundreds,
             CheckCodePrefix::S10 => PrefixSpecificity::Tens,
@@ -1416,9 +1685,15 @@ impl CheckCodePrefix {
             CheckCodePrefix::U011 => PrefixSpecificity::Explicit,
             CheckCodePrefix::U012 => PrefixSpecificity::Explicit,
             CheckCodePrefix::W => PrefixSpecificity::Category,
+            CheckCodePrefix::W1 => PrefixSpecificity::Hundreds,
+            CheckCodePrefix::W19 => PrefixSpecificity::Tens,
+            CheckCodePrefix::W191 => PrefixSpecificity::Explicit,
             CheckCodePrefix::W2 => PrefixSpecificity::Hundreds,
             CheckCodePrefix::W29 => PrefixSpecificity::Tens,
             CheckCodePrefix::W292 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::W5 => PrefixSpecificity::Hundreds,
+            CheckCodePrefix::W50 => PrefixSpecificity::Tens,
+            CheckCodePrefix::W505 => PrefixSpecificity::Explicit,
             CheckCodePrefix::W6 => PrefixSpecificity::Hundreds,
             CheckCodePrefix::W60 => PrefixSpecificity::Tens,
             CheckCodePrefix::W605 => PrefixSpecificity::Explicit,
@@ -1439,6 +1714,10 @@ impl CheckCodePrefix {
             CheckCodePrefix::YTT301 => PrefixSpecificity::Explicit,
             CheckCodePrefix::YTT302 => PrefixSpecificity::Explicit,
             CheckCodePrefix::YTT303 => PrefixSpecificity::Explicit,
+            CheckCodePrefix::Recommended => PrefixSpecificity::All,
+            CheckCodePrefix::Strict => PrefixSpecificity::All,
+            CheckCodePrefix::Pedantic => PrefixSpecificity::All,
+            CheckCodePrefix::All => PrefixSpecificity::All,
         }
     }
 }
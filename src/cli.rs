@@ -7,23 +7,25 @@ use log::warn;
 use regex::Regex;
 
 use crate::checks_gen::CheckCodePrefix;
+use crate::flake8_to_ruff::plugin::Plugin;
+use crate::import_graph::GraphFormat;
 use crate::logging::LogLevel;
-use crate::printer::SerializationFormat;
+use crate::message::{ColumnEncoding, Severity};
+use crate::printer::{ColorChoice, SerializationFormat};
 use crate::settings::configuration::Configuration;
-use crate::settings::types::{PatternPrefixPair, PerFileIgnore, PythonVersion};
+use crate::settings::types::{
+    parse_check_code_prefix, PatternPrefixPair, PerFileIgnore, PythonVersion,
+};
 
 #[derive(Debug, Parser)]
 #[command(author, about = "ruff: An extremely fast Python linter.")]
 #[command(version)]
 pub struct Cli {
-    #[arg(required = true)]
+    #[arg(required_unless_present_any = ["clean", "daemon", "migrate_config", "debug_ast", "debug_tokens"])]
     pub files: Vec<PathBuf>,
     /// Path to the `pyproject.toml` file to use for configuration.
     #[arg(long)]
     pub config: Option<PathBuf>,
-    /// Enable verbose logging.
-    #[arg(short, long, group = "verbosity")]
-    pub verbose: bool,
     /// Only log errors.
     #[arg(short, long, group = "verbosity")]
     pub quiet: bool,
@@ -31,6 +33,11 @@ pub struct Cli {
     /// errors).
     #[arg(short, long, group = "verbosity")]
     pub silent: bool,
+    /// Enable verbose logging. Pass twice (`-vv`) to also report per-phase
+    /// timing within each file, on top of `-v`'s per-file timing and cache
+    /// hit/miss stats.
+    #[arg(short, long, group = "verbosity", action = clap::ArgAction::Count)]
+    pub verbose: u8,
     /// Exit with status code "0", even upon detecting errors.
     #[arg(short, long)]
     pub exit_zero: bool,
@@ -42,23 +49,92 @@ pub struct Cli {
     fix: bool,
     #[clap(long, overrides_with("fix"), hide = true)]
     no_fix: bool,
+    /// Include fixes that may not preserve the original behavior of the code
+    /// being fixed, in addition to safe fixes.
+    #[arg(long)]
+    pub unsafe_fixes: bool,
+    /// After applying fixes, re-lint the result and fail if any rule that
+    /// produced a fix is still reporting -- a fix that doesn't converge.
+    /// For catching autofix bugs during rule development; doubles lint
+    /// time, so it's not meant for routine use. Ignored when reading from
+    /// stdin, and a no-op without `--fix`.
+    #[arg(long)]
+    pub check_fix_idempotence: bool,
+    /// Run all checks as though no `# noqa` directives were present, so
+    /// suppressed violations are reported too. Useful for periodically
+    /// auditing how much is being suppressed and whether it's still needed.
+    #[arg(long)]
+    pub ignore_noqa: bool,
     /// Disable cache reads.
     #[arg(short, long)]
     pub no_cache: bool,
+    /// Path to the cache directory. Overrides the `cache-dir` property in
+    /// `pyproject.toml` and the `RUFF_CACHE_DIR` environment variable, if set.
+    #[arg(long)]
+    pub cache_dir: Option<PathBuf>,
+    /// Remove the resolved cache directory and exit, printing what was
+    /// deleted. Useful for discarding stale results without having to hunt
+    /// down the cache location by hand.
+    #[arg(long)]
+    pub clean: bool,
+    /// Run as a long-lived daemon, listening on a Unix domain socket for
+    /// lint requests and serving them with warm settings and cache.
+    #[arg(long)]
+    pub daemon: bool,
+    /// Path to the Unix domain socket the daemon should listen on. Defaults
+    /// to a `daemon.sock` file inside the resolved cache directory.
+    #[arg(long)]
+    pub socket: Option<PathBuf>,
+    /// Run as a Language Server Protocol server over stdio, publishing
+    /// diagnostics and quick-fix code actions for open documents.
+    #[arg(long)]
+    pub server: bool,
+    /// Lint only files that differ from this git ref (e.g. a branch or
+    /// commit), for fast incremental checks.
+    #[arg(long)]
+    pub diff_against: Option<String>,
+    /// Capture the violations from this run into a baseline file at the
+    /// given path, for use with `--baseline` on subsequent runs.
+    #[arg(long)]
+    pub generate_baseline: Option<PathBuf>,
+    /// Suppress violations already present in this baseline file, so that
+    /// only newly introduced violations are reported.
+    #[arg(long)]
+    pub baseline: Option<PathBuf>,
+    /// Print a JSON Schema for the `[tool.ruff]` section of `pyproject.toml`,
+    /// for use by editors and other tooling that validate or autocomplete
+    /// configuration files.
+    #[arg(long)]
+    pub generate_schema: bool,
+    /// Enable rules that are still under active development and selected via
+    /// `"ALL"`. Has no effect on rules enabled explicitly or via a more
+    /// specific prefix.
+    #[arg(long)]
+    pub preview: bool,
+    /// Follow symlinks when discovering files to check. Off by default,
+    /// since following symlinked directories can pull in files well outside
+    /// the project.
+    #[arg(long)]
+    pub follow_symlinks: bool,
     /// List of error codes to enable.
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, value_delimiter = ',', value_parser = parse_check_code_prefix)]
     pub select: Vec<CheckCodePrefix>,
     /// Like --select, but adds additional error codes on top of the selected
     /// ones.
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, value_delimiter = ',', value_parser = parse_check_code_prefix)]
     pub extend_select: Vec<CheckCodePrefix>,
     /// List of error codes to ignore.
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, value_delimiter = ',', value_parser = parse_check_code_prefix)]
     pub ignore: Vec<CheckCodePrefix>,
     /// Like --ignore, but adds additional error codes on top of the ignored
     /// ones.
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, value_delimiter = ',', value_parser = parse_check_code_prefix)]
     pub extend_ignore: Vec<CheckCodePrefix>,
+    /// List of error codes to report as warnings rather than errors: they
+    /// still print and are still fixable, but don't cause a non-zero exit
+    /// code.
+    #[arg(long, value_delimiter = ',', value_parser = parse_check_code_prefix)]
+    pub warnings: Vec<CheckCodePrefix>,
     /// List of paths, used to exclude files and/or directories from checks.
     #[arg(long, value_delimiter = ',')]
     pub exclude: Vec<String>,
@@ -72,12 +148,42 @@ pub struct Cli {
     /// Output serialization format for error messages.
     #[arg(long, value_enum, default_value_t=SerializationFormat::Text)]
     pub format: SerializationFormat,
+    /// The message template to use with `--format template`, e.g. `"{path}:{row}: {code}
+    /// {message}"`. Supported placeholders: `{path}`, `{row}`, `{column}`, `{endrow}`,
+    /// `{endcolumn}`, `{code}`, `{severity}`, and `{message}`. One line is printed per
+    /// violation; unrecognized placeholders are left as-is.
+    #[arg(long)]
+    pub template: Option<String>,
+    /// The unit diagnostic columns are reported in. Defaults to `char`
+    /// (correct for terminals); pass `utf16` for editors and LSP clients,
+    /// which index positions in UTF-16 code units, or `utf8` for tools that
+    /// index into the file's raw bytes.
+    #[arg(long, value_enum, default_value_t=ColumnEncoding::Char)]
+    pub column_encoding: ColumnEncoding,
+    /// Control whether colored output is used. Defaults to matching the
+    /// terminal, honoring `NO_COLOR`; `always` keeps colors even when piped
+    /// (e.g. to `less -R`).
+    #[arg(long, value_enum, default_value_t=ColorChoice::Auto)]
+    pub color: ColorChoice,
+    /// Print the offending source line, with a caret span underneath, for
+    /// each diagnostic (in `--format text` output only).
+    #[arg(long)]
+    pub show_source: bool,
+    /// Print a table of aggregate wall time spent per rule category, sorted
+    /// from slowest to fastest, after the run completes.
+    #[arg(long)]
+    pub timings: bool,
     /// See the files ruff will be run against with the current settings.
     #[arg(long)]
     pub show_files: bool,
     /// See ruff's settings.
     #[arg(long)]
     pub show_settings: bool,
+    /// See the list of implemented lint rules, with their category, message
+    /// template, and autofix availability. Respects `--format json` for
+    /// tooling.
+    #[arg(long)]
+    pub show_checks: bool,
     /// Enable automatic additions of noqa directives to failing lines.
     #[arg(long)]
     pub add_noqa: bool,
@@ -87,13 +193,64 @@ pub struct Cli {
     /// The minimum Python version that should be supported.
     #[arg(long)]
     pub target_version: Option<PythonVersion>,
-    /// Round-trip auto-formatting.
-    // TODO(charlie): This should be a sub-command.
-    #[arg(long, hide = true)]
-    pub autoformat: bool,
+    /// The number of threads to lint files in parallel with. Defaults to the
+    /// number of logical cores; pass `1` to force deterministic sequential
+    /// linting, e.g. when debugging a rule.
+    #[arg(long)]
+    pub threads: Option<usize>,
+    /// Format the given files in place, instead of linting them. Reuses the
+    /// same tokenizer/parser as the linter and unparses the resulting AST,
+    /// which means the AST's own comment-free representation is written
+    /// back out -- so, for now, any file containing a `#` comment is left
+    /// untouched rather than silently stripped.
+    #[arg(long)]
+    pub reformat: bool,
     /// The name of the file when passing it through stdin.
     #[arg(long)]
     pub stdin_filename: Option<String>,
+    /// Build the project's first-party import dependency graph and print it,
+    /// instead of linting. Cycles found while walking the graph are printed
+    /// as warnings.
+    #[arg(long)]
+    pub analyze_graph: bool,
+    /// Output format for `--analyze-graph`.
+    #[arg(long, value_enum, default_value_t=GraphFormat::Json)]
+    pub graph_format: GraphFormat,
+    /// Tolerate up to this many violations without failing the run. Useful
+    /// for an incremental rollout, where a bound can be tightened over
+    /// successive changes instead of gating on a clean run immediately.
+    #[arg(long)]
+    pub max_violations: Option<usize>,
+    /// The minimum violation severity that should cause a non-zero exit
+    /// code. Pass `warning` so that rules demoted via `--warnings` still
+    /// fail CI; defaults to failing on errors only.
+    #[arg(long, value_enum, default_value_t=Severity::Error)]
+    pub error_on: Severity,
+    /// Path to a Flake8 configuration file (e.g., `setup.cfg`, `tox.ini`, or
+    /// `.flake8`) to convert to an equivalent `pyproject.toml`
+    /// `[tool.ruff]` section, printed to stdout instead of linting.
+    #[arg(long)]
+    pub migrate_config: Option<PathBuf>,
+    /// The Flake8 plugins to assume are in use when inferring `select` for
+    /// `--migrate-config`. Inferred from the input file's options and codes
+    /// if omitted.
+    #[arg(long, value_delimiter = ',')]
+    pub migrate_plugin: Vec<Plugin>,
+    /// Print the token stream (with source ranges) for the given Python
+    /// file and exit, for reproducing parser-related bug reports.
+    #[arg(long)]
+    pub debug_tokens: Option<PathBuf>,
+    /// Print the parsed AST (with source ranges) for the given Python file
+    /// and exit, for reproducing parser-related bug reports.
+    #[arg(long)]
+    pub debug_ast: Option<PathBuf>,
+    /// Write the formatted report to the given file instead of stdout,
+    /// leaving stdout free for progress messages and errors. Useful for
+    /// formats like `--format json` that CI systems ingest from a path, and
+    /// sidesteps shell-redirection encoding quirks on Windows. Ignored in
+    /// `--watch` mode, which always prints to the terminal.
+    #[arg(long)]
+    pub output_file: Option<PathBuf>,
 }
 
 impl Cli {
@@ -118,7 +275,9 @@ pub fn extract_log_level(cli: &Cli) -> LogLevel {
         LogLevel::Silent
     } else if cli.quiet {
         LogLevel::Quiet
-    } else if cli.verbose {
+    } else if cli.verbose >= 2 {
+        LogLevel::VeryVerbose
+    } else if cli.verbose == 1 {
         LogLevel::Verbose
     } else {
         LogLevel::Default
@@ -0,0 +1,104 @@
+//! A long-running daemon that keeps `Settings` and the on-disk cache warm
+//! across requests, so editors and pre-commit wrappers can avoid paying
+//! Ruff's startup cost on every invocation.
+//!
+//! Clients connect over a Unix domain socket and send one JSON [`Request`]
+//! per line; the daemon replies with one JSON [`Response`] per line. A
+//! request either names a path to lint from disk (using the warm cache) or
+//! supplies buffer contents directly, e.g. an editor's unsaved changes.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use log::{debug, error};
+use serde::{Deserialize, Serialize};
+
+use crate::autofix::fixer;
+use crate::cache;
+use crate::linter::{lint_path, lint_stdin};
+use crate::message::Message;
+use crate::settings::Settings;
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    path: PathBuf,
+    contents: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum Response {
+    Ok { messages: Vec<Message> },
+    Err { error: String },
+}
+
+/// Bind `socket_path` and serve requests until the process is killed.
+pub fn listen(socket_path: &Path, settings: &Settings, cache_dir: &Path) -> Result<()> {
+    if socket_path.exists() {
+        fs::remove_file(socket_path)?;
+    }
+    let listener = UnixListener::bind(socket_path)?;
+    debug!("Daemon listening on {}", socket_path.display());
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                if let Err(e) = handle_client(stream, settings, cache_dir) {
+                    error!("Error handling daemon client: {e:?}");
+                }
+            }
+            Err(e) => error!("Error accepting daemon connection: {e:?}"),
+        }
+    }
+    Ok(())
+}
+
+fn handle_client(stream: UnixStream, settings: &Settings, cache_dir: &Path) -> Result<()> {
+    let mut writer = stream.try_clone()?;
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) => match lint_request(&request, settings, cache_dir) {
+                Ok(messages) => Response::Ok { messages },
+                Err(e) => Response::Err {
+                    error: e.to_string(),
+                },
+            },
+            Err(e) => Response::Err {
+                error: e.to_string(),
+            },
+        };
+        writeln!(writer, "{}", serde_json::to_string(&response)?)?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+fn lint_request(request: &Request, settings: &Settings, cache_dir: &Path) -> Result<Vec<Message>> {
+    match &request.contents {
+        Some(contents) => lint_stdin(
+            &request.path,
+            contents,
+            settings,
+            &fixer::Mode::None,
+            false,
+            false,
+            false,
+        ),
+        None => lint_path(
+            &request.path,
+            settings,
+            &cache::Mode::ReadWrite,
+            &fixer::Mode::None,
+            cache_dir,
+            false,
+            false,
+            false,
+        ),
+    }
+}
@@ -6,7 +6,7 @@ use rustpython_ast::Location;
 use rustpython_parser::lexer::{LexResult, Tok};
 
 use crate::ast::types::Range;
-use crate::checks::LintSource;
+use crate::checks::{Check, LintSource};
 use crate::{Settings, SourceCodeLocator};
 
 bitflags! {
@@ -33,6 +33,8 @@ impl Flags {
 pub struct Directives {
     pub noqa_line_for: IntMap<usize, usize>,
     pub isort_exclusions: IntSet<usize>,
+    pub ruff_disables: Vec<RuffDisable>,
+    pub fmt_exclusions: IntSet<usize>,
 }
 
 pub fn extract_directives(
@@ -51,9 +53,118 @@ pub fn extract_directives(
         } else {
             Default::default()
         },
+        ruff_disables: extract_ruff_disables(lxr, locator),
+        fmt_exclusions: extract_fmt_exclusions(lxr, locator),
     }
 }
 
+/// What a `# ruff: disable` (or `# ruff: disable-next-line`) region suppresses:
+/// every rule, or only a specific set of codes, mirroring `# noqa` vs.
+/// `# noqa: CODE`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RuffDisableScope {
+    All,
+    Codes(Vec<String>),
+}
+
+/// A region (inclusive, 1-indexed rows) over which `scope` is suppressed,
+/// for violations that span too many lines for a single `# noqa` to sit on
+/// sensibly.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RuffDisable {
+    pub start_row: usize,
+    pub end_row: usize,
+    pub scope: RuffDisableScope,
+}
+
+/// If `comment_text` is `directive` (optionally followed by `=CODE,CODE`),
+/// return the codes suffix (`None` if the directive was bare).
+fn match_directive<'a>(comment_text: &'a str, directive: &str) -> Option<Option<&'a str>> {
+    let rest = comment_text.strip_prefix(directive)?;
+    if rest.is_empty() {
+        Some(None)
+    } else {
+        rest.strip_prefix('=').map(Some)
+    }
+}
+
+fn codes_to_scope(codes: Option<&str>) -> RuffDisableScope {
+    match codes {
+        Some(codes) => RuffDisableScope::Codes(
+            codes
+                .split(',')
+                .map(|code| code.trim().to_string())
+                .filter(|code| !code.is_empty())
+                .collect(),
+        ),
+        None => RuffDisableScope::All,
+    }
+}
+
+/// Extract the `# ruff: disable` / `# ruff: disable-next-line` / `# ruff:
+/// enable` regions from the source, for violations (e.g. over a multi-line
+/// statement) that a single-line `# noqa` can't suppress sensibly.
+pub fn extract_ruff_disables(lxr: &[LexResult], locator: &SourceCodeLocator) -> Vec<RuffDisable> {
+    let mut disables: Vec<RuffDisable> = Vec::new();
+    let mut off: Option<(usize, RuffDisableScope)> = None;
+    for (start, tok, end) in lxr.iter().flatten() {
+        if matches!(tok, Tok::Comment) {
+            let comment_text = locator.slice_source_code_range(&Range {
+                location: *start,
+                end_location: *end,
+            });
+            if let Some(codes) = match_directive(comment_text, "# ruff: disable-next-line") {
+                let row = end.row() + 1;
+                disables.push(RuffDisable {
+                    start_row: row,
+                    end_row: row,
+                    scope: codes_to_scope(codes),
+                });
+            } else if let Some(codes) = match_directive(comment_text, "# ruff: disable") {
+                off = Some((start.row(), codes_to_scope(codes)));
+            } else if match_directive(comment_text, "# ruff: enable").is_some() {
+                if let Some((start_row, scope)) = off.take() {
+                    disables.push(RuffDisable {
+                        start_row: start_row + 1,
+                        end_row: start.row(),
+                        scope,
+                    });
+                }
+            }
+        } else if matches!(tok, Tok::EndOfFile) {
+            if let Some((start_row, scope)) = off.take() {
+                disables.push(RuffDisable {
+                    start_row: start_row + 1,
+                    end_row: end.row(),
+                    scope,
+                });
+            }
+            break;
+        }
+    }
+    disables
+}
+
+/// Remove any checks suppressed by a `# ruff: disable` region.
+pub fn filter_disabled(checks: &mut Vec<Check>, disables: &[RuffDisable]) {
+    if disables.is_empty() {
+        return;
+    }
+    checks.retain(|check| {
+        let row = check.location.row();
+        !disables.iter().any(|disable| {
+            row >= disable.start_row
+                && row <= disable.end_row
+                && match &disable.scope {
+                    RuffDisableScope::All => true,
+                    RuffDisableScope::Codes(codes) => {
+                        codes.iter().any(|code| code == check.kind.code().as_ref())
+                    }
+                }
+        })
+    });
+}
+
 /// Extract a mapping from logical line to noqa line.
 pub fn extract_noqa_line_for(lxr: &[LexResult]) -> IntMap<usize, usize> {
     let mut noqa_line_for: IntMap<usize, usize> = IntMap::default();
@@ -111,10 +222,50 @@ pub fn extract_isort_exclusions(lxr: &[LexResult], locator: &SourceCodeLocator)
     exclusions
 }
 
+/// Extract a set of lines over which to disable layout-affecting fixes
+/// (isort's import rewriting, and the blank-line fixes), so ruff doesn't
+/// fight hand-formatted code that Black also leaves alone.
+pub fn extract_fmt_exclusions(lxr: &[LexResult], locator: &SourceCodeLocator) -> IntSet<usize> {
+    let mut exclusions: IntSet<usize> = IntSet::default();
+    let mut off: Option<&Location> = None;
+    for (start, tok, end) in lxr.iter().flatten() {
+        if matches!(tok, Tok::Comment) {
+            let comment_text = locator.slice_source_code_range(&Range {
+                location: *start,
+                end_location: *end,
+            });
+            if off.is_some() {
+                if comment_text == "# fmt: on" {
+                    if let Some(start) = off {
+                        for row in start.row() + 1..=end.row() {
+                            exclusions.insert(row);
+                        }
+                    }
+                    off = None;
+                }
+            } else {
+                if comment_text.contains("fmt: skip") || comment_text.contains("fmt:skip") {
+                    exclusions.insert(start.row());
+                } else if comment_text == "# fmt: off" {
+                    off = Some(start);
+                }
+            }
+        } else if matches!(tok, Tok::EndOfFile) {
+            if let Some(start) = off {
+                for row in start.row() + 1..=end.row() {
+                    exclusions.insert(row);
+                }
+            }
+            break;
+        }
+    }
+    exclusions
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
-    use nohash_hasher::IntMap;
+    use nohash_hasher::{IntMap, IntSet};
     use rustpython_parser::lexer;
     use rustpython_parser::lexer::LexResult;
 
@@ -203,4 +354,75 @@ z = x + 1",
 
         Ok(())
     }
+
+    #[test]
+    fn ruff_disables() {
+        use crate::directives::{extract_ruff_disables, RuffDisable, RuffDisableScope};
+        use crate::source_code_locator::SourceCodeLocator;
+
+        let extract = |contents: &str| {
+            let lxr: Vec<LexResult> = lexer::make_tokenizer(contents).collect();
+            let locator = SourceCodeLocator::new(contents);
+            extract_ruff_disables(&lxr, &locator)
+        };
+
+        assert_eq!(extract("x = 1\ny = 2"), vec![]);
+
+        assert_eq!(
+            extract("# ruff: disable\nx = 1\n# ruff: enable\ny = 2"),
+            vec![RuffDisable {
+                start_row: 2,
+                end_row: 3,
+                scope: RuffDisableScope::All,
+            }]
+        );
+
+        assert_eq!(
+            extract("# ruff: disable=E501,F401\nx = 1\n# ruff: enable"),
+            vec![RuffDisable {
+                start_row: 2,
+                end_row: 3,
+                scope: RuffDisableScope::Codes(vec!["E501".to_string(), "F401".to_string()]),
+            }]
+        );
+
+        // No matching `# ruff: enable`: the region runs to the end of the file.
+        let disables = extract("# ruff: disable\nx = 1\ny = 2");
+        assert_eq!(disables.len(), 1);
+        assert_eq!(disables[0].start_row, 2);
+        assert_eq!(disables[0].scope, RuffDisableScope::All);
+
+        assert_eq!(
+            extract("# ruff: disable-next-line=F821\nundefined_name\ny = 2"),
+            vec![RuffDisable {
+                start_row: 2,
+                end_row: 2,
+                scope: RuffDisableScope::Codes(vec!["F821".to_string()]),
+            }]
+        );
+    }
+
+    #[test]
+    fn fmt_exclusions() {
+        use crate::directives::extract_fmt_exclusions;
+        use crate::source_code_locator::SourceCodeLocator;
+
+        let extract = |contents: &str| {
+            let lxr: Vec<LexResult> = lexer::make_tokenizer(contents).collect();
+            let locator = SourceCodeLocator::new(contents);
+            extract_fmt_exclusions(&lxr, &locator)
+        };
+
+        assert_eq!(extract("x = 1\ny = 2"), IntSet::default());
+
+        assert_eq!(
+            extract("# fmt: off\nx  =  1\n# fmt: on\ny = 2"),
+            IntSet::from_iter([2, 3])
+        );
+
+        assert_eq!(
+            extract("x  =  1  # fmt: skip\ny = 2"),
+            IntSet::from_iter([1])
+        );
+    }
 }
@@ -0,0 +1,274 @@
+//! RUF009: flag duplicated blocks of code across (or within) files, in the
+//! spirit of pylint's `R0801` ("similar lines in N files").
+//!
+//! Detection is line-based rather than AST-based: each file is reduced to
+//! its non-blank, non-comment-only lines with whitespace normalized away,
+//! then a rolling window of `min_duplicate_lines` consecutive lines is
+//! hashed. A hash seen at more than one position means the lines in between
+//! are identical once normalized; matches are greedily extended so a long
+//! duplicated block is reported once, not once per overlapping window.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use rustpython_parser::ast::Location;
+
+use crate::checks::{CheckCode, CheckKind};
+use crate::fs::{self, relativize_path};
+use crate::message::{Message, Severity};
+use crate::settings::Settings;
+
+/// A file reduced to the subset of its lines worth comparing, alongside the
+/// original line number (1-indexed) each one came from.
+struct NormalizedFile {
+    path: PathBuf,
+    lines: Vec<(usize, String)>,
+}
+
+fn normalize(contents: &str) -> Vec<(usize, String)> {
+    contents
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                None
+            } else {
+                Some((i + 1, trimmed.to_string()))
+            }
+        })
+        .collect()
+}
+
+fn hash_window(window: &[(usize, String)]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for (_, line) in window {
+        line.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// How many of the normalized lines starting at `a_start` in `a` and
+/// `b_start` in `b` are identical, beyond the `min_duplicate_lines` already
+/// confirmed equal by a matching window hash.
+fn extend_match(a: &[(usize, String)], a_start: usize, b: &[(usize, String)], b_start: usize) -> usize {
+    let max_len = (a.len() - a_start).min(b.len() - b_start);
+    (0..max_len)
+        .take_while(|&i| a[a_start + i].1 == b[b_start + i].1)
+        .count()
+}
+
+fn location(line: usize) -> Location {
+    Location::new(line, 1)
+}
+
+/// Scan `files` for duplicated blocks of at least `settings.min_duplicate_lines`
+/// normalized lines, reporting each side of a match as `RUF009` with a
+/// pointer to the other occurrence.
+pub fn find_duplicates(files: &[PathBuf], settings: &Settings) -> Result<Vec<Message>> {
+    let min_lines = settings.min_duplicate_lines;
+    if min_lines == 0 {
+        return Ok(Vec::new());
+    }
+
+    let normalized: Vec<NormalizedFile> = files
+        .iter()
+        .map(|path| {
+            let contents = fs::read_file(path)?;
+            Ok(NormalizedFile {
+                path: path.clone(),
+                lines: normalize(&contents),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    let severity = if settings.warnings.contains(&CheckCode::RUF009) {
+        Severity::Warning
+    } else {
+        Severity::Error
+    };
+
+    Ok(find_duplicates_in(&normalized, min_lines, severity))
+}
+
+/// The disk-free core of `find_duplicates`, taking already-normalized files
+/// so it can be exercised directly in tests.
+fn find_duplicates_in(normalized: &[NormalizedFile], min_lines: usize, severity: Severity) -> Vec<Message> {
+    // Map a window's hash to every (file, start-index) it was seen at.
+    let mut windows: HashMap<u64, Vec<(usize, usize)>> = HashMap::new();
+    for (file_idx, file) in normalized.iter().enumerate() {
+        for (start, window) in file.lines.windows(min_lines).enumerate() {
+            let hash = hash_window(window);
+            windows.entry(hash).or_default().push((file_idx, start));
+        }
+    }
+
+    // Track, per pair of files, which positions in each already belong to a
+    // reported match against that specific partner. This is scoped per pair
+    // (rather than one flat per-file grid) so that a long duplicated block
+    // is reported once per pair rather than once per overlapping window
+    // within it, without a block duplicated across three or more files
+    // having its later pairs (e.g. a-c, b-c) suppressed just because an
+    // earlier pair (a-b) already covered those same positions in a or b.
+    let mut covered: HashMap<(usize, usize), Vec<bool>> = HashMap::new();
+
+    let mut messages = Vec::new();
+    let mut hashes: Vec<&u64> = windows.keys().collect();
+    hashes.sort_unstable();
+    for hash in hashes {
+        let occurrences = &windows[hash];
+        if occurrences.len() < 2 {
+            continue;
+        }
+        // Compare every pair of occurrences, not just the first two: a block
+        // duplicated across N files/locations has up to `N choose 2` pairs,
+        // all of which need to be reported (modulo the `covered` check
+        // below), matching pylint R0801's "similar lines in N files".
+        for a_idx in 0..occurrences.len() {
+            for b_idx in (a_idx + 1)..occurrences.len() {
+                let (file_a, start_a) = occurrences[a_idx];
+                let (file_b, start_b) = occurrences[b_idx];
+                if file_a == file_b && start_a == start_b {
+                    continue;
+                }
+                if is_covered(&covered, file_a, file_b, start_a)
+                    || is_covered(&covered, file_b, file_a, start_b)
+                {
+                    continue;
+                }
+
+                let length = extend_match(
+                    &normalized[file_a].lines,
+                    start_a,
+                    &normalized[file_b].lines,
+                    start_b,
+                );
+                if length < min_lines {
+                    continue;
+                }
+
+                mark_covered(&mut covered, file_a, file_b, normalized[file_a].lines.len(), start_a, length);
+                mark_covered(&mut covered, file_b, file_a, normalized[file_b].lines.len(), start_b, length);
+
+                let a_line = normalized[file_a].lines[start_a].0;
+                let b_line = normalized[file_b].lines[start_b].0;
+                messages.push(duplicate_message(
+                    &normalized[file_a].path,
+                    a_line,
+                    length,
+                    &normalized[file_b].path,
+                    b_line,
+                    severity,
+                ));
+                messages.push(duplicate_message(
+                    &normalized[file_b].path,
+                    b_line,
+                    length,
+                    &normalized[file_a].path,
+                    a_line,
+                    severity,
+                ));
+            }
+        }
+    }
+
+    messages
+}
+
+/// Whether position `pos` in `file` has already been reported as part of a
+/// duplicate match against `other`.
+fn is_covered(covered: &HashMap<(usize, usize), Vec<bool>>, file: usize, other: usize, pos: usize) -> bool {
+    covered.get(&(file, other)).map_or(false, |bits| bits[pos])
+}
+
+/// Record that `file[start..start + length]` has been reported as part of a
+/// duplicate match against `other`.
+fn mark_covered(
+    covered: &mut HashMap<(usize, usize), Vec<bool>>,
+    file: usize,
+    other: usize,
+    file_len: usize,
+    start: usize,
+    length: usize,
+) {
+    let bits = covered.entry((file, other)).or_insert_with(|| vec![false; file_len]);
+    for i in start..start + length {
+        bits[i] = true;
+    }
+}
+
+fn duplicate_message(
+    path: &Path,
+    line: usize,
+    length: usize,
+    other_path: &Path,
+    other_line: usize,
+    severity: Severity,
+) -> Message {
+    let other = format!("{}:{other_line}", relativize_path(other_path));
+    Message {
+        kind: CheckKind::DuplicateCode(length, other),
+        fixed: false,
+        location: location(line),
+        end_location: location(line + length),
+        filename: path.to_string_lossy().to_string(),
+        fix: None,
+        alternatives: Vec::new(),
+        severity,
+        // The duplicate lives in another file, which `Annotation` can't
+        // point at (it's scoped to the `Check`'s own file); `other`, above,
+        // carries that cross-file reference as plain text instead.
+        related: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_duplicates_in, normalize, NormalizedFile};
+    use crate::message::Severity;
+
+    fn file(path: &str, contents: &str) -> NormalizedFile {
+        NormalizedFile {
+            path: path.into(),
+            lines: normalize(contents),
+        }
+    }
+
+    const BLOCK: &str = "def f():\n    a = 1\n    b = 2\n    c = 3\n    return a + b + c\n";
+
+    #[test]
+    fn two_way_duplicate_is_reported_once_per_side() {
+        let files = vec![file("a.py", BLOCK), file("b.py", BLOCK)];
+        let messages = find_duplicates_in(&files, 4, Severity::Error);
+
+        assert_eq!(messages.len(), 2);
+    }
+
+    #[test]
+    fn three_way_duplicate_reports_every_pair() {
+        let files = vec![file("a.py", BLOCK), file("b.py", BLOCK), file("c.py", BLOCK)];
+        let messages = find_duplicates_in(&files, 4, Severity::Error);
+
+        // 3 files duplicating the same block is `3 choose 2` = 3 pairs, each
+        // reported from both sides: 6 messages total. Before the fix, only
+        // the first pair (a.py, b.py) was ever compared, so c.py's copy was
+        // silently dropped and this assertion would see 2, not 6.
+        assert_eq!(messages.len(), 6);
+
+        let filenames: Vec<&str> = messages.iter().map(|m| m.filename.as_str()).collect();
+        assert!(filenames.iter().any(|&f| f == "a.py"));
+        assert!(filenames.iter().any(|&f| f == "b.py"));
+        assert!(filenames.iter().any(|&f| f == "c.py"));
+    }
+
+    #[test]
+    fn no_duplicate_below_min_lines_is_ignored() {
+        let files = vec![file("a.py", "x = 1\n"), file("b.py", "x = 1\n")];
+        let messages = find_duplicates_in(&files, 4, Severity::Error);
+
+        assert!(messages.is_empty());
+    }
+}
@@ -0,0 +1,121 @@
+use rustpython_ast::{Location, Stmt};
+
+use crate::autofix::Fix;
+use crate::source_code_locator::SourceCodeLocator;
+
+/// Whether the scanner in `end_of_arguments` is inside plain code, a `#`
+/// comment, or a string literal (and if a string, which quote closes it).
+#[derive(Clone, Copy)]
+enum ScanState {
+    Code,
+    Comment,
+    Str { quote: char, triple: bool },
+}
+
+/// Advance `row`/`col` past `ch`, the same bookkeeping at every call site.
+fn advance(row: &mut usize, col: &mut usize, ch: char) {
+    if ch == '\n' {
+        *row += 1;
+        *col = 0;
+    } else {
+        *col += 1;
+    }
+}
+
+/// Find the position immediately after a function's closing `)`, by
+/// scanning forward from `stmt` and tracking paren depth. Simpler than
+/// reconstructing the signature via the CST, and just as exact, as long as
+/// the scan also tracks string and comment state: a `)` inside a string
+/// literal (e.g. a default argument like `x=")"`) or a `#` comment isn't a
+/// real closing paren, and counting it as one would splice the return
+/// annotation into the middle of the literal.
+fn end_of_arguments(locator: &SourceCodeLocator, stmt: &Stmt) -> Option<Location> {
+    let text = locator.slice_source_code_at(&stmt.location);
+    let chars: Vec<char> = text.chars().collect();
+    let mut depth = 0i32;
+    let mut row = stmt.location.row();
+    let mut col = stmt.location.column();
+    let mut state = ScanState::Code;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let ch = chars[i];
+        match state {
+            ScanState::Comment => {
+                advance(&mut row, &mut col, ch);
+                i += 1;
+                if ch == '\n' {
+                    state = ScanState::Code;
+                }
+            }
+            ScanState::Str { quote, triple } => {
+                // An escaped character (including an escaped quote) can't
+                // close the string; skip over it without reinterpreting it.
+                if ch == '\\' && i + 1 < chars.len() {
+                    advance(&mut row, &mut col, ch);
+                    advance(&mut row, &mut col, chars[i + 1]);
+                    i += 2;
+                    continue;
+                }
+                let closing_len = if ch != quote {
+                    None
+                } else if triple {
+                    (chars.get(i + 1) == Some(&quote) && chars.get(i + 2) == Some(&quote))
+                        .then_some(3)
+                } else {
+                    Some(1)
+                };
+                if let Some(len) = closing_len {
+                    for &closing_ch in &chars[i..i + len] {
+                        advance(&mut row, &mut col, closing_ch);
+                    }
+                    i += len;
+                    state = ScanState::Code;
+                    continue;
+                }
+                advance(&mut row, &mut col, ch);
+                i += 1;
+            }
+            ScanState::Code => {
+                if ch == '#' {
+                    state = ScanState::Comment;
+                    advance(&mut row, &mut col, ch);
+                    i += 1;
+                    continue;
+                }
+                if ch == '"' || ch == '\'' {
+                    let triple = chars.get(i + 1) == Some(&ch) && chars.get(i + 2) == Some(&ch);
+                    let len = if triple { 3 } else { 1 };
+                    for &opening_ch in &chars[i..i + len] {
+                        advance(&mut row, &mut col, opening_ch);
+                    }
+                    i += len;
+                    state = ScanState::Str { quote: ch, triple };
+                    continue;
+                }
+                if ch == '(' {
+                    depth += 1;
+                } else if ch == ')' {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(Location::new(row, col + 1));
+                    }
+                }
+                advance(&mut row, &mut col, ch);
+                i += 1;
+            }
+        }
+    }
+    None
+}
+
+/// (ANN201, ANN202, ANN204, ANN205, ANN206) Insert `-> annotation` right
+/// after a function's closing parenthesis.
+pub fn add_return_annotation(
+    locator: &SourceCodeLocator,
+    stmt: &Stmt,
+    annotation: &str,
+) -> Option<Fix> {
+    let at = end_of_arguments(locator, stmt)?;
+    Some(Fix::insertion(format!(" -> {annotation}"), at))
+}
@@ -1,3 +1,4 @@
+mod fixes;
 pub mod plugins;
 pub mod settings;
 
@@ -48,6 +49,9 @@ mod tests {
                     suppress_dummy_args: true,
                     suppress_none_returning: false,
                     allow_star_arg_any: false,
+                    allow_untyped_decorated: vec![],
+                    ignore_fully_untyped: false,
+                    ignore_names: vec![],
                 },
                 ..Settings::for_rules(vec![
                     CheckCode::ANN001,
@@ -74,6 +78,9 @@ mod tests {
                     suppress_dummy_args: false,
                     suppress_none_returning: false,
                     allow_star_arg_any: false,
+                    allow_untyped_decorated: vec![],
+                    ignore_fully_untyped: false,
+                    ignore_names: vec![],
                 },
                 ..Settings::for_rules(vec![
                     CheckCode::ANN201,
@@ -100,6 +107,9 @@ mod tests {
                     suppress_dummy_args: false,
                     suppress_none_returning: true,
                     allow_star_arg_any: false,
+                    allow_untyped_decorated: vec![],
+                    ignore_fully_untyped: false,
+                    ignore_names: vec![],
                 },
                 ..Settings::for_rules(vec![
                     CheckCode::ANN201,
@@ -116,6 +126,108 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn allow_untyped_decorated() -> Result<()> {
+        let mut checks = test_path(
+            Path::new("./resources/test/fixtures/flake8_annotations/allow_untyped_decorated.py"),
+            &Settings {
+                flake8_annotations: flake8_annotations::settings::Settings {
+                    mypy_init_return: false,
+                    suppress_dummy_args: false,
+                    suppress_none_returning: false,
+                    allow_star_arg_any: false,
+                    allow_untyped_decorated: vec!["overload".to_string(), "fixture".to_string()],
+                    ignore_fully_untyped: false,
+                    ignore_names: vec![],
+                },
+                ..Settings::for_rules(vec![
+                    CheckCode::ANN001,
+                    CheckCode::ANN002,
+                    CheckCode::ANN003,
+                    CheckCode::ANN101,
+                    CheckCode::ANN102,
+                    CheckCode::ANN201,
+                    CheckCode::ANN202,
+                    CheckCode::ANN204,
+                    CheckCode::ANN205,
+                    CheckCode::ANN206,
+                ])
+            },
+            &fixer::Mode::Generate,
+        )?;
+        checks.sort_by_key(|check| check.location);
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
+
+    #[test]
+    fn ignore_fully_untyped() -> Result<()> {
+        let mut checks = test_path(
+            Path::new("./resources/test/fixtures/flake8_annotations/ignore_fully_untyped.py"),
+            &Settings {
+                flake8_annotations: flake8_annotations::settings::Settings {
+                    mypy_init_return: false,
+                    suppress_dummy_args: false,
+                    suppress_none_returning: false,
+                    allow_star_arg_any: false,
+                    allow_untyped_decorated: vec![],
+                    ignore_fully_untyped: true,
+                    ignore_names: vec![],
+                },
+                ..Settings::for_rules(vec![
+                    CheckCode::ANN001,
+                    CheckCode::ANN002,
+                    CheckCode::ANN003,
+                    CheckCode::ANN101,
+                    CheckCode::ANN102,
+                    CheckCode::ANN201,
+                    CheckCode::ANN202,
+                    CheckCode::ANN204,
+                    CheckCode::ANN205,
+                    CheckCode::ANN206,
+                ])
+            },
+            &fixer::Mode::Generate,
+        )?;
+        checks.sort_by_key(|check| check.location);
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
+
+    #[test]
+    fn ignore_names() -> Result<()> {
+        let mut checks = test_path(
+            Path::new("./resources/test/fixtures/flake8_annotations/ignore_names.py"),
+            &Settings {
+                flake8_annotations: flake8_annotations::settings::Settings {
+                    mypy_init_return: false,
+                    suppress_dummy_args: false,
+                    suppress_none_returning: false,
+                    allow_star_arg_any: false,
+                    allow_untyped_decorated: vec![],
+                    ignore_fully_untyped: false,
+                    ignore_names: vec![glob::Pattern::new("test_*").unwrap()],
+                },
+                ..Settings::for_rules(vec![
+                    CheckCode::ANN001,
+                    CheckCode::ANN002,
+                    CheckCode::ANN003,
+                    CheckCode::ANN101,
+                    CheckCode::ANN102,
+                    CheckCode::ANN201,
+                    CheckCode::ANN202,
+                    CheckCode::ANN204,
+                    CheckCode::ANN205,
+                    CheckCode::ANN206,
+                ])
+            },
+            &fixer::Mode::Generate,
+        )?;
+        checks.sort_by_key(|check| check.location);
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
+
     #[test]
     fn allow_star_arg_any() -> Result<()> {
         let mut checks = test_path(
@@ -126,6 +238,9 @@ mod tests {
                     suppress_dummy_args: false,
                     suppress_none_returning: false,
                     allow_star_arg_any: true,
+                    allow_untyped_decorated: vec![],
+                    ignore_fully_untyped: false,
+                    ignore_names: vec![],
                 },
                 ..Settings::for_rules(vec![CheckCode::ANN401])
             },
@@ -1,11 +1,16 @@
+use std::path::Path;
+
+use glob::Pattern;
 use rustpython_ast::{Arguments, Constant, Expr, ExprKind, Stmt, StmtKind};
 
+use crate::ast::helpers::match_name_or_attr;
 use crate::ast::types::Range;
 use crate::ast::visitor;
 use crate::ast::visitor::Visitor;
 use crate::check_ast::Checker;
 use crate::checks::{CheckCode, CheckKind};
 use crate::docstrings::definition::{Definition, DefinitionKind};
+use crate::flake8_annotations::fixes::add_return_annotation;
 use crate::visibility::Visibility;
 use crate::{visibility, Check};
 
@@ -29,7 +34,52 @@ where
     }
 }
 
+/// Whether `body` contains a `yield` or `yield from`, making the function it
+/// belongs to a generator. Like `ReturnStatementVisitor`, this doesn't
+/// recurse into nested function defs, since a nested function's own `yield`s
+/// make *it* a generator, not the function being checked.
+#[derive(Default)]
+struct YieldVisitor {
+    is_generator: bool,
+}
+
+impl<'a> Visitor<'a> for YieldVisitor {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        match &stmt.node {
+            StmtKind::FunctionDef { .. } | StmtKind::AsyncFunctionDef { .. } => {
+                // No recurse.
+            }
+            _ => visitor::walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if matches!(expr.node, ExprKind::Yield { .. } | ExprKind::YieldFrom { .. }) {
+            self.is_generator = true;
+        } else {
+            visitor::walk_expr(self, expr);
+        }
+    }
+}
+
+fn is_generator(body: &[Stmt]) -> bool {
+    let mut visitor = YieldVisitor::default();
+    for stmt in body {
+        visitor.visit_stmt(stmt);
+    }
+    visitor.is_generator
+}
+
+/// Whether `body` provably only returns `None` — either implicitly (falling
+/// off the end, or a bare `return`) or explicitly (`return None`). A
+/// generator function is never `None`-returning, even if every `return` in
+/// it is bare: it returns a generator object, not the value of its
+/// `return`s.
 fn is_none_returning(body: &[Stmt]) -> bool {
+    if is_generator(body) {
+        return false;
+    }
+
     let mut visitor: ReturnStatementVisitor = Default::default();
     for stmt in body {
         visitor.visit_stmt(stmt);
@@ -48,6 +98,62 @@ fn is_none_returning(body: &[Stmt]) -> bool {
     true
 }
 
+/// If `body` provably only returns literal constants of a single primitive
+/// type, return the name of that type (e.g. `"str"`), suitable for use as a
+/// return annotation. Like `is_none_returning`, this is an approximation
+/// rather than a full reachability analysis: to avoid suggesting a literal
+/// type for a function that can also fall through to an implicit `None`,
+/// this requires the last top-level statement in `body` to itself be an
+/// unconditional `return`.
+fn literal_return_annotation(body: &[Stmt]) -> Option<&'static str> {
+    if !matches!(
+        body.last().map(|stmt| &stmt.node),
+        Some(StmtKind::Return { .. })
+    ) {
+        return None;
+    }
+
+    let mut visitor: ReturnStatementVisitor = Default::default();
+    for stmt in body {
+        visitor.visit_stmt(stmt);
+    }
+
+    let mut annotation = None;
+    for expr in visitor.returns {
+        // A bare `return` returns `None`, so the function isn't literal-only.
+        let value = expr.as_ref()?;
+        let kind = match &value.node {
+            ExprKind::Constant { value: constant, .. } => match constant {
+                Constant::Str(_) => "str",
+                Constant::Bytes(_) => "bytes",
+                Constant::Int(_) => "int",
+                Constant::Float(_) => "float",
+                Constant::Bool(_) => "bool",
+                _ => return None,
+            },
+            _ => return None,
+        };
+        match annotation {
+            None => annotation = Some(kind),
+            Some(existing) if existing == kind => {}
+            Some(_) => return None,
+        }
+    }
+    annotation
+}
+
+/// Return the obvious return annotation for `body`, if any: `None` for a
+/// function that provably never returns a value, or the common literal type
+/// of a function that provably always does. `None` (the Rust one) if
+/// neither is obvious.
+fn obvious_return_annotation(body: &[Stmt]) -> Option<&'static str> {
+    if is_none_returning(body) {
+        Some("None")
+    } else {
+        literal_return_annotation(body)
+    }
+}
+
 /// ANN401
 fn check_dynamically_typed(checker: &mut Checker, annotation: &Expr, name: &str) {
     if checker.match_typing_module(annotation, "Any") {
@@ -58,13 +164,16 @@ fn check_dynamically_typed(checker: &mut Checker, annotation: &Expr, name: &str)
     };
 }
 
-fn match_function_def(stmt: &Stmt) -> (&str, &Arguments, &Option<Box<Expr>>, &Vec<Stmt>) {
+fn match_function_def(
+    stmt: &Stmt,
+) -> (&str, &Arguments, &Option<Box<Expr>>, &Vec<Stmt>, &Vec<Expr>) {
     match &stmt.node {
         StmtKind::FunctionDef {
             name,
             args,
             returns,
             body,
+            decorator_list,
             ..
         }
         | StmtKind::AsyncFunctionDef {
@@ -72,12 +181,58 @@ fn match_function_def(stmt: &Stmt) -> (&str, &Arguments, &Option<Box<Expr>>, &Ve
             args,
             returns,
             body,
+            decorator_list,
             ..
-        } => (name, args, returns, body),
+        } => (name, args, returns, body, decorator_list),
         _ => panic!("Found non-FunctionDef in match_name"),
     }
 }
 
+/// Return `true` if the function is decorated with one of the configured
+/// `allow-untyped-decorated` decorators (e.g., `@overload`, `@pytest.fixture`).
+fn is_allowed_untyped_decorated(
+    decorator_list: &[Expr],
+    allow_untyped_decorated: &[String],
+) -> bool {
+    decorator_list.iter().any(|expr| {
+        let expr = match &expr.node {
+            ExprKind::Call { func, .. } => func,
+            _ => expr,
+        };
+        allow_untyped_decorated
+            .iter()
+            .any(|decorator| match_name_or_attr(expr, decorator))
+    })
+}
+
+/// Return `true` if `name` or `path` matches one of the configured
+/// `ignore-names` glob patterns (e.g. `test_*`, or `*/migrations/*`).
+fn is_ignored_name(name: &str, path: &Path, ignore_names: &[Pattern]) -> bool {
+    ignore_names
+        .iter()
+        .any(|pattern| pattern.matches(name) || pattern.matches(&path.to_string_lossy()))
+}
+
+/// Return `true` if the function has no annotations at all: no argument
+/// annotations and no return annotation.
+fn is_fully_untyped(args: &Arguments, returns: &Option<Box<Expr>>) -> bool {
+    returns.is_none()
+        && args
+            .args
+            .iter()
+            .chain(args.posonlyargs.iter())
+            .chain(args.kwonlyargs.iter())
+            .all(|arg| arg.node.annotation.is_none())
+        && args
+            .vararg
+            .as_ref()
+            .map_or(true, |arg| arg.node.annotation.is_none())
+        && args
+            .kwarg
+            .as_ref()
+            .map_or(true, |arg| arg.node.annotation.is_none())
+}
+
 /// Generate flake8-annotation checks for a given `Definition`.
 pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &Visibility) {
     // TODO(charlie): Consider using the AST directly here rather than `Definition`.
@@ -89,7 +244,26 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
         DefinitionKind::Class(_) => {}
         DefinitionKind::NestedClass(_) => {}
         DefinitionKind::Function(stmt) | DefinitionKind::NestedFunction(stmt) => {
-            let (name, args, returns, body) = match_function_def(stmt);
+            let (name, args, returns, body, decorator_list) = match_function_def(stmt);
+
+            if is_allowed_untyped_decorated(
+                decorator_list,
+                &checker.settings.flake8_annotations.allow_untyped_decorated,
+            ) {
+                return;
+            }
+            if is_ignored_name(
+                name,
+                checker.path(),
+                &checker.settings.flake8_annotations.ignore_names,
+            ) {
+                return;
+            }
+            if checker.settings.flake8_annotations.ignore_fully_untyped
+                && is_fully_untyped(args, returns)
+            {
+                return;
+            }
 
             // ANN001, ANN401
             for arg in args
@@ -179,27 +353,66 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
                 match visibility {
                     Visibility::Public => {
                         if checker.settings.enabled.contains(&CheckCode::ANN201) {
-                            checker.add_check(Check::new(
+                            let mut check = Check::new(
                                 CheckKind::MissingReturnTypePublicFunction(name.to_string()),
                                 Range::from_located(stmt),
-                            ));
+                            );
+                            if checker.patch() {
+                                if let Some(annotation) = obvious_return_annotation(body) {
+                                    if let Some(fix) =
+                                        add_return_annotation(checker.locator, stmt, annotation)
+                                    {
+                                        check.amend(fix);
+                                    }
+                                }
+                            }
+                            checker.add_check(check);
                         }
                     }
                     Visibility::Private => {
                         if checker.settings.enabled.contains(&CheckCode::ANN202) {
-                            checker.add_check(Check::new(
+                            let mut check = Check::new(
                                 CheckKind::MissingReturnTypePrivateFunction(name.to_string()),
                                 Range::from_located(stmt),
-                            ));
+                            );
+                            if checker.patch() {
+                                if let Some(annotation) = obvious_return_annotation(body) {
+                                    if let Some(fix) =
+                                        add_return_annotation(checker.locator, stmt, annotation)
+                                    {
+                                        check.amend(fix);
+                                    }
+                                }
+                            }
+                            checker.add_check(check);
                         }
                     }
                 }
             }
         }
         DefinitionKind::Method(stmt) => {
-            let (name, args, returns, body) = match_function_def(stmt);
+            let (name, args, returns, body, decorator_list) = match_function_def(stmt);
             let mut has_any_typed_arg = false;
 
+            if is_allowed_untyped_decorated(
+                decorator_list,
+                &checker.settings.flake8_annotations.allow_untyped_decorated,
+            ) {
+                return;
+            }
+            if is_ignored_name(
+                name,
+                checker.path(),
+                &checker.settings.flake8_annotations.ignore_names,
+            ) {
+                return;
+            }
+            if checker.settings.flake8_annotations.ignore_fully_untyped
+                && is_fully_untyped(args, returns)
+            {
+                return;
+            }
+
             // ANN001
             for arg in args
                 .args
@@ -318,24 +531,54 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
 
                 if visibility::is_classmethod(stmt) {
                     if checker.settings.enabled.contains(&CheckCode::ANN206) {
-                        checker.add_check(Check::new(
+                        let mut check = Check::new(
                             CheckKind::MissingReturnTypeClassMethod(name.to_string()),
                             Range::from_located(stmt),
-                        ));
+                        );
+                        if checker.patch() {
+                            if let Some(annotation) = obvious_return_annotation(body) {
+                                if let Some(fix) =
+                                    add_return_annotation(checker.locator, stmt, annotation)
+                                {
+                                    check.amend(fix);
+                                }
+                            }
+                        }
+                        checker.add_check(check);
                     }
                 } else if visibility::is_staticmethod(stmt) {
                     if checker.settings.enabled.contains(&CheckCode::ANN205) {
-                        checker.add_check(Check::new(
+                        let mut check = Check::new(
                             CheckKind::MissingReturnTypeStaticMethod(name.to_string()),
                             Range::from_located(stmt),
-                        ));
+                        );
+                        if checker.patch() {
+                            if let Some(annotation) = obvious_return_annotation(body) {
+                                if let Some(fix) =
+                                    add_return_annotation(checker.locator, stmt, annotation)
+                                {
+                                    check.amend(fix);
+                                }
+                            }
+                        }
+                        checker.add_check(check);
                     }
                 } else if visibility::is_magic(stmt) {
                     if checker.settings.enabled.contains(&CheckCode::ANN204) {
-                        checker.add_check(Check::new(
+                        let mut check = Check::new(
                             CheckKind::MissingReturnTypeMagicMethod(name.to_string()),
                             Range::from_located(stmt),
-                        ));
+                        );
+                        if checker.patch() {
+                            if let Some(annotation) = obvious_return_annotation(body) {
+                                if let Some(fix) =
+                                    add_return_annotation(checker.locator, stmt, annotation)
+                                {
+                                    check.amend(fix);
+                                }
+                            }
+                        }
+                        checker.add_check(check);
                     }
                 } else if visibility::is_init(stmt) {
                     // Allow omission of return annotation in `__init__` functions, as long as at
@@ -344,28 +587,62 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
                         if !(checker.settings.flake8_annotations.mypy_init_return
                             && has_any_typed_arg)
                         {
-                            checker.add_check(Check::new(
+                            let mut check = Check::new(
                                 CheckKind::MissingReturnTypeMagicMethod(name.to_string()),
                                 Range::from_located(stmt),
-                            ));
+                            );
+                            if checker.patch() {
+                                // `__init__` can only ever return `None`; returning
+                                // anything else is a `TypeError` at runtime.
+                                if let Some(fix) =
+                                    add_return_annotation(checker.locator, stmt, "None")
+                                {
+                                    check.amend(fix);
+                                }
+                            }
+                            checker.add_check(check);
                         }
                     }
                 } else {
                     match visibility {
                         Visibility::Public => {
                             if checker.settings.enabled.contains(&CheckCode::ANN201) {
-                                checker.add_check(Check::new(
+                                let mut check = Check::new(
                                     CheckKind::MissingReturnTypePublicFunction(name.to_string()),
                                     Range::from_located(stmt),
-                                ));
+                                );
+                                if checker.patch() {
+                                    if let Some(annotation) = obvious_return_annotation(body) {
+                                        if let Some(fix) = add_return_annotation(
+                                            checker.locator,
+                                            stmt,
+                                            annotation,
+                                        ) {
+                                            check.amend(fix);
+                                        }
+                                    }
+                                }
+                                checker.add_check(check);
                             }
                         }
                         Visibility::Private => {
                             if checker.settings.enabled.contains(&CheckCode::ANN202) {
-                                checker.add_check(Check::new(
+                                let mut check = Check::new(
                                     CheckKind::MissingReturnTypePrivateFunction(name.to_string()),
                                     Range::from_located(stmt),
-                                ));
+                                );
+                                if checker.patch() {
+                                    if let Some(annotation) = obvious_return_annotation(body) {
+                                        if let Some(fix) = add_return_annotation(
+                                            checker.locator,
+                                            stmt,
+                                            annotation,
+                                        ) {
+                                            check.amend(fix);
+                                        }
+                                    }
+                                }
+                                checker.add_check(check);
                             }
                         }
                     }
@@ -1,8 +1,10 @@
 //! Settings for the `flake-annotations` plugin.
 
+use glob::Pattern;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 pub struct Options {
     /// Allow omission of a return type hint for `__init__` if at least one
@@ -18,6 +20,16 @@ pub struct Options {
     pub suppress_none_returning: Option<bool>,
     /// Suppress ANN401 for dynamically typed *args and **kwargs.
     pub allow_star_arg_any: Option<bool>,
+    /// Suppress all ANN errors for functions decorated with one of these
+    /// decorators (e.g., `overload`, `pytest.fixture`).
+    pub allow_untyped_decorated: Option<Vec<String>>,
+    /// Suppress all ANN errors for functions that have no annotations at all
+    /// (neither arguments nor a return type).
+    pub ignore_fully_untyped: Option<bool>,
+    /// Suppress all ANN errors for functions whose name, or whose file's
+    /// path, matches one of these glob patterns (e.g. `test_*` for test
+    /// functions, or `*/migrations/*` for migration files).
+    pub ignore_names: Option<Vec<String>>,
 }
 
 #[derive(Debug, Hash, Default)]
@@ -26,6 +38,9 @@ pub struct Settings {
     pub suppress_dummy_args: bool,
     pub suppress_none_returning: bool,
     pub allow_star_arg_any: bool,
+    pub allow_untyped_decorated: Vec<String>,
+    pub ignore_fully_untyped: bool,
+    pub ignore_names: Vec<Pattern>,
 }
 
 impl Settings {
@@ -35,6 +50,14 @@ impl Settings {
             suppress_dummy_args: options.suppress_dummy_args.unwrap_or_default(),
             suppress_none_returning: options.suppress_none_returning.unwrap_or_default(),
             allow_star_arg_any: options.allow_star_arg_any.unwrap_or_default(),
+            allow_untyped_decorated: options.allow_untyped_decorated.unwrap_or_default(),
+            ignore_fully_untyped: options.ignore_fully_untyped.unwrap_or_default(),
+            ignore_names: options
+                .ignore_names
+                .unwrap_or_default()
+                .iter()
+                .map(|pattern| Pattern::new(pattern).expect("Invalid pattern."))
+                .collect(),
         }
     }
 }
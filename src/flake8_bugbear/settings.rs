@@ -1,10 +1,13 @@
 //! Settings for the `pep8-naming` plugin.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 pub struct Options {
+    /// Additional callable names, in addition to the defaults, to consider
+    /// "immutable" when enforcing `B008`.
     pub extend_immutable_calls: Option<Vec<String>>,
 }
 
@@ -4,8 +4,13 @@ use crate::flake8_builtins::types::ShadowingType;
 use crate::python::builtins::BUILTINS;
 
 /// Check builtin name shadowing.
-pub fn builtin_shadowing(name: &str, location: Range, node_type: ShadowingType) -> Option<Check> {
-    if BUILTINS.contains(&name) {
+pub fn builtin_shadowing(
+    name: &str,
+    location: Range,
+    node_type: ShadowingType,
+    extend_builtins: &[String],
+) -> Option<Check> {
+    if BUILTINS.contains(&name) || extend_builtins.iter().any(|builtin| builtin == name) {
         Some(Check::new(
             match node_type {
                 ShadowingType::Variable => CheckKind::BuiltinVariableShadowing(name.to_string()),
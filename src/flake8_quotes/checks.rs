@@ -1,6 +1,7 @@
 use rustpython_ast::Location;
 
 use crate::ast::types::Range;
+use crate::autofix::Fix;
 use crate::checks::{Check, CheckKind};
 use crate::flake8_quotes::settings::{Quote, Settings};
 use crate::source_code_locator::SourceCodeLocator;
@@ -40,12 +41,24 @@ fn good_docstring(quote: &Quote) -> &str {
     }
 }
 
+/// Swap the delimiters of a triple-quoted string, provided doing so wouldn't require escaping a
+/// quote character. Returns `None` if the content ends with the delimiter's own quote character,
+/// since appending the new delimiter would then be ambiguous with the content.
+fn swap_triple_quotes(raw_text: &str, prefix: &str, good: &str) -> Option<String> {
+    let inner = &raw_text[3..raw_text.len() - 3];
+    if inner.ends_with(good.chars().next().unwrap()) {
+        return None;
+    }
+    Some(format!("{}{}{}{}", prefix, good, inner, good))
+}
+
 pub fn quotes(
     locator: &SourceCodeLocator,
     start: &Location,
     end: &Location,
     is_docstring: bool,
     settings: &Settings,
+    autofix: bool,
 ) -> Option<Check> {
     let text = locator.slice_source_code_range(&Range {
         location: *start,
@@ -55,7 +68,8 @@ pub fn quotes(
     // Remove any prefixes (e.g., remove `u` from `u"foo"`).
     let last_quote_char = text.chars().last().unwrap();
     let first_quote_char = text.find(last_quote_char).unwrap();
-    let prefix = &text[..first_quote_char].to_lowercase();
+    let original_prefix = &text[..first_quote_char];
+    let prefix = &original_prefix.to_lowercase();
     let raw_text = &text[first_quote_char..];
 
     // Determine if the string is multiline-based.
@@ -69,18 +83,27 @@ pub fn quotes(
         false
     };
 
+    let range = Range {
+        location: *start,
+        end_location: *end,
+    };
+
     if is_docstring {
         if raw_text.contains(good_docstring(&settings.docstring_quotes)) {
             return None;
         }
 
-        return Some(Check::new(
+        let mut check = Check::new(
             CheckKind::BadQuotesDocstring(settings.docstring_quotes.clone()),
-            Range {
-                location: *start,
-                end_location: *end,
-            },
-        ));
+            range,
+        );
+        if autofix {
+            let good = good_docstring(&settings.docstring_quotes);
+            if let Some(fixed) = swap_triple_quotes(raw_text, original_prefix, good) {
+                check.amend(Fix::replacement(fixed, *start, *end));
+            }
+        }
+        return Some(check);
     } else if is_multiline {
         // If our string is or contains a known good string, ignore it.
         if raw_text.contains(good_multiline(&settings.multiline_quotes)) {
@@ -92,13 +115,17 @@ pub fn quotes(
             return None;
         }
 
-        return Some(Check::new(
+        let mut check = Check::new(
             CheckKind::BadQuotesMultilineString(settings.multiline_quotes.clone()),
-            Range {
-                location: *start,
-                end_location: *end,
-            },
-        ));
+            range,
+        );
+        if autofix {
+            let good = good_multiline(&settings.multiline_quotes);
+            if let Some(fixed) = swap_triple_quotes(raw_text, original_prefix, good) {
+                check.amend(Fix::replacement(fixed, *start, *end));
+            }
+        }
+        return Some(check);
     } else {
         let string_contents = &raw_text[1..raw_text.len() - 1];
 
@@ -110,26 +137,40 @@ pub fn quotes(
             if string_contents.contains(good_single(&settings.inline_quotes))
                 && !string_contents.contains(bad_single(&settings.inline_quotes))
             {
-                return Some(Check::new(
-                    CheckKind::AvoidQuoteEscape,
-                    Range {
-                        location: *start,
-                        end_location: *end,
-                    },
-                ));
+                let mut check = Check::new(CheckKind::AvoidQuoteEscape, range);
+                if autofix {
+                    let good = good_single(&settings.inline_quotes);
+                    let bad = bad_single(&settings.inline_quotes);
+                    // The good quote character is no longer the delimiter, so it no longer
+                    // needs to be escaped.
+                    let unescaped =
+                        string_contents.replace(&format!("\\{}", good), &good.to_string());
+                    check.amend(Fix::replacement(
+                        format!("{}{}{}{}", original_prefix, bad, unescaped, bad),
+                        *start,
+                        *end,
+                    ));
+                }
+                return Some(check);
             }
             return None;
         }
 
         // If we're not using the preferred type, only allow use to avoid escapes.
         if !string_contents.contains(good_single(&settings.inline_quotes)) {
-            return Some(Check::new(
+            let mut check = Check::new(
                 CheckKind::BadQuotesInlineString(settings.inline_quotes.clone()),
-                Range {
-                    location: *start,
-                    end_location: *end,
-                },
-            ));
+                range,
+            );
+            if autofix {
+                let good = good_single(&settings.inline_quotes);
+                check.amend(Fix::replacement(
+                    format!("{}{}{}{}", original_prefix, good, string_contents, good),
+                    *start,
+                    *end,
+                ));
+            }
+            return Some(check);
         }
     }
 
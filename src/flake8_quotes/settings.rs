@@ -1,20 +1,26 @@
 //! Settings for the `flake-quotes` plugin.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 pub enum Quote {
     Single,
     Double,
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 pub struct Options {
+    /// The preferred quote character for inline strings.
     pub inline_quotes: Option<Quote>,
+    /// The preferred quote character for multiline strings.
     pub multiline_quotes: Option<Quote>,
+    /// The preferred quote character for docstrings.
     pub docstring_quotes: Option<Quote>,
+    /// Whether to avoid using single quotes when a string contains a double
+    /// quote character (or vice versa), to avoid escaping.
     pub avoid_escape: Option<bool>,
 }
 
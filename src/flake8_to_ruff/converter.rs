@@ -1,14 +1,14 @@
 use std::collections::{BTreeSet, HashMap};
 
 use anyhow::Result;
-use ruff::checks_gen::CheckCodePrefix;
-use ruff::flake8_quotes::settings::Quote;
-use ruff::settings::options::Options;
-use ruff::settings::pyproject::Pyproject;
-use ruff::{flake8_annotations, flake8_bugbear, flake8_quotes, pep8_naming};
 
-use crate::plugin::Plugin;
-use crate::{parser, plugin};
+use super::plugin::Plugin;
+use super::{parser, plugin};
+use crate::checks_gen::CheckCodePrefix;
+use crate::flake8_quotes::settings::Quote;
+use crate::settings::options::Options;
+use crate::settings::pyproject::Pyproject;
+use crate::{flake8_annotations, flake8_bugbear, flake8_quotes, pep8_naming, pycodestyle};
 
 pub fn convert(
     flake8: &HashMap<String, Option<String>>,
@@ -72,6 +72,7 @@ pub fn convert(
     let mut flake8_bugbear: flake8_bugbear::settings::Options = Default::default();
     let mut flake8_quotes: flake8_quotes::settings::Options = Default::default();
     let mut pep8_naming: pep8_naming::settings::Options = Default::default();
+    let mut pycodestyle: pycodestyle::settings::Options = Default::default();
     for (key, value) in flake8 {
         if let Some(value) = value {
             match key.as_str() {
@@ -80,6 +81,11 @@ pub fn convert(
                     Ok(line_length) => options.line_length = Some(line_length),
                     Err(e) => eprintln!("Unable to parse '{key}' property: {e}"),
                 },
+                // pycodestyle
+                "max-doc-length" | "max_doc_length" => match value.clone().parse::<usize>() {
+                    Ok(max_doc_length) => pycodestyle.max_doc_length = Some(max_doc_length),
+                    Err(e) => eprintln!("Unable to parse '{key}' property: {e}"),
+                },
                 "select" => {
                     // No-op (handled above).
                     select.extend(parser::parse_prefix_codes(value.as_ref()));
@@ -197,6 +203,9 @@ pub fn convert(
     if pep8_naming != Default::default() {
         options.pep8_naming = Some(pep8_naming);
     }
+    if pycodestyle != Default::default() {
+        options.pycodestyle = Some(pycodestyle);
+    }
 
     // Create the pyproject.toml.
     Ok(Pyproject::new(options))
@@ -207,19 +216,20 @@ mod tests {
     use std::collections::HashMap;
 
     use anyhow::Result;
-    use ruff::checks_gen::CheckCodePrefix;
-    use ruff::flake8_quotes;
-    use ruff::settings::options::Options;
-    use ruff::settings::pyproject::Pyproject;
 
-    use crate::converter::convert;
-    use crate::plugin::Plugin;
+    use super::convert;
+    use crate::checks_gen::CheckCodePrefix;
+    use crate::flake8_quotes;
+    use crate::flake8_to_ruff::plugin::Plugin;
+    use crate::settings::options::Options;
+    use crate::settings::pyproject::Pyproject;
 
     #[test]
     fn it_converts_empty() -> Result<()> {
         let actual = convert(&HashMap::from([]), None)?;
         let expected = Pyproject::new(Options {
             line_length: None,
+            tab_size: None,
             src: None,
             fix: None,
             exclude: None,
@@ -240,6 +250,7 @@ mod tests {
             flake8_quotes: None,
             isort: None,
             pep8_naming: None,
+            pycodestyle: None,
         });
         assert_eq!(actual, expected);
 
@@ -254,6 +265,7 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             line_length: Some(100),
+            tab_size: None,
             src: None,
             fix: None,
             exclude: None,
@@ -274,6 +286,7 @@ mod tests {
             flake8_quotes: None,
             isort: None,
             pep8_naming: None,
+            pycodestyle: None,
         });
         assert_eq!(actual, expected);
 
@@ -288,6 +301,7 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             line_length: Some(100),
+            tab_size: None,
             src: None,
             fix: None,
             exclude: None,
@@ -308,6 +322,7 @@ mod tests {
             flake8_quotes: None,
             isort: None,
             pep8_naming: None,
+            pycodestyle: None,
         });
         assert_eq!(actual, expected);
 
@@ -322,6 +337,7 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             line_length: None,
+            tab_size: None,
             src: None,
             fix: None,
             exclude: None,
@@ -342,6 +358,7 @@ mod tests {
             flake8_quotes: None,
             isort: None,
             pep8_naming: None,
+            pycodestyle: None,
         });
         assert_eq!(actual, expected);
 
@@ -356,6 +373,7 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             line_length: None,
+            tab_size: None,
             src: None,
             fix: None,
             exclude: None,
@@ -381,6 +399,7 @@ mod tests {
             }),
             isort: None,
             pep8_naming: None,
+            pycodestyle: None,
         });
         assert_eq!(actual, expected);
 
@@ -398,6 +417,7 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             line_length: None,
+            tab_size: None,
             src: None,
             fix: None,
             exclude: None,
@@ -453,6 +473,7 @@ mod tests {
             flake8_quotes: None,
             isort: None,
             pep8_naming: None,
+            pycodestyle: None,
         });
         assert_eq!(actual, expected);
 
@@ -467,6 +488,7 @@ mod tests {
         )?;
         let expected = Pyproject::new(Options {
             line_length: None,
+            tab_size: None,
             src: None,
             fix: None,
             exclude: None,
@@ -493,6 +515,7 @@ mod tests {
             }),
             isort: None,
             pep8_naming: None,
+            pycodestyle: None,
         });
         assert_eq!(actual, expected);
 
@@ -0,0 +1,3 @@
+pub mod converter;
+mod parser;
+pub mod plugin;
@@ -4,8 +4,9 @@ use std::str::FromStr;
 use anyhow::Result;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use ruff::checks_gen::CheckCodePrefix;
-use ruff::settings::types::PatternPrefixPair;
+
+use crate::checks_gen::CheckCodePrefix;
+use crate::settings::types::PatternPrefixPair;
 
 static COMMA_SEPARATED_LIST_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"[,\s]").unwrap());
 
@@ -193,10 +194,10 @@ pub fn collect_per_file_ignores(
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
-    use ruff::checks_gen::CheckCodePrefix;
-    use ruff::settings::types::PatternPrefixPair;
 
-    use crate::parser::{parse_files_to_codes_mapping, parse_prefix_codes, parse_strings};
+    use super::{parse_files_to_codes_mapping, parse_prefix_codes, parse_strings};
+    use crate::checks_gen::CheckCodePrefix;
+    use crate::settings::types::PatternPrefixPair;
 
     #[test]
     fn it_parses_prefix_codes() {
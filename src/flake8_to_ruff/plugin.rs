@@ -2,7 +2,8 @@ use std::collections::{BTreeSet, HashMap};
 use std::str::FromStr;
 
 use anyhow::anyhow;
-use ruff::checks_gen::CheckCodePrefix;
+
+use crate::checks_gen::CheckCodePrefix;
 
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub enum Plugin {
@@ -384,7 +385,7 @@ pub fn resolve_select(
 mod tests {
     use std::collections::HashMap;
 
-    use crate::plugin::{infer_plugins_from_options, Plugin};
+    use super::{infer_plugins_from_options, Plugin};
 
     #[test]
     fn it_infers_plugins() {
@@ -0,0 +1,39 @@
+//! The `ruff format` subsystem: a Black-compatible-baseline formatter for
+//! Python source, built on the same tokenizer/parser/AST-unparser
+//! infrastructure the linter already uses.
+//!
+//! `code_gen::SourceGenerator` regenerates source directly from the AST,
+//! which gives consistent statement and expression layout, but the AST
+//! carries no comments. Reformatting a file that contains one would
+//! therefore silently delete it, so [`format_source`] refuses to touch any
+//! file with a `# comment` rather than risk losing content; teams should
+//! exclude such files until comment-preserving formatting lands.
+
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use rustpython_parser::lexer::Tok;
+
+use crate::code_gen::SourceGenerator;
+use crate::linter::{parse_program_tokens, tokenize};
+
+/// Format `contents` (read from `path`, which is used only to label parse
+/// errors and the comment-detection message below).
+pub fn format_source(path: &Path, contents: &str) -> Result<String> {
+    let tokens = tokenize(contents);
+    if tokens
+        .iter()
+        .flatten()
+        .any(|(_, tok, _)| matches!(tok, Tok::Comment))
+    {
+        bail!(
+            "{} contains comments, which the formatter would discard; skipping",
+            path.display()
+        );
+    }
+
+    let python_ast = parse_program_tokens(tokens, &path.to_string_lossy())?;
+    let mut generator: SourceGenerator = Default::default();
+    generator.unparse_suite(&python_ast)?;
+    Ok(generator.generate()?)
+}
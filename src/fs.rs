@@ -1,14 +1,18 @@
 use std::borrow::Cow;
 use std::collections::BTreeSet;
-use std::fs::File;
-use std::io::{BufReader, Read};
+use std::fs::{self, File};
+use std::io::{BufReader, Read, Write};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use anyhow::{anyhow, Result};
+use fnv::FnvHashSet;
+use ignore::{DirEntry, WalkBuilder, WalkState};
 use log::debug;
+use once_cell::sync::Lazy;
 use path_absolutize::{path_dedot, Absolutize};
-use walkdir::{DirEntry, WalkDir};
+use regex::bytes::Regex as BytesRegex;
 
 use crate::checks::CheckCode;
 use crate::settings::types::{FilePattern, PerFileIgnore};
@@ -59,11 +63,16 @@ fn is_included(path: &Path) -> bool {
     file_name.ends_with(".py") || file_name.ends_with(".pyi")
 }
 
-pub fn iter_python_files<'a>(
-    path: &'a Path,
-    exclude: &'a [FilePattern],
-    extend_exclude: &'a [FilePattern],
-) -> impl Iterator<Item = Result<DirEntry, walkdir::Error>> + 'a {
+/// Collect every Python file under `path`, applying `exclude`/`extend_exclude`
+/// during traversal (so an excluded directory is pruned rather than merely
+/// filtered out of its results) and walking the tree with one thread per
+/// core, which matters on `node_modules`/`venv`-heavy trees.
+pub fn iter_python_files(
+    path: &Path,
+    exclude: &[FilePattern],
+    extend_exclude: &[FilePattern],
+    follow_symlinks: bool,
+) -> Vec<Result<DirEntry, ignore::Error>> {
     // Run some checks over the provided patterns, to enable optimizations below.
     let has_exclude = !exclude.is_empty();
     let has_extend_exclude = !extend_exclude.is_empty();
@@ -74,47 +83,124 @@ pub fn iter_python_files<'a>(
         .iter()
         .all(|pattern| matches!(pattern, FilePattern::Simple(_)));
 
-    WalkDir::new(normalize_path(path))
-        .into_iter()
-        .filter_entry(move |entry| {
-            if !has_exclude && !has_extend_exclude {
-                return true;
+    let is_excluded_entry = |path: &Path, is_dir: bool| -> bool {
+        if !has_exclude && !has_extend_exclude {
+            return false;
+        }
+        match extract_path_names(path) {
+            Ok((file_path, file_basename)) => {
+                if has_exclude
+                    && (!exclude_simple || is_dir)
+                    && is_excluded(file_path, file_basename, exclude.iter())
+                {
+                    debug!("Ignored path via `exclude`: {:?}", path);
+                    true
+                } else if has_extend_exclude
+                    && (!extend_exclude_simple || is_dir)
+                    && is_excluded(file_path, file_basename, extend_exclude.iter())
+                {
+                    debug!("Ignored path via `extend-exclude`: {:?}", path);
+                    true
+                } else {
+                    false
+                }
             }
+            Err(_) => {
+                debug!("Ignored path due to error in parsing: {:?}", path);
+                false
+            }
+        }
+    };
+
+    // Following symlinks can surface the same file under more than one path
+    // (e.g. a symlink alongside its target, or two symlinks to the same
+    // file), so track canonical paths already yielded and skip repeats.
+    // The walker itself detects symlink cycles back to an ancestor directory
+    // and reports them as an `Err`, which is passed through unfiltered.
+    let seen = Mutex::new(FnvHashSet::default());
+    let results: Mutex<Vec<Result<DirEntry, ignore::Error>>> = Mutex::new(Vec::new());
 
-            let path = entry.path();
-            match extract_path_names(path) {
-                Ok((file_path, file_basename)) => {
-                    let file_type = entry.file_type();
-
-                    if has_exclude
-                        && (!exclude_simple || file_type.is_dir())
-                        && is_excluded(file_path, file_basename, exclude.iter())
-                    {
-                        debug!("Ignored path via `exclude`: {:?}", path);
-                        false
-                    } else if has_extend_exclude
-                        && (!extend_exclude_simple || file_type.is_dir())
-                        && is_excluded(file_path, file_basename, extend_exclude.iter())
-                    {
-                        debug!("Ignored path via `extend-exclude`: {:?}", path);
-                        false
-                    } else {
-                        true
+    let visit = |result: Result<DirEntry, ignore::Error>| -> WalkState {
+        let entry = match result {
+            Ok(entry) => entry,
+            Err(err) => {
+                results.lock().unwrap().push(Err(err));
+                return WalkState::Continue;
+            }
+        };
+
+        let is_dir = entry.file_type().map_or(false, |file_type| file_type.is_dir());
+        if is_excluded_entry(entry.path(), is_dir) {
+            return if is_dir {
+                WalkState::Skip
+            } else {
+                WalkState::Continue
+            };
+        }
+        if is_dir || (entry.path_is_symlink() && entry.path().is_dir()) {
+            return WalkState::Continue;
+        }
+        if !(entry.depth() == 0 || is_included(entry.path())) {
+            return WalkState::Continue;
+        }
+        if follow_symlinks {
+            // Only files can plausibly alias each other via symlinks, so
+            // canonicalize just those (as opposed to every directory the
+            // walker descends into).
+            match entry.path().canonicalize() {
+                Ok(canonical) => {
+                    if !seen.lock().unwrap().insert(canonical) {
+                        return WalkState::Continue;
                     }
                 }
-                Err(_) => {
-                    debug!("Ignored path due to error in parsing: {:?}", path);
-                    true
-                }
+                Err(_) => return WalkState::Continue,
             }
+        }
+        results.lock().unwrap().push(Ok(entry));
+        WalkState::Continue
+    };
+
+    let mut builder = WalkBuilder::new(normalize_path(path));
+    // We apply our own `exclude`/`extend-exclude` patterns above; don't also
+    // respect `.gitignore` and friends, or skip dotfiles, as `ignore`'s
+    // standard filters would otherwise do.
+    builder.standard_filters(false).follow_links(follow_symlinks);
+
+    #[cfg(not(target_family = "wasm"))]
+    builder.build_parallel().run(|| Box::new(visit));
+
+    // `ignore`'s parallel walker spins up OS threads, which aren't available
+    // on wasm; fall back to its sequential walker there (mirrors the
+    // `par_iter`/sequential-`iter` split in `main.rs`).
+    #[cfg(target_family = "wasm")]
+    for result in builder.build() {
+        visit(result);
+    }
+
+    results.into_inner().unwrap()
+}
+
+/// Canonicalize each of `paths`, then drop exact duplicates and any path
+/// that's already covered by another one in the set (e.g. `src/pkg/file.py`
+/// when `src` is also present), so overlapping CLI arguments (`ruff . src/
+/// src/pkg/file.py`) don't get linted twice, and so per-file-ignores match
+/// consistently against a single, normalized path form.
+pub fn normalize_and_dedupe_paths(paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = paths
+        .into_iter()
+        .map(|path| path.canonicalize().unwrap_or(path))
+        .collect();
+    paths.sort_unstable();
+    paths.dedup();
+    paths
+        .iter()
+        .filter(|path| {
+            !paths
+                .iter()
+                .any(|other| *other != **path && path.starts_with(other))
         })
-        .filter(|entry| {
-            entry.as_ref().map_or(true, |entry| {
-                (entry.depth() == 0 || is_included(entry.path()))
-                    && !entry.file_type().is_dir()
-                    && !(entry.file_type().is_symlink() && entry.path().is_dir())
-            })
-        })
+        .cloned()
+        .collect()
 }
 
 /// Create tree set with codes matching the pattern/code pairs.
@@ -161,23 +247,194 @@ pub(crate) fn relativize_path(path: &Path) -> Cow<str> {
     path.to_string_lossy()
 }
 
-/// Read a file's contents from disk.
+/// Number of leading bytes sniffed by `is_binary` to detect binary files
+/// without reading the whole thing into memory.
+const BINARY_SNIFF_LEN: usize = 8192;
+
+/// Whether the first few KB of `path` contain a NUL byte, the same heuristic
+/// Git and most other tools use to distinguish binary files from text:
+/// legitimate UTF-8 (or any other common text encoding) source never
+/// contains one.
+pub(crate) fn is_binary(path: &Path) -> Result<bool> {
+    let file = File::open(path)?;
+    let mut buf_reader = BufReader::new(file);
+    let mut buf = [0; BINARY_SNIFF_LEN];
+    let num_read = buf_reader.read(&mut buf)?;
+    Ok(buf[..num_read].contains(&0))
+}
+
+/// Read a file's contents from disk, decoding it to UTF-8.
+///
+/// Most source files are already UTF-8 and are returned as-is (after
+/// stripping a UTF-8 BOM, if present). If a file isn't valid UTF-8, fall back
+/// to honoring a PEP 263 encoding cookie (e.g. `# -*- coding: latin-1 -*-`)
+/// in its first two lines, rather than failing outright — legacy, pre-PEP 3120
+/// codebases are often declared as `latin-1` or `cp1252`.
 pub(crate) fn read_file(path: &Path) -> Result<String> {
     let file = File::open(path)?;
     let mut buf_reader = BufReader::new(file);
-    let mut contents = String::new();
-    buf_reader.read_to_string(&mut contents)?;
-    Ok(contents)
+    let mut bytes = vec![];
+    buf_reader.read_to_end(&mut bytes)?;
+    decode(&bytes)
+}
+
+/// Whether a file has a leading UTF-8 BOM and/or uses CRLF line endings, so
+/// that a fixed version of it can be written back out with the same
+/// conventions rather than a bare-UTF-8, LF-only one.
+pub(crate) struct FileConventions {
+    pub(crate) bom: bool,
+    pub(crate) crlf: bool,
+}
+
+pub(crate) fn read_file_conventions(path: &Path) -> Result<FileConventions> {
+    let file = File::open(path)?;
+    let mut buf_reader = BufReader::new(file);
+    let mut bytes = vec![];
+    buf_reader.read_to_end(&mut bytes)?;
+    Ok(FileConventions {
+        bom: bytes.starts_with(&[0xEF, 0xBB, 0xBF]),
+        crlf: bytes.windows(2).any(|window| window == b"\r\n"),
+    })
+}
+
+/// Write `contents` to `path`, so that a process killed mid-write (e.g. an
+/// interrupted `--fix` run) can never leave `path` truncated: the new
+/// contents are built up in a temp file alongside `path` (so the rename
+/// below stays on one filesystem), fsync'd, given `path`'s existing
+/// permissions (if any), and only then atomically renamed into place.
+pub(crate) fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let temp_path = dir.join(format!(
+        ".{}.tmp{}",
+        path.file_name().unwrap_or_default().to_string_lossy(),
+        std::process::id()
+    ));
+
+    let mut file = File::create(&temp_path)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+
+    if let Ok(metadata) = fs::metadata(path) {
+        fs::set_permissions(&temp_path, metadata.permissions())?;
+    }
+
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+static CODING_COOKIE: Lazy<BytesRegex> =
+    Lazy::new(|| BytesRegex::new(r"^[ \t\f]*#.*coding[:=][ \t]*([-\w.]+)").unwrap());
+
+/// Extract the codec name declared by a PEP 263 encoding cookie, if any, by
+/// scanning the first two physical lines (the only place Python honors one).
+fn detect_coding_cookie(bytes: &[u8]) -> Option<String> {
+    bytes
+        .split(|&b| b == b'\n')
+        .take(2)
+        .find_map(|line| CODING_COOKIE.captures(line))
+        .map(|captures| {
+            String::from_utf8_lossy(&captures[1])
+                .to_ascii_lowercase()
+                .replace('_', "-")
+        })
+}
+
+/// Decode a byte value under the `cp1252` codec, which agrees with `latin-1`
+/// except for 32 code points in the C1 control range that Windows reused for
+/// printable characters. The handful of bytes `cp1252` leaves undefined fall
+/// back to their `latin-1` (identity) mapping rather than erroring, since
+/// this is already a best-effort fallback for undeclared/legacy encodings.
+fn decode_cp1252_byte(byte: u8) -> char {
+    match byte {
+        0x80 => '\u{20AC}',
+        0x82 => '\u{201A}',
+        0x83 => '\u{0192}',
+        0x84 => '\u{201E}',
+        0x85 => '\u{2026}',
+        0x86 => '\u{2020}',
+        0x87 => '\u{2021}',
+        0x88 => '\u{02C6}',
+        0x89 => '\u{2030}',
+        0x8A => '\u{0160}',
+        0x8B => '\u{2039}',
+        0x8C => '\u{0152}',
+        0x8E => '\u{017D}',
+        0x91 => '\u{2018}',
+        0x92 => '\u{2019}',
+        0x93 => '\u{201C}',
+        0x94 => '\u{201D}',
+        0x95 => '\u{2022}',
+        0x96 => '\u{2013}',
+        0x97 => '\u{2014}',
+        0x98 => '\u{02DC}',
+        0x99 => '\u{2122}',
+        0x9A => '\u{0161}',
+        0x9B => '\u{203A}',
+        0x9C => '\u{0153}',
+        0x9E => '\u{017E}',
+        0x9F => '\u{0178}',
+        _ => byte as char,
+    }
+}
+
+/// Decode raw source bytes to a UTF-8 `String`, per the rules described on
+/// `read_file`.
+fn decode(bytes: &[u8]) -> Result<String> {
+    let bytes = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+    if let Ok(contents) = std::str::from_utf8(bytes) {
+        return Ok(contents.to_string());
+    }
+
+    match detect_coding_cookie(bytes).as_deref() {
+        Some("latin-1" | "latin1" | "iso-8859-1" | "iso8859-1") => {
+            Ok(bytes.iter().map(|&b| b as char).collect())
+        }
+        Some("cp1252" | "windows-1252") => {
+            Ok(bytes.iter().map(|&b| decode_cp1252_byte(b)).collect())
+        }
+        Some(unknown) => Err(anyhow!("Unsupported source encoding: {unknown}")),
+        None => Err(anyhow!(
+            "File is not valid UTF-8, and declares no `coding` cookie"
+        )),
+    }
+}
+
+/// Locate a first-party dotted module (e.g. `foo.bar`) under one of the given
+/// `src` roots. Prefers a concrete file to read (`foo/bar.py`, or
+/// `foo/bar/__init__.py` for a regular package), but also resolves bare
+/// namespace-package directories (no `__init__.py`) so first-party detection
+/// keeps working for them.
+pub(crate) fn resolve_module(src: &[PathBuf], module: &str) -> Option<PathBuf> {
+    let relative = module.replace('.', "/");
+    for root in src {
+        let module_file = root.join(format!("{relative}.py"));
+        if module_file.is_file() {
+            return Some(module_file);
+        }
+
+        let package_dir = root.join(&relative);
+        let package_init = package_dir.join("__init__.py");
+        if package_init.is_file() {
+            return Some(package_init);
+        }
+        if package_dir.is_dir() {
+            return Some(package_dir);
+        }
+    }
+    None
 }
 
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
 
     use anyhow::Result;
     use path_absolutize::Absolutize;
 
-    use crate::fs::{extract_path_names, is_excluded, is_included};
+    use crate::fs::{
+        decode, extract_path_names, is_excluded, is_included, normalize_and_dedupe_paths,
+        resolve_module,
+    };
     use crate::settings::types::FilePattern;
 
     #[test]
@@ -265,4 +522,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn dedupes_overlapping_paths() {
+        let paths = normalize_and_dedupe_paths(vec![
+            PathBuf::from("resources/test/fixtures"),
+            PathBuf::from("resources/test/fixtures/F401_0.py"),
+            PathBuf::from("resources/test/fixtures"),
+        ]);
+        assert_eq!(
+            paths,
+            vec![Path::new("resources/test/fixtures")
+                .canonicalize()
+                .unwrap()]
+        );
+    }
+
+    #[test]
+    fn decode_non_utf8() {
+        assert_eq!(decode(b"x = 1\n").unwrap(), "x = 1\n");
+
+        // A `latin-1`-declared file with a byte (e.g. 0xe9, "é") that isn't valid UTF-8.
+        let latin1 = b"# -*- coding: latin-1 -*-\nname = \"caf\xe9\"\n";
+        assert_eq!(
+            decode(latin1).unwrap(),
+            "# -*- coding: latin-1 -*-\nname = \"café\"\n"
+        );
+
+        // Undeclared, non-UTF-8 bytes can't be decoded.
+        assert!(decode(b"name = \"caf\xe9\"\n").is_err());
+    }
+
+    #[test]
+    fn module_resolution() {
+        let src = vec![PathBuf::from("resources/test/fixtures")];
+        assert_eq!(
+            resolve_module(&src, "F401_0"),
+            Some(PathBuf::from("resources/test/fixtures/F401_0.py"))
+        );
+        assert_eq!(
+            resolve_module(&src, "isort"),
+            Some(PathBuf::from("resources/test/fixtures/isort"))
+        );
+        assert_eq!(resolve_module(&src, "does_not_exist"), None);
+    }
 }
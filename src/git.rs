@@ -0,0 +1,109 @@
+//! Best-effort integration with the user's `git` binary, for restricting a
+//! lint run to only the files (and hunks) that changed relative to a given
+//! ref.
+//!
+//! There's no git implementation among Ruff's dependencies, so this shells
+//! out to `git` rather than vendoring one.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::{bail, Result};
+
+/// The paths of files added, copied, modified, or renamed between
+/// `reference` and the working tree (uncommitted changes included), as
+/// absolute paths.
+pub fn diff_against(reference: &str) -> Result<Vec<PathBuf>> {
+    let toplevel = Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .output()?;
+    if !toplevel.status.success() {
+        bail!(
+            "Failed to locate a git repository (is `git` installed, and is the current \
+             directory inside a repository?): {}",
+            String::from_utf8_lossy(&toplevel.stderr).trim()
+        );
+    }
+    let toplevel = PathBuf::from(String::from_utf8_lossy(&toplevel.stdout).trim());
+
+    let diff = Command::new("git")
+        .args(["diff", "--name-only", "--diff-filter=ACMR", reference])
+        .current_dir(&toplevel)
+        .output()?;
+    if !diff.status.success() {
+        bail!(
+            "`git diff` against {reference:?} failed: {}",
+            String::from_utf8_lossy(&diff.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&diff.stdout)
+        .lines()
+        .map(|path| toplevel.join(path))
+        .collect())
+}
+
+/// The `(start, end)` line ranges (1-indexed, inclusive) added or modified in
+/// each changed file, keyed by absolute path, as reported by `git diff`'s
+/// unified hunk headers (`@@ -a,b +c,d @@`). Only the post-image (`+c,d`)
+/// side is tracked, since that's what a diagnostic's row refers to; a file
+/// with only deletions has no ranges.
+pub fn diff_hunks(reference: &str) -> Result<HashMap<PathBuf, Vec<(usize, usize)>>> {
+    let toplevel = Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .output()?;
+    if !toplevel.status.success() {
+        bail!(
+            "Failed to locate a git repository (is `git` installed, and is the current \
+             directory inside a repository?): {}",
+            String::from_utf8_lossy(&toplevel.stderr).trim()
+        );
+    }
+    let toplevel = PathBuf::from(String::from_utf8_lossy(&toplevel.stdout).trim());
+
+    let diff = Command::new("git")
+        .args(["diff", "-U0", "--diff-filter=ACMR", reference])
+        .current_dir(&toplevel)
+        .output()?;
+    if !diff.status.success() {
+        bail!(
+            "`git diff` against {reference:?} failed: {}",
+            String::from_utf8_lossy(&diff.stderr).trim()
+        );
+    }
+
+    let mut hunks: HashMap<PathBuf, Vec<(usize, usize)>> = HashMap::new();
+    let mut current_file = None;
+    for line in String::from_utf8_lossy(&diff.stdout).lines() {
+        if let Some(path) = line.strip_prefix("+++ b/") {
+            current_file = Some(toplevel.join(path));
+        } else if let Some(header) = line.strip_prefix("@@ ") {
+            if let Some(path) = &current_file {
+                if let Some(range) = parse_hunk_header(header) {
+                    hunks.entry(path.clone()).or_default().push(range);
+                }
+            }
+        }
+    }
+    Ok(hunks)
+}
+
+/// Parse the post-image span (e.g. `+14,5`) out of a unified diff hunk header
+/// (e.g. `-12,3 +14,5 @@ fn foo() {`), returning `None` for a pure deletion
+/// (post-image length of zero), which touches no lines in the new file.
+fn parse_hunk_header(header: &str) -> Option<(usize, usize)> {
+    let plus = header
+        .split_whitespace()
+        .find_map(|part| part.strip_prefix('+'))?;
+    let mut parts = plus.splitn(2, ',');
+    let start: usize = parts.next()?.parse().ok()?;
+    let len: usize = match parts.next() {
+        Some(len) => len.parse().ok()?,
+        None => 1,
+    };
+    if len == 0 {
+        return None;
+    }
+    Some((start, start + len - 1))
+}
@@ -0,0 +1,217 @@
+//! `--analyze-graph`: build the project's first-party import dependency
+//! graph, serialize it as JSON or DOT, and flag any cycles it contains.
+//!
+//! Resolution reuses the same machinery isort's import categorization is
+//! built on (`isort::categorize`, `fs::resolve_module`): a module only
+//! becomes a node once it's classified as first-party, so standard-library
+//! and third-party imports never show up in the graph. Relative imports
+//! (`from . import foo`) are classified as first-party too, but since
+//! `resolve_module` only resolves dotted names against `src` roots, they
+//! contribute no edge on their own; teams that need those edges as well
+//! should route through an absolute import instead, for now.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use rustpython_ast::{Stmt, StmtKind};
+
+use crate::ast::visitor::{walk_stmt, Visitor};
+use crate::fs::{self, iter_python_files, relativize_path, resolve_module};
+use crate::isort::categorize::{categorize, ImportType};
+use crate::linter::{parse_program_tokens, tokenize};
+use crate::settings::Settings;
+
+/// Output format for `--analyze-graph`.
+#[derive(Clone, Copy, ValueEnum, PartialEq, Eq, Debug)]
+pub enum GraphFormat {
+    Json,
+    Dot,
+}
+
+/// The project's first-party import graph: an edge from `a` to `b` means some
+/// import in `a` resolved to the first-party module at path `b`.
+#[derive(Debug, Default)]
+pub struct ImportGraph {
+    edges: BTreeMap<PathBuf, BTreeSet<PathBuf>>,
+}
+
+/// Collects the dotted module name (and, for `from` imports, the relative
+/// import level) of every `import`/`from ... import` statement in a file,
+/// regardless of nesting (e.g. inside a function or `try` block).
+#[derive(Default)]
+struct ImportCollector {
+    modules: Vec<(String, Option<usize>)>,
+}
+
+impl<'a> Visitor<'a> for ImportCollector {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        match &stmt.node {
+            StmtKind::Import { names } => {
+                for alias in names {
+                    self.modules.push((alias.node.name.clone(), None));
+                }
+            }
+            StmtKind::ImportFrom { module, level, .. } => {
+                self.modules
+                    .push((module.clone().unwrap_or_default(), *level));
+            }
+            _ => walk_stmt(self, stmt),
+        }
+    }
+}
+
+fn module_base(module: &str) -> &str {
+    module.split('.').next().unwrap_or(module)
+}
+
+/// Walk every Python file reachable from `files`, and build the first-party
+/// import graph between them.
+pub fn build(files: &[PathBuf], settings: &Settings) -> Result<ImportGraph> {
+    let paths: Vec<PathBuf> = files
+        .iter()
+        .flat_map(|path| {
+            iter_python_files(
+                path,
+                &settings.exclude,
+                &settings.extend_exclude,
+                settings.follow_symlinks,
+            )
+        })
+        .flatten()
+        .map(|entry| entry.path().to_path_buf())
+        .collect();
+
+    let mut graph = ImportGraph::default();
+    for path in &paths {
+        let contents = fs::read_file(path)?;
+        let tokens = tokenize(&contents);
+        let Ok(python_ast) = parse_program_tokens(tokens, &path.to_string_lossy()) else {
+            // A file that doesn't parse contributes no edges, rather than
+            // failing the whole graph -- the linter's own syntax-error check
+            // (E999) is the right place to surface that.
+            continue;
+        };
+
+        let mut collector = ImportCollector::default();
+        for stmt in &python_ast {
+            collector.visit_stmt(stmt);
+        }
+
+        let targets = graph.edges.entry(fs::normalize_path(path)).or_default();
+        for (module, level) in &collector.modules {
+            let base = module_base(module);
+            let import_type = categorize(
+                base,
+                level,
+                &settings.src,
+                &settings.isort.known_first_party,
+                &settings.isort.known_third_party,
+                &settings.isort.extra_standard_library,
+            );
+            if import_type != ImportType::FirstParty {
+                continue;
+            }
+            let resolved = resolve_module(&settings.src, module)
+                .or_else(|| resolve_module(&settings.src, base));
+            if let Some(resolved) = resolved {
+                targets.insert(fs::normalize_path(&resolved));
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Depth-first search for cycles in `graph`, returning each one as the
+/// sequence of files that make it up. Reports every cycle it finds rather
+/// than collapsing them into minimal strongly-connected components, since a
+/// project with a handful of files involved in a cycle wants to see all of
+/// the paths through it, not just proof that one exists.
+pub fn cycles(graph: &ImportGraph) -> Vec<Vec<PathBuf>> {
+    let mut visited: BTreeSet<PathBuf> = BTreeSet::new();
+    let mut found = Vec::new();
+    for start in graph.edges.keys() {
+        if !visited.contains(start) {
+            let mut stack = Vec::new();
+            visit(graph, start, &mut visited, &mut stack, &mut found);
+        }
+    }
+    found
+}
+
+fn visit(
+    graph: &ImportGraph,
+    node: &PathBuf,
+    visited: &mut BTreeSet<PathBuf>,
+    stack: &mut Vec<PathBuf>,
+    found: &mut Vec<Vec<PathBuf>>,
+) {
+    if let Some(pos) = stack.iter().position(|visiting| visiting == node) {
+        found.push(stack[pos..].to_vec());
+        return;
+    }
+    if !visited.insert(node.clone()) {
+        return;
+    }
+
+    stack.push(node.clone());
+    if let Some(targets) = graph.edges.get(node) {
+        for target in targets {
+            visit(graph, target, visited, stack, found);
+        }
+    }
+    stack.pop();
+}
+
+/// Format each cycle found in `graph` as a human-readable chain of
+/// (relativized) file paths, e.g. for printing as a warning.
+pub fn describe_cycles(graph: &ImportGraph) -> Vec<String> {
+    cycles(graph)
+        .iter()
+        .map(|cycle| {
+            cycle
+                .iter()
+                .map(|path| relativize_path(path))
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        })
+        .collect()
+}
+
+/// Serialize `graph` as a JSON object mapping each file to the files it
+/// imports, both relativized to the current working directory for
+/// readability.
+pub fn to_json(graph: &ImportGraph) -> Result<String> {
+    let edges: BTreeMap<String, BTreeSet<String>> = graph
+        .edges
+        .iter()
+        .map(|(node, targets)| {
+            (
+                relativize_path(node).into_owned(),
+                targets
+                    .iter()
+                    .map(|target| relativize_path(target).into_owned())
+                    .collect(),
+            )
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&edges)?)
+}
+
+/// Serialize `graph` as a Graphviz DOT digraph.
+pub fn to_dot(graph: &ImportGraph) -> String {
+    let mut dot = String::from("digraph imports {\n");
+    for (node, targets) in &graph.edges {
+        for target in targets {
+            dot.push_str(&format!(
+                "    {:?} -> {:?};\n",
+                relativize_path(node),
+                relativize_path(target)
+            ));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
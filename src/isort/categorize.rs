@@ -1,9 +1,9 @@
 use std::collections::{BTreeMap, BTreeSet};
-use std::fs;
 use std::path::PathBuf;
 
 use once_cell::sync::Lazy;
 
+use crate::fs::resolve_module;
 use crate::python::sys::KNOWN_STANDARD_LIBRARY;
 
 #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone)]
@@ -35,7 +35,7 @@ pub fn categorize(
         import_type.clone()
     } else if KNOWN_STANDARD_LIBRARY.contains(module_base) {
         ImportType::StandardLibrary
-    } else if find_local(src, module_base) {
+    } else if resolve_module(src, module_base).is_some() {
         ImportType::FirstParty
     } else {
         ImportType::ThirdParty
@@ -49,19 +49,3 @@ static STATIC_CLASSIFICATIONS: Lazy<BTreeMap<&'static str, ImportType>> = Lazy::
         ("", ImportType::FirstParty),
     ])
 });
-
-fn find_local(paths: &[PathBuf], base: &str) -> bool {
-    for path in paths {
-        if let Ok(metadata) = fs::metadata(path.join(base)) {
-            if metadata.is_dir() {
-                return true;
-            }
-        }
-        if let Ok(metadata) = fs::metadata(path.join(format!("{base}.py"))) {
-            if metadata.is_file() {
-                return true;
-            }
-        }
-    }
-    false
-}
@@ -0,0 +1,58 @@
+use rustpython_ast::{Location, Stmt};
+
+use crate::ast::types::Range;
+use crate::isort::types::Comments;
+use crate::source_code_locator::SourceCodeLocator;
+
+/// Extract the leading (own-line) and trailing (end-of-line) comments attached to an import
+/// statement, so that they can be re-attached when the statement is re-sorted.
+pub fn collect(stmt: &Stmt, locator: &SourceCodeLocator) -> Comments {
+    Comments {
+        leading: leading_comments(stmt, locator),
+        trailing: trailing_comment(stmt, locator),
+    }
+}
+
+fn line_content(row: usize, locator: &SourceCodeLocator) -> String {
+    let range = Range {
+        location: Location::new(row, 0),
+        end_location: Location::new(row + 1, 0),
+    };
+    locator
+        .slice_source_code_range(&range)
+        .trim_end_matches(['\n', '\r'])
+        .to_string()
+}
+
+/// Comment-only lines directly above the statement, in source order.
+fn leading_comments(stmt: &Stmt, locator: &SourceCodeLocator) -> Vec<String> {
+    let mut comments = vec![];
+    let mut row = stmt.location.row();
+    while row > 1 {
+        row -= 1;
+        let line = line_content(row, locator);
+        if line.trim_start().starts_with('#') {
+            comments.push(line.trim().to_string());
+        } else {
+            break;
+        }
+    }
+    comments.reverse();
+    comments
+}
+
+/// The end-of-line comment following the statement, if any (e.g., `# noqa`).
+fn trailing_comment(stmt: &Stmt, locator: &SourceCodeLocator) -> Option<String> {
+    let end_location = stmt.end_location.unwrap();
+    let range = Range {
+        location: end_location,
+        end_location: Location::new(end_location.row() + 1, 0),
+    };
+    let suffix = locator.slice_source_code_range(&range);
+    let trimmed = suffix.trim();
+    if trimmed.starts_with('#') {
+        Some(trimmed.to_string())
+    } else {
+        None
+    }
+}
@@ -8,8 +8,10 @@ use rustpython_ast::{Stmt, StmtKind};
 use crate::isort::categorize::{categorize, ImportType};
 use crate::isort::sorting::{member_key, module_key};
 use crate::isort::types::{AliasData, ImportBlock, ImportFromData, Importable, OrderedImportBlock};
+use crate::source_code_locator::SourceCodeLocator;
 
-mod categorize;
+pub(crate) mod categorize;
+mod comments;
 pub mod plugins;
 pub mod settings;
 mod sorting;
@@ -19,15 +21,20 @@ mod types;
 // Hard-code four-space indentation for the imports themselves, to match Black.
 const INDENT: &str = "    ";
 
-fn normalize_imports<'a>(imports: &'a [&'a Stmt]) -> ImportBlock<'a> {
+fn normalize_imports<'a>(imports: &'a [&'a Stmt], locator: &SourceCodeLocator) -> ImportBlock<'a> {
     let mut block: ImportBlock = Default::default();
     for import in imports {
+        // Comments are attached to the statement they came from, not to any one member, so a
+        // multi-member statement (e.g. `import a, b  # noqa`) carries the same comments onto
+        // each of its members.
+        let comments = comments::collect(import, locator);
         match &import.node {
             StmtKind::Import { names } => {
                 for name in names {
                     block.import.insert(AliasData {
                         name: &name.node.name,
                         asname: &name.node.asname,
+                        comments: comments.clone(),
                     });
                 }
             }
@@ -44,6 +51,7 @@ fn normalize_imports<'a>(imports: &'a [&'a Stmt]) -> ImportBlock<'a> {
                     targets.insert(AliasData {
                         name: &name.node.name,
                         asname: &name.node.asname,
+                        comments: comments.clone(),
                     });
                 }
             }
@@ -128,6 +136,7 @@ fn sort_imports(block: ImportBlock) -> OrderedImportBlock {
 
 pub fn format_imports(
     block: Vec<&Stmt>,
+    locator: &SourceCodeLocator,
     line_length: &usize,
     src: &[PathBuf],
     known_first_party: &BTreeSet<String>,
@@ -135,7 +144,7 @@ pub fn format_imports(
     extra_standard_library: &BTreeSet<String>,
 ) -> String {
     // Normalize imports (i.e., deduplicate, aggregate `from` imports).
-    let block = normalize_imports(&block);
+    let block = normalize_imports(&block, locator);
 
     // Categorize by type (e.g., first-party vs. third-party).
     let block_by_type = categorize_imports(
@@ -160,20 +169,29 @@ pub fn format_imports(
         }
 
         // Format `StmtKind::Import` statements.
-        for AliasData { name, asname } in import_block.import.iter() {
+        for alias @ AliasData { name, asname, .. } in import_block.import.iter() {
+            for comment in &alias.comments.leading {
+                output.append(comment);
+                output.append("\n");
+            }
             if let Some(asname) = asname {
-                output.append(&format!("import {} as {}\n", name, asname));
+                output.append(&format!("import {} as {}", name, asname));
             } else {
-                output.append(&format!("import {}\n", name));
+                output.append(&format!("import {}", name));
+            }
+            if let Some(trailing) = &alias.comments.trailing {
+                output.append(&format!("  {}", trailing));
             }
+            output.append("\n");
         }
 
         // Format `StmtKind::ImportFrom` statements.
         for (import_from, aliases) in import_block.import_from.iter() {
             let prelude: String = format!("from {} import ", import_from.module_name());
+            let has_comments = aliases.iter().any(|alias| !alias.comments.is_empty());
             let members: Vec<String> = aliases
                 .iter()
-                .map(|AliasData { name, asname }| {
+                .map(|AliasData { name, asname, .. }| {
                     if let Some(asname) = asname {
                         format!("{} as {}", name, asname)
                     } else {
@@ -182,7 +200,9 @@ pub fn format_imports(
                 })
                 .collect();
 
-            // Can we fit the import on a single line?
+            // Can we fit the import on a single line? Members with attached comments always
+            // force the parenthesized, one-member-per-line form, so that each comment has
+            // somewhere to live.
             let expected_len: usize =
                     // `from base import `
                     prelude.len()
@@ -191,7 +211,7 @@ pub fn format_imports(
                         // `, `
                         + 2 * (members.len() - 1);
 
-            if expected_len <= *line_length {
+            if !has_comments && expected_len <= *line_length {
                 // `from base import `
                 output.append(&prelude);
                 // `member( as alias)?(, )?`
@@ -210,10 +230,18 @@ pub fn format_imports(
                 output.append("\n");
 
                 // `    member( as alias)?,\n`
-                for part in members {
+                for (alias, part) in aliases.iter().zip(members) {
+                    for comment in &alias.comments.leading {
+                        output.append(INDENT);
+                        output.append(comment);
+                        output.append("\n");
+                    }
                     output.append(INDENT);
                     output.append(&part);
                     output.append(",");
+                    if let Some(trailing) = &alias.comments.trailing {
+                        output.append(&format!("  {}", trailing));
+                    }
                     output.append("\n");
                 }
 
@@ -245,6 +273,7 @@ mod tests {
     #[test_case(Path::new("leading_prefix.py"))]
     #[test_case(Path::new("no_reorder_within_section.py"))]
     #[test_case(Path::new("order_by_type.py"))]
+    #[test_case(Path::new("preserve_comments.py"))]
     #[test_case(Path::new("preserve_indentation.py"))]
     #[test_case(Path::new("reorder_within_section.py"))]
     #[test_case(Path::new("separate_first_party_imports.py"))]
@@ -44,7 +44,10 @@ fn match_trailing_content(body: &[&Stmt], locator: &SourceCodeLocator) -> bool {
         end_location: Location::new(end_location.row() + 1, 0),
     };
     let suffix = locator.slice_source_code_range(&range);
-    suffix.chars().any(|char| !char.is_whitespace())
+    // A trailing comment (e.g., `import os  # noqa`) isn't "trailing content" in the sense this
+    // check cares about: it's re-attached to the import by `format_imports`, rather than
+    // stripped, so it doesn't need the leading/trailing special-case handling below.
+    !suffix.trim().is_empty() && !suffix.trim_start().starts_with('#')
 }
 
 /// I001
@@ -64,6 +67,7 @@ pub fn check_imports(
     // Generate the sorted import block.
     let expected = format_imports(
         body,
+        locator,
         &(settings.line_length - indentation.len()),
         &settings.src,
         &settings.isort.known_first_party,
@@ -2,13 +2,20 @@
 
 use std::collections::BTreeSet;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 pub struct Options {
+    /// A list of modules to consider first-party, regardless of whether they
+    /// can be identified as such via introspection of the local filesystem.
     pub known_first_party: Option<Vec<String>>,
+    /// A list of modules to consider third-party, regardless of whether they
+    /// can be identified as such via introspection of the local filesystem.
     pub known_third_party: Option<Vec<String>>,
+    /// A list of modules to consider standard-library, in addition to those
+    /// known to the current Python version.
     pub extra_standard_library: Option<Vec<String>>,
 }
 
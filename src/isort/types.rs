@@ -1,4 +1,6 @@
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet};
+use std::hash::{Hash, Hasher};
 
 #[derive(Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
 pub struct ImportFromData<'a> {
@@ -6,10 +8,55 @@ pub struct ImportFromData<'a> {
     pub level: &'a Option<usize>,
 }
 
-#[derive(Debug, Hash, Ord, PartialOrd, Eq, PartialEq)]
+/// Leading (own-line) and trailing (end-of-line) comments attached to an import statement.
+#[derive(Debug, Default, Clone)]
+pub struct Comments {
+    pub leading: Vec<String>,
+    pub trailing: Option<String>,
+}
+
+impl Comments {
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && self.trailing.is_none()
+    }
+}
+
+/// An imported member, plus any comments attached to the import statement it came from.
+///
+/// `comments` is deliberately excluded from equality, ordering, and hashing: two aliases that
+/// import the same name are the same import for sorting and deduplication purposes, regardless
+/// of which one happened to carry a comment.
+#[derive(Debug)]
 pub struct AliasData<'a> {
     pub name: &'a str,
     pub asname: &'a Option<String>,
+    pub comments: Comments,
+}
+
+impl PartialEq for AliasData<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.name, self.asname) == (other.name, other.asname)
+    }
+}
+
+impl Eq for AliasData<'_> {}
+
+impl PartialOrd for AliasData<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AliasData<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.name, self.asname).cmp(&(other.name, other.asname))
+    }
+}
+
+impl Hash for AliasData<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.name, self.asname).hash(state);
+    }
 }
 
 pub trait Importable {
@@ -15,6 +15,7 @@ use crate::source_code_locator::SourceCodeLocator;
 
 mod ast;
 pub mod autofix;
+pub mod baseline;
 pub mod cache;
 pub mod check_ast;
 mod check_imports;
@@ -25,8 +26,12 @@ pub mod checks_gen;
 pub mod cli;
 pub mod code_gen;
 mod cst;
+#[cfg(not(target_family = "wasm"))]
+pub mod daemon;
 mod directives;
+pub mod doctest;
 mod docstrings;
+pub mod duplicate_code;
 mod flake8_2020;
 pub mod flake8_annotations;
 pub mod flake8_bandit;
@@ -35,8 +40,13 @@ mod flake8_builtins;
 mod flake8_comprehensions;
 mod flake8_print;
 pub mod flake8_quotes;
+pub mod flake8_to_ruff;
+pub mod format;
 pub mod fs;
+pub mod git;
+pub mod import_graph;
 mod isort;
+pub mod jupyter;
 mod lex;
 pub mod linter;
 pub mod logging;
@@ -47,12 +57,20 @@ pub mod printer;
 mod pycodestyle;
 mod pydocstyle;
 mod pyflakes;
+mod pylint;
 mod python;
 mod pyupgrade;
 mod rules;
+#[cfg(not(target_family = "wasm"))]
+pub mod server;
 pub mod settings;
 pub mod source_code_locator;
+pub mod timings;
 pub mod visibility;
+#[cfg(target_family = "wasm")]
+pub mod wasm;
+#[cfg(feature = "extension-module")]
+mod pymodule;
 
 /// Run Ruff over Python source code directly.
 pub fn check(path: &Path, contents: &str, autofix: bool) -> Result<Vec<Check>> {
@@ -93,6 +111,8 @@ pub fn check(path: &Path, contents: &str, autofix: bool) -> Result<Vec<Check>> {
         &directives,
         &settings,
         &if autofix { Mode::Generate } else { Mode::None },
+        false,
+        false,
     )?;
 
     Ok(checks)
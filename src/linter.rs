@@ -1,11 +1,15 @@
-use std::fs::write;
 use std::io;
 use std::io::Write;
 use std::path::Path;
+#[cfg(not(target_family = "wasm"))]
+use std::time::Instant;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 #[cfg(not(target_family = "wasm"))]
 use log::debug;
+#[cfg(not(target_family = "wasm"))]
+use log::trace;
+use nohash_hasher::IntSet;
 use rustpython_ast::{Mod, Suite};
 use rustpython_parser::error::ParseError;
 use rustpython_parser::lexer::LexResult;
@@ -20,7 +24,6 @@ use crate::check_imports::check_imports;
 use crate::check_lines::check_lines;
 use crate::check_tokens::check_tokens;
 use crate::checks::{Check, CheckCode, CheckKind, LintSource};
-use crate::code_gen::SourceGenerator;
 use crate::directives::Directives;
 use crate::message::Message;
 use crate::noqa::add_noqa;
@@ -29,6 +32,15 @@ use crate::source_code_locator::SourceCodeLocator;
 use crate::{cache, directives, fs};
 
 /// Collect tokens up to and including the first error.
+///
+/// Callers tokenize a file exactly once and thread the resulting
+/// `Vec<LexResult>` through `directives::extract_directives` (`# noqa`,
+/// `# isort`, and `# ruff: disable` directives), `check_path`'s token-based
+/// checks, and
+/// `parse_program_tokens` (which consumes it to build the AST for the
+/// AST-based and import-based checks). Autofix rendering doesn't retokenize
+/// either: fixes are computed from the ranges already recorded on each
+/// `Check` during those passes.
 pub(crate) fn tokenize(contents: &str) -> Vec<LexResult> {
     let mut tokens: Vec<LexResult> = vec![];
     for tok in lexer::make_tokenizer(contents) {
@@ -52,6 +64,12 @@ pub(crate) fn parse_program_tokens(
     })
 }
 
+/// Run the enabled checks against a single file's tokens/AST/lines.
+///
+/// The token- and line-based passes don't depend on a successful parse, so a
+/// syntax error only suppresses the AST- and import-based checks: it's
+/// reported as an `E999` diagnostic rather than aborting the whole file.
+#[cfg_attr(target_family = "wasm", allow(unused_variables))]
 pub(crate) fn check_path(
     path: &Path,
     contents: &str,
@@ -60,6 +78,8 @@ pub(crate) fn check_path(
     directives: &Directives,
     settings: &Settings,
     autofix: &fixer::Mode,
+    timings: bool,
+    ignore_noqa: bool,
 ) -> Result<Vec<Check>> {
     // Aggregate all checks.
     let mut checks: Vec<Check> = vec![];
@@ -70,7 +90,21 @@ pub(crate) fn check_path(
         .iter()
         .any(|check_code| matches!(check_code.lint_source(), LintSource::Tokens));
     if use_tokens {
+        #[cfg(not(target_family = "wasm"))]
+        let start = Instant::now();
         check_tokens(&mut checks, locator, &tokens, settings, autofix);
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let elapsed = start.elapsed();
+            trace!(
+                "Token-based checks took {:?} for: {}",
+                elapsed,
+                path.to_string_lossy()
+            );
+            if timings {
+                crate::timings::record(LintSource::Tokens, elapsed);
+            }
+        }
     }
 
     // Run the AST-based checks.
@@ -86,25 +120,66 @@ pub(crate) fn check_path(
         match parse_program_tokens(tokens, "<filename>") {
             Ok(python_ast) => {
                 if use_ast {
+                    #[cfg(not(target_family = "wasm"))]
+                    let start = Instant::now();
                     checks.extend(check_ast(&python_ast, locator, settings, autofix, path));
+                    #[cfg(not(target_family = "wasm"))]
+                    {
+                        let elapsed = start.elapsed();
+                        trace!(
+                            "AST-based checks took {:?} for: {}",
+                            elapsed,
+                            path.to_string_lossy()
+                        );
+                        if timings {
+                            crate::timings::record(LintSource::AST, elapsed);
+                        }
+                    }
                 }
                 if use_imports {
+                    #[cfg(not(target_family = "wasm"))]
+                    let start = Instant::now();
+                    // Don't let isort touch a `# fmt: off` block any more than
+                    // it touches an `# isort: off` block.
+                    let isort_exclusions: IntSet<usize> = directives
+                        .isort_exclusions
+                        .iter()
+                        .chain(directives.fmt_exclusions.iter())
+                        .copied()
+                        .collect();
                     checks.extend(check_imports(
                         &python_ast,
                         locator,
-                        &directives.isort_exclusions,
+                        &isort_exclusions,
                         settings,
                         autofix,
                     ));
+                    #[cfg(not(target_family = "wasm"))]
+                    {
+                        let elapsed = start.elapsed();
+                        trace!(
+                            "Import-based checks took {:?} for: {}",
+                            elapsed,
+                            path.to_string_lossy()
+                        );
+                        if timings {
+                            crate::timings::record(LintSource::Imports, elapsed);
+                        }
+                    }
                 }
             }
             Err(parse_error) => {
                 if settings.enabled.contains(&CheckCode::E999) {
+                    // The parser only gives us a start position, so approximate
+                    // the end of the offending span as the rest of that line.
+                    let end_location = locator
+                        .line_end(parse_error.location.row())
+                        .max(parse_error.location);
                     checks.push(Check::new(
                         CheckKind::SyntaxError(parse_error.error.to_string()),
                         Range {
                             location: parse_error.location,
-                            end_location: parse_error.location,
+                            end_location,
                         },
                     ))
                 }
@@ -113,33 +188,82 @@ pub(crate) fn check_path(
     }
 
     // Run the lines-based checks.
+    #[cfg(not(target_family = "wasm"))]
+    let start = Instant::now();
     check_lines(
         &mut checks,
         contents,
         &directives.noqa_line_for,
+        &directives.fmt_exclusions,
         settings,
         autofix,
+        ignore_noqa,
     );
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let elapsed = start.elapsed();
+        trace!(
+            "Line-based checks took {:?} for: {}",
+            elapsed,
+            path.to_string_lossy()
+        );
+        if timings {
+            crate::timings::record(LintSource::Lines, elapsed);
+        }
+    }
+
+    // Remove any checks suppressed by a `# ruff: disable` region.
+    directives::filter_disabled(&mut checks, &directives.ruff_disables);
 
     // Create path ignores.
     if !checks.is_empty() && !settings.per_file_ignores.is_empty() {
         let ignores = fs::ignores_from_path(path, &settings.per_file_ignores)?;
         if !ignores.is_empty() {
-            return Ok(checks
-                .into_iter()
-                .filter(|check| !ignores.contains(&check.kind.code()))
-                .collect());
+            checks.retain(|check| !ignores.contains(&check.kind.code()));
         }
     }
 
+    // Cap the number of violations reported for a single file, so a
+    // generated file riddled with (e.g.) `E501`s doesn't flood the terminal
+    // or inflate memory use. The overflow is collapsed into one summary
+    // check rather than silently dropped.
+    if checks.len() > settings.max_violations_per_file
+        && settings.enabled.contains(&CheckCode::RUF008)
+    {
+        let suppressed = checks.split_off(settings.max_violations_per_file);
+        checks.push(Check::new(
+            CheckKind::TooManyViolations(suppressed.len()),
+            Range::default(),
+        ));
+    }
+
     Ok(checks)
 }
 
+/// Lint `source` under `settings`, without touching the filesystem or the
+/// cache. This is the entry point for embedding Ruff as a library: unlike
+/// `lint_path`, it takes source text directly, and unlike `lint_stdin`, it
+/// isn't tied to the CLI's stdin-linting mode.
+pub fn lint_source(source: &str, settings: &Settings) -> Result<Vec<Message>> {
+    lint_stdin(
+        Path::new("<source>"),
+        source,
+        settings,
+        &fixer::Mode::None,
+        false,
+        false,
+        false,
+    )
+}
+
 pub fn lint_stdin(
     path: &Path,
     stdin: &str,
     settings: &Settings,
     autofix: &fixer::Mode,
+    unsafe_fixes: bool,
+    timings: bool,
+    ignore_noqa: bool,
 ) -> Result<Vec<Message>> {
     // Tokenize once.
     let tokens: Vec<LexResult> = tokenize(stdin);
@@ -163,11 +287,13 @@ pub fn lint_stdin(
         &directives,
         settings,
         autofix,
+        timings,
+        ignore_noqa,
     )?;
 
     // Apply autofix, write results to stdout.
     if matches!(autofix, fixer::Mode::Apply) {
-        match fix_file(&mut checks, &locator) {
+        match fix_file(&mut checks, &locator, unsafe_fixes) {
             None => io::stdout().write_all(stdin.as_bytes()),
             Some(contents) => io::stdout().write_all(contents.as_bytes()),
         }?;
@@ -176,7 +302,7 @@ pub fn lint_stdin(
     // Convert to messages.
     Ok(checks
         .into_iter()
-        .map(|check| Message::from_check(path.to_string_lossy().to_string(), check))
+        .map(|check| Message::from_check(path.to_string_lossy().to_string(), check, settings))
         .collect())
 }
 
@@ -186,16 +312,44 @@ pub fn lint_path(
     settings: &Settings,
     mode: &cache::Mode,
     autofix: &fixer::Mode,
+    cache_dir: &Path,
+    unsafe_fixes: bool,
+    timings: bool,
+    ignore_noqa: bool,
 ) -> Result<Vec<Message>> {
     let metadata = path.metadata()?;
 
-    // Check the cache.
+    // Skip files above `max-file-size` and files that look binary, rather
+    // than risk exhausting memory tokenizing and parsing them. Checked
+    // before the cache lookup, since it's cheaper than hashing settings, and
+    // before reading the file, since that's the allocation we're avoiding.
+    if metadata.len() > settings.max_file_size {
+        return Err(anyhow!(
+            "{} exceeds the configured max-file-size ({} > {} bytes); skipping",
+            path.to_string_lossy(),
+            metadata.len(),
+            settings.max_file_size
+        ));
+    }
+    if fs::is_binary(path)? {
+        return Err(anyhow!("{} appears to be a binary file; skipping", path.to_string_lossy()));
+    }
+
+    // Check the cache. Bypassed entirely under `--ignore-noqa`, since the
+    // cache key doesn't account for it and its results would otherwise leak
+    // into (or be polluted by) normal, noqa-respecting runs.
     #[cfg(not(target_family = "wasm"))]
-    if let Some(messages) = cache::get(path, &metadata, settings, autofix, mode) {
-        debug!("Cache hit for: {}", path.to_string_lossy());
-        return Ok(messages);
+    if !ignore_noqa {
+        if let Some(messages) = cache::get(path, &metadata, settings, autofix, mode, cache_dir) {
+            debug!("Cache hit for: {}", path.to_string_lossy());
+            return Ok(messages);
+        }
+        debug!("Cache miss for: {}", path.to_string_lossy());
     }
 
+    #[cfg(not(target_family = "wasm"))]
+    let start = Instant::now();
+
     // Read the file from disk.
     let contents = fs::read_file(path)?;
 
@@ -221,22 +375,52 @@ pub fn lint_path(
         &directives,
         settings,
         autofix,
+        timings,
+        ignore_noqa,
     )?;
 
     // Apply autofix.
+    //
+    // TODO(charlie): For a file that `fs::read_file` decoded from a
+    // non-UTF-8 encoding cookie, this writes the fix back out as UTF-8
+    // rather than round-tripping to the original encoding. Re-encoding on
+    // write needs the source encoding threaded alongside `contents`, which
+    // no caller here currently carries.
     if matches!(autofix, fixer::Mode::Apply) {
-        if let Some(fixed_contents) = fix_file(&mut checks, &locator) {
-            write(path, fixed_contents.as_ref())?;
+        if let Some(fixed_contents) = fix_file(&mut checks, &locator, unsafe_fixes) {
+            let conventions = fs::read_file_conventions(path)?;
+            // Normalize to a single line-ending convention, then restore the
+            // file's own: `fixed_contents` mixes untouched `\r\n` regions
+            // (copied verbatim from the original) with bare `\n` in whatever
+            // a fix generated, so a blind `\n` -> `\r\n` replacement would
+            // double up the former.
+            let mut fixed_contents = fixed_contents.replace("\r\n", "\n");
+            if conventions.crlf {
+                fixed_contents = fixed_contents.replace('\n', "\r\n");
+            }
+            if conventions.bom {
+                fixed_contents.insert(0, '\u{feff}');
+            }
+            fs::write_atomic(path, &fixed_contents)?;
         }
     };
 
     // Convert to messages.
     let messages: Vec<Message> = checks
         .into_iter()
-        .map(|check| Message::from_check(path.to_string_lossy().to_string(), check))
+        .map(|check| Message::from_check(path.to_string_lossy().to_string(), check, settings))
         .collect();
     #[cfg(not(target_family = "wasm"))]
-    cache::set(path, &metadata, settings, autofix, &messages, mode);
+    if !ignore_noqa {
+        cache::set(path, &metadata, settings, autofix, &messages, mode, cache_dir);
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    debug!(
+        "Checked {} in {:?}",
+        path.to_string_lossy(),
+        start.elapsed()
+    );
 
     Ok(messages)
 }
@@ -267,23 +451,25 @@ pub fn add_noqa_to_path(path: &Path, settings: &Settings) -> Result<usize> {
         &directives,
         settings,
         &fixer::Mode::None,
+        false,
+        false,
     )?;
 
-    add_noqa(&checks, &contents, &directives.noqa_line_for, path)
+    add_noqa(
+        &checks,
+        &contents,
+        &directives.noqa_line_for,
+        settings.line_length,
+        path,
+    )
 }
 
 pub fn autoformat_path(path: &Path) -> Result<()> {
     // Read the file from disk.
     let contents = fs::read_file(path)?;
 
-    // Tokenize once.
-    let tokens: Vec<LexResult> = tokenize(&contents);
-
-    // Generate the AST.
-    let python_ast = parse_program_tokens(tokens, "<filename>")?;
-    let mut generator: SourceGenerator = Default::default();
-    generator.unparse_suite(&python_ast)?;
-    write(path, generator.generate()?)?;
+    let formatted = crate::format::format_source(path, &contents)?;
+    fs::write_atomic(path, &formatted)?;
 
     Ok(())
 }
@@ -306,6 +492,8 @@ pub fn test_path(path: &Path, settings: &Settings, autofix: &fixer::Mode) -> Res
         &directives,
         settings,
         autofix,
+        false,
+        false,
     )
 }
 
@@ -371,6 +559,9 @@ mod tests {
     #[test_case(CheckCode::D105, Path::new("D.py"); "D105")]
     #[test_case(CheckCode::D106, Path::new("D.py"); "D106")]
     #[test_case(CheckCode::D107, Path::new("D.py"); "D107")]
+    #[test_case(CheckCode::D100, Path::new("D_pyi.pyi"); "D100_pyi")]
+    #[test_case(CheckCode::D101, Path::new("D_pyi.pyi"); "D101_pyi")]
+    #[test_case(CheckCode::D103, Path::new("D_pyi.pyi"); "D103_pyi")]
     #[test_case(CheckCode::D201, Path::new("D.py"); "D201")]
     #[test_case(CheckCode::D202, Path::new("D.py"); "D202")]
     #[test_case(CheckCode::D203, Path::new("D.py"); "D203")]
@@ -408,6 +599,18 @@ mod tests {
     #[test_case(CheckCode::D417, Path::new("canonical_google_examples.py"); "D417_2")]
     #[test_case(CheckCode::D418, Path::new("D.py"); "D418")]
     #[test_case(CheckCode::D419, Path::new("D.py"); "D419")]
+    #[test_case(CheckCode::E101, Path::new("E101.py"); "E101")]
+    #[test_case(CheckCode::E201, Path::new("E201.py"); "E201")]
+    #[test_case(CheckCode::E202, Path::new("E202.py"); "E202")]
+    #[test_case(CheckCode::E211, Path::new("E211.py"); "E211")]
+    #[test_case(CheckCode::E261, Path::new("E261.py"); "E261")]
+    #[test_case(CheckCode::E262, Path::new("E262.py"); "E262")]
+    #[test_case(CheckCode::E265, Path::new("E265.py"); "E265")]
+    #[test_case(CheckCode::E301, Path::new("E301.py"); "E301")]
+    #[test_case(CheckCode::E302, Path::new("E302.py"); "E302")]
+    #[test_case(CheckCode::E303, Path::new("E303.py"); "E303")]
+    #[test_case(CheckCode::E304, Path::new("E304.py"); "E304")]
+    #[test_case(CheckCode::E305, Path::new("E305.py"); "E305")]
     #[test_case(CheckCode::E402, Path::new("E402.py"); "E402")]
     #[test_case(CheckCode::E501, Path::new("E501.py"); "E501")]
     #[test_case(CheckCode::E711, Path::new("E711.py"); "E711")]
@@ -448,6 +651,7 @@ mod tests {
     #[test_case(CheckCode::F706, Path::new("F706.py"); "F706")]
     #[test_case(CheckCode::F707, Path::new("F707.py"); "F707")]
     #[test_case(CheckCode::F722, Path::new("F722.py"); "F722")]
+    #[test_case(CheckCode::F811, Path::new("F811.py"); "F811")]
     #[test_case(CheckCode::F821, Path::new("F821_0.py"); "F821_0")]
     #[test_case(CheckCode::F821, Path::new("F821_1.py"); "F821_1")]
     #[test_case(CheckCode::F821, Path::new("F821_2.py"); "F821_2")]
@@ -496,14 +700,19 @@ mod tests {
     #[test_case(CheckCode::U011, Path::new("U011_0.py"); "U011_0")]
     #[test_case(CheckCode::U011, Path::new("U011_1.py"); "U011_1")]
     #[test_case(CheckCode::U012, Path::new("U012.py"); "U012")]
+    #[test_case(CheckCode::W191, Path::new("W191.py"); "W191")]
     #[test_case(CheckCode::W292, Path::new("W292_0.py"); "W292_0")]
     #[test_case(CheckCode::W292, Path::new("W292_1.py"); "W292_1")]
     #[test_case(CheckCode::W292, Path::new("W292_2.py"); "W292_2")]
+    #[test_case(CheckCode::W505, Path::new("W505.py"); "W505")]
     #[test_case(CheckCode::W605, Path::new("W605_0.py"); "W605_0")]
     #[test_case(CheckCode::W605, Path::new("W605_1.py"); "W605_1")]
     #[test_case(CheckCode::RUF001, Path::new("RUF001.py"); "RUF001")]
     #[test_case(CheckCode::RUF002, Path::new("RUF002.py"); "RUF002")]
     #[test_case(CheckCode::RUF003, Path::new("RUF003.py"); "RUF003")]
+    #[test_case(CheckCode::RUF004, Path::new("RUF004.py"); "RUF004")]
+    #[test_case(CheckCode::RUF005, Path::new("RUF005.py"); "RUF005")]
+    #[test_case(CheckCode::RUF006, Path::new("RUF006.py"); "RUF006")]
     #[test_case(CheckCode::YTT101, Path::new("YTT101.py"); "YTT101")]
     #[test_case(CheckCode::YTT102, Path::new("YTT102.py"); "YTT102")]
     #[test_case(CheckCode::YTT103, Path::new("YTT103.py"); "YTT103")]
@@ -526,6 +735,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn ruf007_site_packages() -> Result<()> {
+        let mut checks = test_path(
+            Path::new("./resources/test/fixtures/RUF007.py"),
+            &settings::Settings {
+                site_packages: vec![
+                    Path::new("resources/test/fixtures/ruf007_site_packages").to_path_buf(),
+                ],
+                ..settings::Settings::for_rule(CheckCode::RUF007)
+            },
+            &fixer::Mode::Generate,
+        )?;
+        checks.sort_by_key(|check| check.location);
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
+
     #[test]
     fn f841_dummy_variable_rgx() -> Result<()> {
         let mut checks = test_path(
@@ -542,10 +768,26 @@ mod tests {
     }
 
     #[test]
-    fn m001() -> Result<()> {
+    fn e999_partial_linting() -> Result<()> {
+        let mut checks = test_path(
+            Path::new("./resources/test/fixtures/E999_partial.py"),
+            &settings::Settings::for_rules(vec![CheckCode::E999, CheckCode::E501]),
+            &fixer::Mode::Generate,
+        )?;
+        checks.sort_by_key(|check| check.location);
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
+
+    #[test]
+    fn ruf100() -> Result<()> {
         let mut checks = test_path(
-            Path::new("./resources/test/fixtures/M001.py"),
-            &settings::Settings::for_rules(vec![CheckCode::M001, CheckCode::E501, CheckCode::F841]),
+            Path::new("./resources/test/fixtures/RUF100.py"),
+            &settings::Settings::for_rules(vec![
+                CheckCode::RUF100,
+                CheckCode::E501,
+                CheckCode::F841,
+            ]),
             &fixer::Mode::Generate,
         )?;
         checks.sort_by_key(|check| check.location);
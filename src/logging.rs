@@ -23,8 +23,12 @@ pub enum LogLevel {
     Quiet,
     // All user-facing output (+ `log::LevelFilter::Info`).
     Default,
-    // All user-facing output (+ `log::LevelFilter::Debug`).
+    // All user-facing output, plus per-file timing and cache hit/miss stats
+    // (+ `log::LevelFilter::Debug`). Set via `-v`.
     Verbose,
+    // Everything `Verbose` reports, plus per-phase timing within each file
+    // (+ `log::LevelFilter::Trace`). Set via `-vv`.
+    VeryVerbose,
 }
 
 impl LogLevel {
@@ -32,6 +36,7 @@ impl LogLevel {
         match self {
             LogLevel::Default => log::LevelFilter::Info,
             LogLevel::Verbose => log::LevelFilter::Debug,
+            LogLevel::VeryVerbose => log::LevelFilter::Trace,
             LogLevel::Quiet => log::LevelFilter::Off,
             LogLevel::Silent => log::LevelFilter::Off,
         }
@@ -72,5 +77,6 @@ mod tests {
         assert!(LogLevel::Quiet > LogLevel::Silent);
         assert!(LogLevel::Verbose > LogLevel::Default);
         assert!(LogLevel::Verbose > LogLevel::Silent);
+        assert!(LogLevel::VeryVerbose > LogLevel::Verbose);
     }
 }
@@ -4,27 +4,44 @@ use std::process::ExitCode;
 use std::sync::mpsc::channel;
 use std::time::Instant;
 
+use ::ruff::baseline;
 #[cfg(not(target_family = "wasm"))]
 use ::ruff::cache;
 use ::ruff::checks::{CheckCode, CheckKind};
+use ::ruff::checks_gen::CheckCodePrefix;
 use ::ruff::cli::{collect_per_file_ignores, extract_log_level, warn_on, Cli, Warnable};
-use ::ruff::fs::iter_python_files;
+#[cfg(not(target_family = "wasm"))]
+use ::ruff::daemon;
+use ::ruff::duplicate_code;
+use ::ruff::flake8_to_ruff::{converter, plugin::Plugin};
+use ::ruff::fs::{self, iter_python_files};
+use ::ruff::git;
+use ::ruff::import_graph;
 use ::ruff::linter::{add_noqa_to_path, autoformat_path, lint_path, lint_stdin};
 use ::ruff::logging::{set_up_logging, LogLevel};
-use ::ruff::message::Message;
+use ::ruff::message::{Message, Severity};
 use ::ruff::printer::{Printer, SerializationFormat};
+#[cfg(not(target_family = "wasm"))]
+use ::ruff::server;
 use ::ruff::settings::configuration::Configuration;
-use ::ruff::settings::types::FilePattern;
+use ::ruff::settings::editorconfig;
+use ::ruff::settings::options::Options;
+use ::ruff::settings::types::{FilePattern, IndentStyle};
 use ::ruff::settings::user::UserConfiguration;
 use ::ruff::settings::{pyproject, Settings};
+use ::ruff::timings;
 use anyhow::Result;
 use clap::Parser;
 use colored::Colorize;
+use configparser::ini::Ini;
+use fnv::FnvHashSet;
+use ignore::DirEntry;
 use log::{debug, error};
 use notify::{raw_watcher, RecursiveMode, Watcher};
 #[cfg(not(target_family = "wasm"))]
 use rayon::prelude::*;
-use walkdir::DirEntry;
+use serde::Serialize;
+use strum::IntoEnumIterator;
 
 #[cfg(feature = "update-informer")]
 const CARGO_PKG_NAME: &str = env!("CARGO_PKG_NAME");
@@ -80,10 +97,61 @@ fn show_settings(
     );
 }
 
+#[derive(Serialize)]
+struct ExpandedCheck {
+    code: &'static str,
+    name: &'static str,
+    category: &'static str,
+    message: String,
+    fixable: bool,
+}
+
+/// List every implemented `CheckCode`, deriving each entry directly from the
+/// `CheckCode`/`CheckKind` registry in `checks.rs` so this can't drift from
+/// what's actually implemented.
+fn show_checks(format: &SerializationFormat) -> Result<()> {
+    let checks: Vec<ExpandedCheck> = CheckCode::iter()
+        .map(|check_code| {
+            let check_kind = check_code.kind();
+            ExpandedCheck {
+                code: check_kind.code().as_ref(),
+                name: check_kind.as_ref(),
+                category: check_code.category().title(),
+                message: check_kind.summary(),
+                fixable: check_kind.fixable(),
+            }
+        })
+        .collect();
+
+    match format {
+        SerializationFormat::Json => println!("{}", serde_json::to_string_pretty(&checks)?),
+        SerializationFormat::Text | SerializationFormat::Template | SerializationFormat::Azure => {
+            for check in checks {
+                println!(
+                    "{} ({}): {}{}",
+                    check.code,
+                    check.category,
+                    check.message,
+                    if check.fixable { " [fixable]" } else { "" }
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn show_files(files: &[PathBuf], settings: &Settings) {
     let mut entries: Vec<DirEntry> = files
         .iter()
-        .flat_map(|path| iter_python_files(path, &settings.exclude, &settings.extend_exclude))
+        .flat_map(|path| {
+            iter_python_files(
+                path,
+                &settings.exclude,
+                &settings.extend_exclude,
+                settings.follow_symlinks,
+            )
+        })
         .flatten()
         .collect();
     entries.sort_by(|a, b| a.path().cmp(b.path()));
@@ -92,15 +160,76 @@ fn show_files(files: &[PathBuf], settings: &Settings) {
     }
 }
 
+/// Print the aggregate wall time spent in each lint phase, sorted from
+/// slowest to fastest, for `--timings`.
+fn show_timings() {
+    println!("Timings (by rule category):");
+    for (source, duration) in timings::totals() {
+        println!("  {source:?}: {duration:?}");
+    }
+}
+
+/// Print the token stream (with source ranges) for `path`, for
+/// `--debug-tokens`.
+fn debug_tokens(path: &Path) -> Result<()> {
+    let contents = std::fs::read_to_string(path)?;
+    for result in rustpython_parser::lexer::make_tokenizer(&contents) {
+        match result {
+            Ok((start, tok, end)) => println!("{start:?} - {end:?}: {tok:?}"),
+            Err(err) => {
+                println!("{err:?}");
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Print the parsed AST (with source ranges) for `path`, for `--debug-ast`.
+fn debug_ast(path: &Path) -> Result<()> {
+    let contents = std::fs::read_to_string(path)?;
+    let python_ast = rustpython_parser::parser::parse_program(&contents, &path.to_string_lossy())?;
+    println!("{python_ast:#?}");
+    Ok(())
+}
+
+/// Convert a Flake8 INI-style configuration file into an equivalent
+/// `pyproject.toml` `[tool.ruff]` section, for `--migrate-config`.
+fn migrate_config(path: &Path, plugins: Option<Vec<Plugin>>) -> Result<String> {
+    let mut ini = Ini::new_cs();
+    ini.set_multiline(true);
+    let config = ini.load(path).map_err(|msg| anyhow::anyhow!(msg))?;
+    let flake8 = config
+        .get("flake8")
+        .ok_or_else(|| anyhow::anyhow!("Unable to find flake8 section in INI file."))?;
+    let pyproject = converter::convert(flake8, plugins)?;
+    Ok(toml::to_string_pretty(&pyproject)?)
+}
+
 fn read_from_stdin() -> Result<String> {
     let mut buffer = String::new();
     io::stdin().lock().read_to_string(&mut buffer)?;
     Ok(buffer)
 }
 
-fn run_once_stdin(settings: &Settings, filename: &Path, autofix: bool) -> Result<Vec<Message>> {
+fn run_once_stdin(
+    settings: &Settings,
+    filename: &Path,
+    autofix: bool,
+    unsafe_fixes: bool,
+    timings: bool,
+    ignore_noqa: bool,
+) -> Result<Vec<Message>> {
     let stdin = read_from_stdin()?;
-    let mut messages = lint_stdin(filename, &stdin, settings, &autofix.into())?;
+    let mut messages = lint_stdin(
+        filename,
+        &stdin,
+        settings,
+        &autofix.into(),
+        unsafe_fixes,
+        timings,
+        ignore_noqa,
+    )?;
     messages.sort_unstable();
     Ok(messages)
 }
@@ -110,13 +239,38 @@ fn run_once(
     settings: &Settings,
     cache: bool,
     autofix: bool,
-) -> Result<Vec<Message>> {
+    cache_dir: &Path,
+    diff_against: Option<&str>,
+    unsafe_fixes: bool,
+    timings: bool,
+    ignore_noqa: bool,
+) -> Result<(Vec<Message>, usize)> {
     // Collect all the files to check.
     let start = Instant::now();
-    let paths: Vec<Result<DirEntry, walkdir::Error>> = files
+    let mut paths: Vec<Result<DirEntry, ignore::Error>> = files
         .iter()
-        .flat_map(|path| iter_python_files(path, &settings.exclude, &settings.extend_exclude))
+        .flat_map(|path| {
+            iter_python_files(
+                path,
+                &settings.exclude,
+                &settings.extend_exclude,
+                settings.follow_symlinks,
+            )
+        })
         .collect();
+    if let Some(reference) = diff_against {
+        let changed: FnvHashSet<PathBuf> = git::diff_against(reference)?
+            .into_iter()
+            .filter_map(|path| path.canonicalize().ok())
+            .collect();
+        paths.retain(|entry| {
+            entry
+                .as_ref()
+                .ok()
+                .and_then(|entry| entry.path().canonicalize().ok())
+                .map_or(true, |path| changed.contains(&path))
+        });
+    }
     let duration = start.elapsed();
     debug!("Identified files to lint in: {:?}", duration);
 
@@ -126,8 +280,17 @@ fn run_once(
             match entry {
                 Ok(entry) => {
                     let path = entry.path();
-                    lint_path(path, settings, &cache.into(), &autofix.into())
-                        .map_err(|e| (Some(path.to_owned()), e.to_string()))
+                    lint_path(
+                        path,
+                        settings,
+                        &cache.into(),
+                        &autofix.into(),
+                        cache_dir,
+                        unsafe_fixes,
+                        timings,
+                        ignore_noqa,
+                    )
+                    .map_err(|e| (Some(path.to_owned()), e.to_string()))
                 }
                 Err(e) => Err((
                     e.path().map(Path::to_owned),
@@ -144,6 +307,10 @@ fn run_once(
                             location: Default::default(),
                             end_location: Default::default(),
                             filename: path.to_string_lossy().to_string(),
+                            fix: None,
+                            alternatives: Vec::new(),
+                            severity: Severity::Error,
+                            related: Vec::new(),
                         }]
                     } else {
                         error!("Failed to check {}: {message}", path.to_string_lossy());
@@ -158,19 +325,105 @@ fn run_once(
         .flatten()
         .collect();
 
+    if let Some(reference) = diff_against {
+        let hunks = git::diff_hunks(reference)?;
+        messages.retain(|message| {
+            PathBuf::from(&message.filename)
+                .canonicalize()
+                .ok()
+                .and_then(|path| hunks.get(&path))
+                .map_or(false, |ranges| {
+                    ranges
+                        .iter()
+                        .any(|&(start, end)| (start..=end).contains(&message.location.row()))
+                })
+        });
+    }
+
+    if settings.enabled.contains(&CheckCode::RUF009) {
+        let existing: Vec<PathBuf> = paths
+            .iter()
+            .filter_map(|entry| entry.as_ref().ok())
+            .map(|entry| entry.path().to_path_buf())
+            .collect();
+        messages.extend(duplicate_code::find_duplicates(&existing, settings)?);
+    }
+
     messages.sort_unstable();
     let duration = start.elapsed();
     debug!("Checked files in: {:?}", duration);
 
-    Ok(messages)
+    let files_scanned = paths.len();
+    Ok((messages, files_scanned))
+}
+
+/// For `--check-fix-idempotence`: re-lint `files` after fixes have already
+/// been written, and fail if any rule that fixed something in `first_pass`
+/// is still reporting -- which would mean its fix doesn't converge.
+fn check_fix_idempotence(
+    files: &[PathBuf],
+    settings: &Settings,
+    cache: bool,
+    cache_dir: &Path,
+    unsafe_fixes: bool,
+    ignore_noqa: bool,
+    first_pass: &[Message],
+) -> Result<()> {
+    let fixed_codes: FnvHashSet<&CheckCode> = first_pass
+        .iter()
+        .filter(|message| message.fixed)
+        .map(|message| message.kind.code())
+        .collect();
+    if fixed_codes.is_empty() {
+        return Ok(());
+    }
+
+    let (second_pass, _) = run_once(
+        files, settings, cache, false, cache_dir, None, unsafe_fixes, false, ignore_noqa,
+    )?;
+
+    let mut non_converging_codes: FnvHashSet<&CheckCode> = FnvHashSet::default();
+    for message in &second_pass {
+        let code = message.kind.code();
+        if fixed_codes.contains(code) {
+            error!(
+                "{}:{}: {} still reported after fixing",
+                message.filename,
+                message.location.row(),
+                code.as_ref()
+            );
+            non_converging_codes.insert(code);
+        }
+    }
+
+    if non_converging_codes.is_empty() {
+        Ok(())
+    } else {
+        anyhow::bail!(
+            "Fix idempotence check failed: {} rule(s) did not converge: {}",
+            non_converging_codes.len(),
+            non_converging_codes
+                .iter()
+                .map(|code| code.as_ref())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
 }
 
 fn add_noqa(files: &[PathBuf], settings: &Settings) -> Result<usize> {
     // Collect all the files to check.
     let start = Instant::now();
-    let paths: Vec<Result<DirEntry, walkdir::Error>> = files
+    let paths: Vec<Result<DirEntry, ignore::Error>> = files
         .iter()
-        .flat_map(|path| iter_python_files(path, &settings.exclude, &settings.extend_exclude))
+        .flat_map(|path| {
+            iter_python_files(
+                path,
+                &settings.exclude,
+                &settings.extend_exclude,
+                settings.follow_symlinks,
+            )
+        })
         .collect();
     let duration = start.elapsed();
     debug!("Identified files to lint in: {:?}", duration);
@@ -198,7 +451,14 @@ fn autoformat(files: &[PathBuf], settings: &Settings) -> Result<usize> {
     let start = Instant::now();
     let paths: Vec<DirEntry> = files
         .iter()
-        .flat_map(|path| iter_python_files(path, &settings.exclude, &settings.extend_exclude))
+        .flat_map(|path| {
+            iter_python_files(
+                path,
+                &settings.exclude,
+                &settings.extend_exclude,
+                settings.follow_symlinks,
+            )
+        })
         .flatten()
         .collect();
     let duration = start.elapsed();
@@ -219,13 +479,56 @@ fn autoformat(files: &[PathBuf], settings: &Settings) -> Result<usize> {
     Ok(modifications)
 }
 
+/// Build the project's first-party import graph, warn about any cycles found
+/// in it, and render it in `format`.
+fn analyze_graph(
+    files: &[PathBuf],
+    settings: &Settings,
+    format: import_graph::GraphFormat,
+) -> Result<String> {
+    let graph = import_graph::build(files, settings)?;
+
+    for cycle in import_graph::describe_cycles(&graph) {
+        eprintln!("warning: import cycle detected: {cycle}");
+    }
+
+    match format {
+        import_graph::GraphFormat::Json => import_graph::to_json(&graph),
+        import_graph::GraphFormat::Dot => Ok(import_graph::to_dot(&graph)),
+    }
+}
+
+/// Whether `code` is covered by any prefix in `prefixes` (e.g. `W`, `W1`, or
+/// `W191` all cover `W191`). Used to tell whether the user's `select` or
+/// `extend-select` already names a code explicitly, so an `.editorconfig`-
+/// driven default never overrides a deliberate choice.
+fn selects_explicitly(prefixes: &[CheckCodePrefix], code: CheckCode) -> bool {
+    prefixes
+        .iter()
+        .any(|prefix| prefix.codes().contains(&code))
+}
+
 fn inner_main() -> Result<ExitCode> {
     // Extract command-line arguments.
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+    if cli.format == SerializationFormat::Template && cli.template.is_none() {
+        anyhow::bail!("--format template requires --template");
+    }
+    if cli.format != SerializationFormat::Template && cli.template.is_some() {
+        anyhow::bail!("--template is only used with --format template");
+    }
     let fix = cli.fix();
+    let is_stdin = cli.files == vec![PathBuf::from("-")];
+    if !is_stdin {
+        // Canonicalize and deduplicate the targets up front, so overlapping
+        // arguments (e.g. `ruff . src/`) don't get walked, linted, or
+        // fixed more than once.
+        cli.files = fs::normalize_and_dedupe_paths(cli.files);
+    }
 
     let log_level = extract_log_level(&cli);
     set_up_logging(&log_level)?;
+    cli.color.init();
 
     // Find the project root and pyproject.toml.
     let project_root = pyproject::find_project_root(&cli.files);
@@ -253,7 +556,28 @@ fn inner_main() -> Result<ExitCode> {
         .map(|path| FilePattern::from_user(path, &project_root))
         .collect();
 
-    let mut configuration = Configuration::from_pyproject(&pyproject, &project_root)?;
+    // Fall back to `.editorconfig`'s `max_line_length` when nothing else
+    // (pyproject.toml, below) sets `line-length` explicitly, so a monorepo
+    // with per-directory `.editorconfig` conventions doesn't need a
+    // redundant `pyproject.toml` in every directory just to get `E501`
+    // right.
+    let editorconfig = editorconfig::resolve(&cli.files);
+    let mut options = pyproject::load_options(&pyproject)?;
+    if options.line_length.is_none() {
+        if let Some(max_line_length) = editorconfig.max_line_length {
+            debug!("Falling back to .editorconfig line length: {max_line_length}");
+            options.line_length = Some(max_line_length);
+        }
+    }
+
+    let mut configuration = Configuration::from_options(options, &project_root)?;
+    if let Some(root) = &project_root {
+        for nested in pyproject::find_nested_pyprojects(root, &cli.files) {
+            debug!("Found nested pyproject.toml at: {:?}", nested);
+            let nested_options = pyproject::load_options(&Some(nested))?;
+            configuration = configuration.merge(nested_options, &project_root)?;
+        }
+    }
     if !exclude.is_empty() {
         configuration.exclude = exclude;
     }
@@ -292,15 +616,77 @@ fn inner_main() -> Result<ExitCode> {
     if !cli.extend_ignore.is_empty() {
         configuration.extend_ignore = cli.extend_ignore;
     }
+    if !cli.warnings.is_empty() {
+        configuration.warnings = cli.warnings;
+    }
     if let Some(target_version) = cli.target_version {
         configuration.target_version = target_version;
     }
+    if let Some(threads) = cli.threads {
+        configuration.threads = threads;
+    }
     if let Some(dummy_variable_rgx) = cli.dummy_variable_rgx {
         configuration.dummy_variable_rgx = dummy_variable_rgx;
     }
     if let Some(fix) = fix {
         configuration.fix = fix;
     }
+    if cli.preview {
+        configuration.preview = true;
+    }
+    if cli.follow_symlinks {
+        configuration.follow_symlinks = true;
+    }
+    if let Ok(cache_dir) = std::env::var("RUFF_CACHE_DIR") {
+        configuration.cache_dir = PathBuf::from(cache_dir);
+    }
+    if let Some(cache_dir) = cli.cache_dir {
+        configuration.cache_dir = cache_dir;
+    }
+
+    // An `.editorconfig` declaring `indent_style = tab` means tabs are this
+    // directory's convention, so don't let W191 (tab-indentation) flag every
+    // line, unless the user asked for it by name.
+    if editorconfig.indent_style == Some(IndentStyle::Tab)
+        && !selects_explicitly(&configuration.select, CheckCode::W191)
+        && !selects_explicitly(&configuration.extend_select, CheckCode::W191)
+    {
+        debug!("`.editorconfig` declares `indent_style = tab`; disabling W191");
+        configuration.extend_ignore.push(CheckCodePrefix::W191);
+    }
+
+    if let Some(path) = &cli.debug_tokens {
+        debug_tokens(path)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(path) = &cli.debug_ast {
+        debug_ast(path)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if cli.generate_schema {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&schemars::schema_for!(Options))?
+        );
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if cli.show_checks {
+        show_checks(&cli.format)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(flake8_config) = &cli.migrate_config {
+        let plugins = if cli.migrate_plugin.is_empty() {
+            None
+        } else {
+            Some(cli.migrate_plugin)
+        };
+        println!("{}", migrate_config(flake8_config, plugins)?);
+        return Ok(ExitCode::SUCCESS);
+    }
 
     if cli.show_settings && cli.show_files {
         eprintln!("Error: specify --show-settings or show-files (not both).");
@@ -313,17 +699,68 @@ fn inner_main() -> Result<ExitCode> {
 
     // Extract settings for internal use.
     let autofix = configuration.fix;
+    let cache_dir = configuration.cache_dir.clone();
+    let threads = configuration.threads;
     let settings = Settings::from_configuration(configuration);
 
+    // Bound how many files are linted in parallel. A value of `0` (the
+    // default) leaves Rayon's own default in place, which sizes the pool to
+    // the number of logical cores.
+    #[cfg(not(target_family = "wasm"))]
+    if threads != 0 {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()?;
+    }
+
+    if cli.clean {
+        if cache::clean(&cache_dir)? {
+            if log_level >= LogLevel::Default {
+                println!("Removed cache directory: {}", cache_dir.display());
+            }
+        } else if log_level >= LogLevel::Default {
+            println!("No cache directory found at: {}", cache_dir.display());
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if cli.daemon {
+        #[cfg(not(target_family = "wasm"))]
+        cache::init(&cache_dir)?;
+        let socket_path = cli.socket.unwrap_or_else(|| cache_dir.join("daemon.sock"));
+        if log_level >= LogLevel::Default {
+            eprintln!("Listening on {}...", socket_path.display());
+        }
+        daemon::listen(&socket_path, &settings, &cache_dir)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
     if cli.show_files {
         show_files(&cli.files, &settings);
         return Ok(ExitCode::SUCCESS);
     }
 
     #[cfg(not(target_family = "wasm"))]
-    cache::init()?;
+    cache::init(&cache_dir)?;
+
+    if cli.server {
+        #[cfg(not(target_family = "wasm"))]
+        {
+            server::run(&settings)?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        #[cfg(target_family = "wasm")]
+        anyhow::bail!("--server is not available on this target");
+    }
 
-    let printer = Printer::new(&cli.format, &log_level);
+    let printer = Printer::new(
+        &cli.format,
+        &log_level,
+        cli.show_source,
+        cli.column_encoding,
+        cli.output_file.as_deref(),
+        cli.template.as_deref(),
+    );
     if cli.watch {
         if autofix {
             eprintln!("Warning: --fix is not enabled in watch mode.");
@@ -333,8 +770,12 @@ fn inner_main() -> Result<ExitCode> {
             eprintln!("Warning: --no-qa is not enabled in watch mode.");
         }
 
-        if cli.autoformat {
-            eprintln!("Warning: --autoformat is not enabled in watch mode.");
+        if cli.reformat {
+            eprintln!("Warning: --reformat is not enabled in watch mode.");
+        }
+
+        if cli.analyze_graph {
+            eprintln!("Warning: --analyze-graph is not enabled in watch mode.");
         }
 
         if cli.format != SerializationFormat::Text {
@@ -345,7 +786,17 @@ fn inner_main() -> Result<ExitCode> {
         printer.clear_screen()?;
         printer.write_to_user("Starting linter in watch mode...\n");
 
-        let messages = run_once(&cli.files, &settings, !cli.no_cache, false)?;
+        let (messages, _) = run_once(
+            &cli.files,
+            &settings,
+            !cli.no_cache,
+            false,
+            &cache_dir,
+            cli.diff_against.as_deref(),
+            cli.unsafe_fixes,
+            false,
+            cli.ignore_noqa,
+        )?;
         printer.write_continuously(&messages)?;
 
         // Configure the file watcher.
@@ -363,7 +814,17 @@ fn inner_main() -> Result<ExitCode> {
                             printer.clear_screen()?;
                             printer.write_to_user("File change detected...\n");
 
-                            let messages = run_once(&cli.files, &settings, !cli.no_cache, false)?;
+                            let (messages, _) = run_once(
+                                &cli.files,
+                                &settings,
+                                !cli.no_cache,
+                                false,
+                                &cache_dir,
+                                cli.diff_against.as_deref(),
+                                cli.unsafe_fixes,
+                                false,
+                                cli.ignore_noqa,
+                            )?;
                             printer.write_continuously(&messages)?;
                         }
                     }
@@ -376,28 +837,88 @@ fn inner_main() -> Result<ExitCode> {
         if modifications > 0 && log_level >= LogLevel::Default {
             println!("Added {modifications} noqa directives.");
         }
-    } else if cli.autoformat {
+    } else if cli.reformat {
         let modifications = autoformat(&cli.files, &settings)?;
         if modifications > 0 && log_level >= LogLevel::Default {
             println!("Formatted {modifications} files.");
         }
+    } else if cli.analyze_graph {
+        let output = analyze_graph(&cli.files, &settings, cli.graph_format)?;
+        println!("{output}");
+    } else if let Some(baseline_path) = &cli.generate_baseline {
+        let (messages, _) = run_once(
+            &cli.files,
+            &settings,
+            !cli.no_cache,
+            false,
+            &cache_dir,
+            cli.diff_against.as_deref(),
+            cli.unsafe_fixes,
+            false,
+            cli.ignore_noqa,
+        )?;
+        baseline::write(baseline_path, &messages)?;
+        if log_level >= LogLevel::Default {
+            println!(
+                "Wrote {} violations to baseline at {}.",
+                messages.len(),
+                baseline_path.to_string_lossy()
+            );
+        }
     } else {
-        let is_stdin = cli.files == vec![PathBuf::from("-")];
-
         // Generate lint violations.
-        let messages = if is_stdin {
+        let start = Instant::now();
+        let (messages, files_scanned) = if is_stdin {
             let filename = cli.stdin_filename.unwrap_or_else(|| "-".to_string());
             let path = Path::new(&filename);
-            run_once_stdin(&settings, path, autofix)?
+            let messages = run_once_stdin(
+                &settings,
+                path,
+                autofix,
+                cli.unsafe_fixes,
+                cli.timings,
+                cli.ignore_noqa,
+            )?;
+            (messages, 1)
         } else {
-            run_once(&cli.files, &settings, !cli.no_cache, autofix)?
+            run_once(
+                &cli.files,
+                &settings,
+                !cli.no_cache,
+                autofix,
+                &cache_dir,
+                cli.diff_against.as_deref(),
+                cli.unsafe_fixes,
+                cli.timings,
+                cli.ignore_noqa,
+            )?
+        };
+        let duration = start.elapsed();
+        let messages = match &cli.baseline {
+            Some(baseline_path) => baseline::filter(messages, &baseline::read(baseline_path)?),
+            None => messages,
         };
+        if cli.timings {
+            show_timings();
+        }
+
+        if autofix && cli.check_fix_idempotence && !is_stdin {
+            check_fix_idempotence(
+                &cli.files,
+                &settings,
+                !cli.no_cache,
+                &cache_dir,
+                cli.unsafe_fixes,
+                cli.ignore_noqa,
+                &messages,
+            )?;
+        }
 
         // Always try to print violations (the printer itself may suppress output),
         // unless we're writing fixes via stdin (in which case, the transformed
         // source code goes to stdout).
         if !(is_stdin && autofix) {
-            printer.write_once(&messages)?;
+            printer.write_once(&messages, files_scanned, duration)?;
         }
 
         // Check for updates if we're in a non-silent log level.
@@ -406,7 +927,11 @@ fn inner_main() -> Result<ExitCode> {
             check_for_updates();
         }
 
-        if messages.iter().any(|message| !message.fixed) && !cli.exit_zero {
+        let violation_count = messages
+            .iter()
+            .filter(|message| !message.fixed && message.severity.at_least(cli.error_on))
+            .count();
+        if violation_count > cli.max_violations.unwrap_or(0) && !cli.exit_zero {
             return Ok(ExitCode::FAILURE);
         }
     }
@@ -1,13 +1,93 @@
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
 use std::path::Path;
 
+use clap::ValueEnum;
 use colored::Colorize;
 use rustpython_parser::ast::Location;
 use serde::{Deserialize, Serialize};
 
-use crate::checks::{Check, CheckKind};
-use crate::fs::relativize_path;
+use crate::autofix::Fix;
+use crate::checks::{Annotation, Check, CheckKind};
+use crate::fs::{read_file, relativize_path};
+use crate::settings::Settings;
+
+/// The unit `Message::location`'s column is reported in. Ruff's own location
+/// arithmetic is always in characters (see `source_code_locator.rs`), but a
+/// character isn't a `char`-wide column everywhere: LSP and most editors
+/// report positions as UTF-16 code units, while some external tools expect
+/// raw UTF-8 byte offsets. `--column-encoding` recodes the column Ruff
+/// already computed into whichever of those three units the consumer needs,
+/// rather than changing how columns are computed internally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ColumnEncoding {
+    /// One column per `char` (Ruff's native unit). Correct for terminals.
+    Char,
+    /// One column per UTF-8 byte, for tools that index into the raw file
+    /// bytes.
+    Utf8,
+    /// One column per UTF-16 code unit, as used by the Language Server
+    /// Protocol and most editors (a column after a character outside the
+    /// Basic Multilingual Plane, e.g. most emoji, is 2 higher than the
+    /// equivalent `char` column).
+    Utf16,
+}
+
+/// Recode a 1-based `char` column on `line` into `encoding`'s unit.
+fn recode_column(line: &str, column: usize, encoding: ColumnEncoding) -> usize {
+    let preceding_chars = column.saturating_sub(1);
+    match encoding {
+        ColumnEncoding::Char => column,
+        ColumnEncoding::Utf8 => {
+            line.chars()
+                .take(preceding_chars)
+                .map(char::len_utf8)
+                .sum::<usize>()
+                + 1
+        }
+        ColumnEncoding::Utf16 => {
+            line.chars()
+                .take(preceding_chars)
+                .map(char::len_utf16)
+                .sum::<usize>()
+                + 1
+        }
+    }
+}
+
+/// Escape the characters Azure Pipelines logging commands treat specially
+/// (`%`, `;`, `\r`, `\n`, `]`) in a property value or message, per
+/// https://learn.microsoft.com/azure/devops/pipelines/scripts/logging-commands.
+/// `%` is escaped first so the other replacements' own `%`-escapes aren't
+/// re-escaped.
+fn escape_azure(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace(';', "%3B")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+        .replace(']', "%5D")
+}
+
+/// Whether a violation should be treated as a hard failure or merely
+/// surfaced. Configured per rule via `Settings::warnings`; defaults to
+/// `Error` for any rule not listed there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    /// Return `true` if `self` is at least as severe as `threshold` (an
+    /// `Error` is more severe than a `Warning`).
+    pub fn at_least(self, threshold: Severity) -> bool {
+        match threshold {
+            Severity::Warning => true,
+            Severity::Error => self == Severity::Error,
+        }
+    }
+}
 
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Message {
@@ -16,16 +96,41 @@ pub struct Message {
     pub location: Location,
     pub end_location: Location,
     pub filename: String,
+    pub fix: Option<Fix>,
+    pub alternatives: Vec<Fix>,
+    pub severity: Severity,
+    pub related: Vec<Annotation>,
 }
 
 impl Message {
-    pub fn from_check(filename: String, check: Check) -> Self {
+    pub fn from_check(filename: String, check: Check, settings: &Settings) -> Self {
+        let severity = if settings.warnings.contains(check.kind.code()) {
+            Severity::Warning
+        } else {
+            Severity::Error
+        };
+        let related = check
+            .related
+            .into_iter()
+            .map(|annotation| Annotation {
+                message: annotation.message,
+                location: Location::new(annotation.location.row(), annotation.location.column() + 1),
+                end_location: Location::new(
+                    annotation.end_location.row(),
+                    annotation.end_location.column() + 1,
+                ),
+            })
+            .collect();
         Self {
             kind: check.kind,
-            fixed: check.fix.map(|fix| fix.applied).unwrap_or_default(),
+            fixed: check.fix.as_ref().map(|fix| fix.applied).unwrap_or_default(),
             location: Location::new(check.location.row(), check.location.column() + 1),
             end_location: Location::new(check.end_location.row(), check.end_location.column() + 1),
             filename,
+            fix: check.fix,
+            alternatives: check.alternatives,
+            severity,
+            related,
         }
     }
 }
@@ -46,19 +151,279 @@ impl PartialOrd for Message {
     }
 }
 
-impl fmt::Display for Message {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
+/// Byte offset of the start of each line in `source`, so that looking up a
+/// given line doesn't require rescanning every line before it.
+pub fn line_starts(source: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+        .collect()
+}
+
+/// The text of the (1-based) `row`th line of `source`, given its
+/// `line_starts` index, with the trailing newline stripped.
+fn line_text<'a>(source: &'a str, line_starts: &[usize], row: usize) -> Option<&'a str> {
+    let index = row.checked_sub(1)?;
+    let start = *line_starts.get(index)?;
+    let end = line_starts.get(index + 1).map_or(source.len(), |&next| next);
+    source.get(start..end).map(|line| line.trim_end_matches(['\n', '\r']))
+}
+
+fn recode_location(
+    location: Location,
+    source: &str,
+    line_starts: &[usize],
+    encoding: ColumnEncoding,
+) -> Location {
+    match line_text(source, line_starts, location.row()) {
+        Some(line) => Location::new(location.row(), recode_column(line, location.column(), encoding)),
+        None => location,
+    }
+}
+
+impl Message {
+    /// Render the offending line(s) from `source` (the full contents of
+    /// `self.filename`) with a `^^^^` span underneath, for `--show-source`.
+    /// `line_starts` is `source`'s line-start index, from `line_starts`
+    /// above. Returns `None` if `self.location`'s line isn't present in
+    /// `source` (e.g. the file has changed since the check ran).
+    pub fn show_source(&self, source: &str, line_starts: &[usize]) -> Option<String> {
+        let index = self.location.row().checked_sub(1)?;
+        let start = *line_starts.get(index)?;
+        let end = line_starts.get(index + 1).map_or(source.len(), |&next| next);
+        let line = source.get(start..end)?.trim_end_matches(['\n', '\r']);
+
+        let start_col = self.location.column().saturating_sub(1);
+        let end_col = if self.end_location.row() == self.location.row() {
+            self.end_location
+                .column()
+                .saturating_sub(1)
+                .max(start_col + 1)
+        } else {
+            line.chars().count()
+        };
+
+        // Preserve tabs in the leading whitespace so the carets stay aligned
+        // under the offending span in the terminal.
+        let indent: String = line
+            .chars()
+            .take(start_col)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(end_col.saturating_sub(start_col).max(1));
+
+        Some(format!("{line}\n{indent}{carets}"))
+    }
+}
+
+impl Message {
+    fn render(&self, row: usize, column: usize) -> String {
+        let code = self.kind.code().as_ref().bold();
+        let code = match self.severity {
+            Severity::Error => code.red(),
+            Severity::Warning => code.yellow(),
+        };
+        format!(
             "{}{}{}{}{}{} {} {}",
             relativize_path(Path::new(&self.filename)).white().bold(),
             ":".cyan(),
-            self.location.row(),
+            row,
             ":".cyan(),
-            self.location.column(),
+            column,
             ":".cyan(),
-            self.kind.code().as_ref().red().bold(),
+            code,
             self.kind.body()
         )
     }
+
+    /// Render a `related` annotation as an indented note beneath the main
+    /// diagnostic line, e.g. `    --> file.py:5:1 first definition here`.
+    fn render_related(&self, note: &str, row: usize, column: usize) -> String {
+        format!(
+            "    {} {}{}{}{}{} {}",
+            "-->".cyan(),
+            relativize_path(Path::new(&self.filename)).white(),
+            ":".cyan(),
+            row,
+            ":".cyan(),
+            column,
+            note
+        )
+    }
+
+    /// Render `self` the same way `Display` does, but with the reported
+    /// column recoded into `encoding` using the text of `self.filename`'s
+    /// offending line (read from `contents`/`line_starts`, the same pair
+    /// `Printer` already keeps cached per file for `--show-source`).
+    pub fn display_with_encoding(
+        &self,
+        encoding: ColumnEncoding,
+        contents: &str,
+        line_starts: &[usize],
+    ) -> String {
+        let column = match line_text(contents, line_starts, self.location.row()) {
+            Some(line) => recode_column(line, self.location.column(), encoding),
+            None => self.location.column(),
+        };
+        self.render(self.location.row(), column)
+    }
+
+    /// Render `self` as an Azure Pipelines `##vso[task.logissue ...]`
+    /// logging command, for `--format azure`, so violations annotate the
+    /// build natively instead of only appearing in the raw log.
+    pub fn render_azure(&self) -> String {
+        format!(
+            "##vso[task.logissue type={};sourcepath={};linenumber={};columnnumber={};code={}]{}",
+            match self.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            },
+            escape_azure(&relativize_path(Path::new(&self.filename))),
+            self.location.row(),
+            self.location.column(),
+            escape_azure(self.kind.code().as_ref()),
+            escape_azure(&self.kind.body()),
+        )
+    }
+
+    /// Render `self` by substituting `{path}`, `{row}`, `{column}`,
+    /// `{endrow}`, `{endcolumn}`, `{code}`, `{severity}`, and `{message}`
+    /// into a user-supplied `--template` string, for `--format template`.
+    /// Placeholders the template doesn't use are simply never replaced;
+    /// there's no schema to validate the template against up front.
+    pub fn render_template(&self, template: &str) -> String {
+        template
+            .replace("{path}", &relativize_path(Path::new(&self.filename)))
+            .replace("{row}", &self.location.row().to_string())
+            .replace("{column}", &self.location.column().to_string())
+            .replace("{endrow}", &self.end_location.row().to_string())
+            .replace("{endcolumn}", &self.end_location.column().to_string())
+            .replace("{code}", self.kind.code().as_ref())
+            .replace(
+                "{severity}",
+                match self.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                },
+            )
+            .replace("{message}", &self.kind.body())
+    }
+
+    /// A one-line note that other candidate fixes exist beyond the one
+    /// `--fix` would apply, e.g. `    (1 alternative fix available; see
+    /// --format json)`. `None` if `self.alternatives` is empty. Since a
+    /// terminal can't offer the user a choice the way an editor or LSP
+    /// client can, this just points toward the format that can.
+    pub fn alternatives_line(&self) -> Option<String> {
+        if self.alternatives.is_empty() {
+            return None;
+        }
+        let count = self.alternatives.len();
+        let plural = if count == 1 { "" } else { "es" };
+        Some(format!(
+            "    ({count} alternative fix{plural} available; see --format json)"
+        ))
+    }
+
+    /// One rendered line per `self.related` annotation, using the `char`
+    /// columns already stored on `self` -- for when no source file is
+    /// available (or needed) to recode them.
+    pub fn related_lines(&self) -> Vec<String> {
+        self.related
+            .iter()
+            .map(|annotation| {
+                self.render_related(
+                    &annotation.message,
+                    annotation.location.row(),
+                    annotation.location.column(),
+                )
+            })
+            .collect()
+    }
+
+    /// One rendered line per `self.related` annotation, for `--show-source`
+    /// text output. Columns are recoded the same way `display_with_encoding`
+    /// recodes the main diagnostic's column.
+    pub fn related_lines_with_encoding(
+        &self,
+        encoding: ColumnEncoding,
+        contents: &str,
+        line_starts: &[usize],
+    ) -> Vec<String> {
+        self.related
+            .iter()
+            .map(|annotation| {
+                let column = match line_text(contents, line_starts, annotation.location.row()) {
+                    Some(line) => recode_column(line, annotation.location.column(), encoding),
+                    None => annotation.location.column(),
+                };
+                self.render_related(&annotation.message, annotation.location.row(), column)
+            })
+            .collect()
+    }
+
+    /// `(location, end_location)` recoded into `encoding`, reading and
+    /// caching each message's file contents in `source_cache` as needed
+    /// (messages aren't necessarily grouped by file, unlike the text
+    /// printer's single-file-at-a-time traversal).
+    pub fn encoded_locations(
+        &self,
+        encoding: ColumnEncoding,
+        source_cache: &mut HashMap<String, Option<(String, Vec<usize>)>>,
+    ) -> (Location, Location) {
+        self.recode_pair(self.location, self.end_location, encoding, source_cache)
+    }
+
+    /// `(message, location, end_location)` for each of `self.related`,
+    /// recoded into `encoding` the same way `encoded_locations` does.
+    pub fn encoded_related(
+        &self,
+        encoding: ColumnEncoding,
+        source_cache: &mut HashMap<String, Option<(String, Vec<usize>)>>,
+    ) -> Vec<(String, Location, Location)> {
+        self.related
+            .iter()
+            .map(|annotation| {
+                let (location, end_location) = self.recode_pair(
+                    annotation.location,
+                    annotation.end_location,
+                    encoding,
+                    source_cache,
+                );
+                (annotation.message.clone(), location, end_location)
+            })
+            .collect()
+    }
+
+    fn recode_pair(
+        &self,
+        location: Location,
+        end_location: Location,
+        encoding: ColumnEncoding,
+        source_cache: &mut HashMap<String, Option<(String, Vec<usize>)>>,
+    ) -> (Location, Location) {
+        if encoding == ColumnEncoding::Char {
+            return (location, end_location);
+        }
+        let cached = source_cache.entry(self.filename.clone()).or_insert_with(|| {
+            read_file(Path::new(&self.filename))
+                .ok()
+                .map(|contents| {
+                    let starts = line_starts(&contents);
+                    (contents, starts)
+                })
+        });
+        let Some((contents, starts)) = cached else {
+            return (location, end_location);
+        };
+        (
+            recode_location(location, contents, starts, encoding),
+            recode_location(end_location, contents, starts, encoding),
+        )
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(self.location.row(), self.location.column()))
+    }
 }
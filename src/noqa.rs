@@ -3,6 +3,7 @@ use std::fs;
 use std::path::Path;
 
 use anyhow::Result;
+use log::warn;
 use nohash_hasher::IntMap;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -47,9 +48,10 @@ pub fn add_noqa(
     checks: &[Check],
     contents: &str,
     noqa_line_for: &IntMap<usize, usize>,
+    line_length: usize,
     path: &Path,
 ) -> Result<usize> {
-    let (count, output) = add_noqa_inner(checks, contents, noqa_line_for)?;
+    let (count, output) = add_noqa_inner(checks, contents, noqa_line_for, line_length)?;
     fs::write(path, output)?;
     Ok(count)
 }
@@ -58,6 +60,7 @@ fn add_noqa_inner(
     checks: &[Check],
     contents: &str,
     noqa_line_for: &IntMap<usize, usize>,
+    line_length: usize,
 ) -> Result<(usize, String)> {
     let lines: Vec<&str> = contents.lines().collect();
     let mut matches_by_line: BTreeMap<usize, BTreeSet<&CheckCode>> = BTreeMap::new();
@@ -102,6 +105,16 @@ fn add_noqa_inner(
                 let codes: Vec<&str> = codes.iter().map(|code| code.as_ref()).collect();
                 output.push_str("  # noqa: ");
                 output.push_str(&codes.join(", "));
+                if output[output.rfind('\n').map_or(0, |i| i + 1)..]
+                    .chars()
+                    .count()
+                    > line_length
+                {
+                    warn!(
+                        "Suppression comment on line {} exceeds the configured line length",
+                        lineno + 1
+                    );
+                }
                 output.push('\n');
                 count += 1;
             }
@@ -125,7 +138,7 @@ mod tests {
         let checks = vec![];
         let contents = "x = 1";
         let noqa_line_for = Default::default();
-        let (count, output) = add_noqa_inner(&checks, contents, &noqa_line_for)?;
+        let (count, output) = add_noqa_inner(&checks, contents, &noqa_line_for, 88)?;
         assert_eq!(count, 0);
         assert_eq!(output.trim(), contents.trim());
 
@@ -138,7 +151,7 @@ mod tests {
         )];
         let contents = "x = 1";
         let noqa_line_for = Default::default();
-        let (count, output) = add_noqa_inner(&checks, contents, &noqa_line_for)?;
+        let (count, output) = add_noqa_inner(&checks, contents, &noqa_line_for, 88)?;
         assert_eq!(count, 1);
         assert_eq!(output.trim(), "x = 1  # noqa: F841".trim());
 
@@ -160,7 +173,7 @@ mod tests {
         ];
         let contents = "x = 1  # noqa: E741";
         let noqa_line_for = Default::default();
-        let (count, output) = add_noqa_inner(&checks, contents, &noqa_line_for)?;
+        let (count, output) = add_noqa_inner(&checks, contents, &noqa_line_for, 88)?;
         assert_eq!(count, 1);
         assert_eq!(output.trim(), "x = 1  # noqa: E741, F841".trim());
 
@@ -182,7 +195,7 @@ mod tests {
         ];
         let contents = "x = 1  # noqa";
         let noqa_line_for = Default::default();
-        let (count, output) = add_noqa_inner(&checks, contents, &noqa_line_for)?;
+        let (count, output) = add_noqa_inner(&checks, contents, &noqa_line_for, 88)?;
         assert_eq!(count, 1);
         assert_eq!(output.trim(), "x = 1  # noqa: E741, F841".trim());
 
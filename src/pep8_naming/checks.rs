@@ -42,8 +42,13 @@ pub fn invalid_function_name(func_def: &Stmt, name: &str, settings: &Settings) -
 }
 
 /// N803
-pub fn invalid_argument_name(name: &str, location: Range) -> Option<Check> {
-    if name.to_lowercase() != name {
+pub fn invalid_argument_name(name: &str, location: Range, settings: &Settings) -> Option<Check> {
+    if name.to_lowercase() != name
+        && !settings
+            .ignore_names
+            .iter()
+            .any(|ignore_name| ignore_name == name)
+    {
         return Some(Check::new(
             CheckKind::InvalidArgumentName(name.to_string()),
             location,
@@ -101,11 +106,21 @@ pub fn invalid_first_argument_name_for_method(
 }
 
 /// N806
-pub fn non_lowercase_variable_in_function(scope: &Scope, expr: &Expr, name: &str) -> Option<Check> {
+pub fn non_lowercase_variable_in_function(
+    scope: &Scope,
+    expr: &Expr,
+    name: &str,
+    settings: &Settings,
+) -> Option<Check> {
     if !matches!(scope.kind, ScopeKind::Function(FunctionScope { .. })) {
         return None;
     }
-    if name.to_lowercase() != name {
+    if name.to_lowercase() != name
+        && !settings
+            .ignore_names
+            .iter()
+            .any(|ignore_name| ignore_name == name)
+    {
         return Some(Check::new(
             CheckKind::NonLowercaseVariableInFunction(name.to_string()),
             Range::from_located(expr),
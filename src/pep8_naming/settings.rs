@@ -1,5 +1,6 @@
 //! Settings for the `pep8-naming` plugin.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 const IGNORE_NAMES: [&str; 12] = [
@@ -21,11 +22,17 @@ const CLASSMETHOD_DECORATORS: [&str; 1] = ["classmethod"];
 
 const STATICMETHOD_DECORATORS: [&str; 1] = ["staticmethod"];
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 pub struct Options {
+    /// A list of names to ignore when considering `pep8-naming` violations,
+    /// in addition to the defaults.
     pub ignore_names: Option<Vec<String>>,
+    /// A list of decorators that, when applied to a method, indicate that the
+    /// method should be treated as a class method for naming purposes.
     pub classmethod_decorators: Option<Vec<String>>,
+    /// A list of decorators that, when applied to a method, indicate that the
+    /// method should be treated as a static method for naming purposes.
     pub staticmethod_decorators: Option<Vec<String>>,
 }
 
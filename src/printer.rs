@@ -1,3 +1,8 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
 use anyhow::Result;
 use clap::ValueEnum;
 use colored::Colorize;
@@ -6,13 +11,59 @@ use serde::Serialize;
 
 use crate::checks::{CheckCode, CheckKind};
 use crate::logging::LogLevel;
-use crate::message::Message;
+use crate::message::{self, ColumnEncoding, Message, Severity};
 use crate::tell_user;
 
 #[derive(Clone, Copy, ValueEnum, PartialEq, Eq, Debug)]
 pub enum SerializationFormat {
     Text,
     Json,
+    /// Render each violation through a user-supplied `--template` string,
+    /// for matching whatever format an editor's error-parser expects
+    /// without Ruff needing to ship a format per tool.
+    Template,
+    /// Emit Azure Pipelines `##vso[task.logissue ...]` logging commands, so
+    /// violations annotate the build natively instead of only appearing in
+    /// the raw log.
+    Azure,
+}
+
+/// When to colorize terminal output.
+#[derive(Clone, Copy, ValueEnum, PartialEq, Eq, Debug)]
+pub enum ColorChoice {
+    /// Colorize if written to an interactive terminal, and `NO_COLOR` isn't
+    /// set.
+    Auto,
+    /// Always colorize, even when piped (e.g. to `less -R`) or redirected.
+    Always,
+    /// Never colorize, regardless of the destination or environment.
+    Never,
+}
+
+impl ColorChoice {
+    /// Apply this choice to the global `colored` state. Must be called
+    /// before any colorized output is written.
+    pub fn init(self) {
+        match self {
+            ColorChoice::Auto => {}
+            ColorChoice::Always => colored::control::set_override(true),
+            ColorChoice::Never => colored::control::set_override(false),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ExpandedEdit<'a> {
+    content: &'a str,
+    location: Location,
+    end_location: Location,
+}
+
+#[derive(Serialize)]
+struct ExpandedAnnotation {
+    message: String,
+    location: Location,
+    end_location: Location,
 }
 
 #[derive(Serialize)]
@@ -24,16 +75,63 @@ struct ExpandedMessage<'a> {
     location: Location,
     end_location: Location,
     filename: &'a String,
+    severity: Severity,
+    /// The edits that make up this diagnostic's fix, if it has one, so that
+    /// editors and CI bots can apply it without re-running Ruff with `--fix`.
+    edits: Vec<ExpandedEdit<'a>>,
+    /// Other fixes that would also resolve this diagnostic, each as its own
+    /// single-edit fix. `--fix` only ever applies `edits`; these are for
+    /// interactive consumers (editors, LSP clients) to offer as a choice.
+    alternatives: Vec<Vec<ExpandedEdit<'a>>>,
+    /// Other locations (in the same file) related to this diagnostic, each
+    /// with a short label explaining the relation -- e.g. the prior
+    /// definition a redefinition check flags.
+    related: Vec<ExpandedAnnotation>,
+}
+
+/// End-of-run totals, for the `--format text` summary line and the
+/// `--format json` `summary` object alike.
+#[derive(Serialize)]
+struct ExpandedSummary {
+    files_scanned: usize,
+    duration_ms: u128,
+    violations: usize,
+    fixed: usize,
+    fixable: usize,
+}
+
+#[derive(Serialize)]
+struct ExpandedReport<'a> {
+    summary: ExpandedSummary,
+    messages: Vec<ExpandedMessage<'a>>,
 }
 
 pub struct Printer<'a> {
     format: &'a SerializationFormat,
     log_level: &'a LogLevel,
+    show_source: bool,
+    column_encoding: ColumnEncoding,
+    output_file: Option<&'a Path>,
+    template: Option<&'a str>,
 }
 
 impl<'a> Printer<'a> {
-    pub fn new(format: &'a SerializationFormat, log_level: &'a LogLevel) -> Self {
-        Self { format, log_level }
+    pub fn new(
+        format: &'a SerializationFormat,
+        log_level: &'a LogLevel,
+        show_source: bool,
+        column_encoding: ColumnEncoding,
+        output_file: Option<&'a Path>,
+        template: Option<&'a str>,
+    ) -> Self {
+        Self {
+            format,
+            log_level,
+            show_source,
+            column_encoding,
+            output_file,
+            template,
+        }
     }
 
     pub fn write_to_user(&self, message: &str) {
@@ -42,7 +140,12 @@ impl<'a> Printer<'a> {
         }
     }
 
-    pub fn write_once(&self, messages: &[Message]) -> Result<()> {
+    pub fn write_once(
+        &self,
+        messages: &[Message],
+        files_scanned: usize,
+        duration: Duration,
+    ) -> Result<()> {
         if matches!(self.log_level, LogLevel::Silent) {
             return Ok(());
         }
@@ -53,53 +156,128 @@ impl<'a> Printer<'a> {
             .iter()
             .filter(|message| message.kind.fixable())
             .count();
+        let summary = ExpandedSummary {
+            files_scanned,
+            duration_ms: duration.as_millis(),
+            violations: outstanding.len(),
+            fixed: fixed.len(),
+            fixable: num_fixable,
+        };
 
-        match self.format {
+        let report = match self.format {
             SerializationFormat::Json => {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(
-                        &messages
-                            .iter()
-                            .map(|message| ExpandedMessage {
+                let mut source_cache: HashMap<String, Option<(String, Vec<usize>)>> =
+                    HashMap::new();
+                serde_json::to_string_pretty(&ExpandedReport {
+                    summary,
+                    messages: messages
+                        .iter()
+                        .map(|message| {
+                            let (location, end_location) = message
+                                .encoded_locations(self.column_encoding, &mut source_cache);
+                            let related = message
+                                .encoded_related(self.column_encoding, &mut source_cache)
+                                .into_iter()
+                                .map(|(message, location, end_location)| ExpandedAnnotation {
+                                    message,
+                                    location,
+                                    end_location,
+                                })
+                                .collect();
+                            ExpandedMessage {
                                 kind: &message.kind,
                                 code: message.kind.code(),
                                 message: message.kind.body(),
                                 fixed: message.fixed,
-                                location: message.location,
-                                end_location: message.end_location,
+                                location,
+                                end_location,
                                 filename: &message.filename,
-                            })
-                            .collect::<Vec<_>>()
-                    )?
-                )
+                                severity: message.severity,
+                                edits: message
+                                    .fix
+                                    .iter()
+                                    .map(|fix| ExpandedEdit {
+                                        content: &fix.patch.content,
+                                        location: fix.patch.location,
+                                        end_location: fix.patch.end_location,
+                                    })
+                                    .collect(),
+                                alternatives: message
+                                    .alternatives
+                                    .iter()
+                                    .map(|fix| {
+                                        vec![ExpandedEdit {
+                                            content: &fix.patch.content,
+                                            location: fix.patch.location,
+                                            end_location: fix.patch.end_location,
+                                        }]
+                                    })
+                                    .collect(),
+                                related,
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                })?
             }
             SerializationFormat::Text => {
+                let mut lines: Vec<String> = Vec::new();
                 if self.log_level >= &LogLevel::Default {
                     if !fixed.is_empty() {
-                        println!(
+                        lines.push(format!(
                             "Found {} error(s) ({} fixed).",
                             outstanding.len(),
                             fixed.len()
-                        )
+                        ))
                     } else if !outstanding.is_empty() {
-                        println!("Found {} error(s).", outstanding.len())
+                        lines.push(format!("Found {} error(s).", outstanding.len()))
                     }
                 }
 
+                let mut current_file: Option<(String, String, Vec<usize>)> = None;
                 for message in outstanding {
-                    println!("{}", message)
+                    lines.extend(self.render_with_source(message, &mut current_file));
                 }
 
                 if self.log_level >= &LogLevel::Default {
                     if num_fixable > 0 {
-                        println!("{num_fixable} potentially fixable with the --fix option.")
+                        lines.push(format!(
+                            "{num_fixable} potentially fixable with the --fix option."
+                        ))
                     }
+
+                    lines.push(format!(
+                        "Checked {} file(s) in {:?}: {} violation(s) ({} fixable).",
+                        summary.files_scanned,
+                        duration,
+                        summary.violations,
+                        summary.fixable
+                    ));
                 }
+                lines.join("\n")
             }
-        }
+            SerializationFormat::Template => {
+                // A user-supplied template replaces Ruff's own formatting
+                // entirely, so -- unlike `Text` -- no banner or summary line
+                // is mixed in; the output is exactly one rendered line per
+                // outstanding violation, matching whatever the consuming
+                // tool's error-parser expects.
+                let template = self
+                    .template
+                    .expect("--format template requires --template");
+                outstanding
+                    .iter()
+                    .map(|message| message.render_template(template))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            SerializationFormat::Azure => outstanding
+                .iter()
+                .map(|message| message.render_azure())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        };
 
-        Ok(())
+        self.emit(&report)
     }
 
     pub fn write_continuously(&self, messages: &[Message]) -> Result<()> {
@@ -118,14 +296,86 @@ impl<'a> Printer<'a> {
             if self.log_level >= &LogLevel::Default {
                 println!();
             }
+            let mut current_file: Option<(String, String, Vec<usize>)> = None;
             for message in messages {
-                println!("{}", message)
+                for line in self.render_with_source(message, &mut current_file) {
+                    println!("{line}");
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Print `report` to stdout, or to `--output-file` if one was given, so
+    /// that a CI system (or anything else ingesting a format like `--format
+    /// json` from a path) isn't stuck scraping the report back out of
+    /// mixed-in progress and error output.
+    fn emit(&self, report: &str) -> Result<()> {
+        match self.output_file {
+            Some(path) => fs::write(path, format!("{report}\n"))?,
+            None => println!("{report}"),
+        }
+        Ok(())
+    }
+
+    /// Render a single message and, if `--show-source` is enabled, the
+    /// offending line(s) with a caret span beneath, as a list of lines.
+    /// `current_file` caches the last file read and its line-start index,
+    /// since messages are sorted by filename, so lines are looked up in
+    /// constant time instead of rescanning the file from the start for every
+    /// message.
+    fn render_with_source(
+        &self,
+        message: &Message,
+        current_file: &mut Option<(String, String, Vec<usize>)>,
+    ) -> Vec<String> {
+        let needs_source = self.show_source || self.column_encoding != ColumnEncoding::Char;
+        if needs_source
+            && current_file
+                .as_ref()
+                .map(|(filename, ..)| filename.as_str())
+                != Some(message.filename.as_str())
+        {
+            *current_file = crate::fs::read_file(Path::new(&message.filename)).ok().map(|contents| {
+                let line_starts = message::line_starts(&contents);
+                (message.filename.clone(), contents, line_starts)
+            });
+        }
+
+        let mut lines = Vec::new();
+        match current_file {
+            Some((_, contents, line_starts)) if self.column_encoding != ColumnEncoding::Char => {
+                lines.push(message.display_with_encoding(
+                    self.column_encoding,
+                    contents,
+                    line_starts,
+                ));
+                lines.extend(message.related_lines_with_encoding(
+                    self.column_encoding,
+                    contents,
+                    line_starts,
+                ));
+            }
+            _ => {
+                lines.push(message.to_string());
+                lines.extend(message.related_lines());
+            }
+        }
+        if let Some(line) = message.alternatives_line() {
+            lines.push(line);
+        }
+        if !self.show_source {
+            return lines;
+        }
+        if let Some((_, contents, line_starts)) = current_file {
+            if let Some(snippet) = message.show_source(contents, line_starts) {
+                lines.push(snippet);
+            }
+        }
+        lines
+    }
+
     pub fn clear_screen(&self) -> Result<()> {
         #[cfg(not(target_family = "wasm"))]
         clearscreen::clear()?;
@@ -1,11 +1,215 @@
 use itertools::izip;
+use log::error;
 use rustpython_ast::Location;
 use rustpython_parser::ast::{Cmpop, Constant, Expr, ExprKind, Unaryop};
+use rustpython_parser::lexer::Tok;
 
+use super::fixes;
 use crate::ast::types::Range;
+use crate::autofix::Fix;
 use crate::checks::{Check, CheckKind, RejectedCmpop};
 use crate::source_code_locator::SourceCodeLocator;
 
+/// E201, E202
+pub fn extraneous_whitespace(
+    tok: &Tok,
+    prev_tok: &Tok,
+    prev_end: Location,
+    start: Location,
+    autofix: bool,
+) -> Option<Check> {
+    if prev_end.row() != start.row() || prev_end.column() == start.column() {
+        return None;
+    }
+
+    let kind = if matches!(prev_tok, Tok::Lpar | Tok::Lsqb | Tok::Lbrace) {
+        CheckKind::WhitespaceAfterOpenBracket(bracket_char(prev_tok))
+    } else if matches!(tok, Tok::Rpar | Tok::Rsqb | Tok::Rbrace) {
+        CheckKind::WhitespaceBeforeCloseBracket(bracket_char(tok))
+    } else {
+        return None;
+    };
+
+    let mut check = Check::new(
+        kind,
+        Range {
+            location: prev_end,
+            end_location: start,
+        },
+    );
+    if autofix {
+        check.amend(Fix::deletion(prev_end, start));
+    }
+    Some(check)
+}
+
+fn bracket_char(tok: &Tok) -> char {
+    match tok {
+        Tok::Lpar => '(',
+        Tok::Rpar => ')',
+        Tok::Lsqb => '[',
+        Tok::Rsqb => ']',
+        Tok::Lbrace => '{',
+        Tok::Rbrace => '}',
+        _ => unreachable!("Expected a bracket token"),
+    }
+}
+
+/// E211
+pub fn whitespace_before_parameters(
+    tok: &Tok,
+    prev_tok: &Tok,
+    prev_prev_tok: Option<&Tok>,
+    prev_end: Location,
+    start: Location,
+) -> Option<Check> {
+    if !matches!(tok, Tok::Lpar | Tok::Lsqb) {
+        return None;
+    }
+    if prev_end == start {
+        return None;
+    }
+    let is_name_or_closing_bracket = matches!(
+        prev_tok,
+        Tok::Name { .. } | Tok::Rpar | Tok::Rsqb | Tok::Rbrace
+    );
+    if !is_name_or_closing_bracket {
+        return None;
+    }
+    // Allow `class A (B):`.
+    if matches!(prev_prev_tok, Some(Tok::Class)) {
+        return None;
+    }
+    Some(Check::new(
+        CheckKind::WhitespaceBeforeParameters(bracket_char(tok)),
+        Range {
+            location: prev_end,
+            end_location: start,
+        },
+    ))
+}
+
+/// Return `true` if the comment text represents a "bad" comment prefix (i.e.
+/// something other than a single leading `#` followed by a space), along
+/// with the offending character (mirroring pycodestyle's `bad_prefix`).
+fn bad_comment_prefix(symbol: &str) -> Option<char> {
+    if symbol.is_empty() || symbol == "#" || symbol == ":" || symbol == "#:" {
+        None
+    } else {
+        let stripped = symbol.trim_start_matches('#');
+        Some(stripped.chars().next().unwrap_or('#'))
+    }
+}
+
+/// E261, E262, E265
+pub fn whitespace_before_comment(
+    text: &str,
+    start: Location,
+    end: Location,
+    prev_end: Location,
+    autofix: bool,
+) -> Vec<Check> {
+    let mut checks = vec![];
+
+    let inline = prev_end.row() == start.row();
+
+    if inline && start.column() < prev_end.column() + 2 {
+        let mut check = Check::new(
+            CheckKind::TooFewSpacesBeforeInlineComment,
+            Range {
+                location: prev_end,
+                end_location: start,
+            },
+        );
+        if autofix {
+            check.amend(Fix::replacement("  ".to_string(), prev_end, start));
+        }
+        checks.push(check);
+    }
+
+    let (symbol, comment) = match text.split_once(' ') {
+        Some((symbol, comment)) => (symbol, comment),
+        None => (text, ""),
+    };
+    let bad_prefix = bad_comment_prefix(symbol);
+
+    if inline {
+        if bad_prefix.is_some() || comment.starts_with(' ') || comment.starts_with('\t') {
+            let mut check = Check::new(
+                CheckKind::InlineCommentShouldStartWithSpace,
+                Range {
+                    location: start,
+                    end_location: end,
+                },
+            );
+            if autofix {
+                check.amend(Fix::replacement(
+                    format!("# {}", text.trim_start_matches('#').trim_start()),
+                    start,
+                    end,
+                ));
+            }
+            checks.push(check);
+        }
+    } else if let Some(bad_prefix) = bad_prefix {
+        if bad_prefix != '#' && (bad_prefix != '!' || start.row() > 1) {
+            let mut check = Check::new(
+                CheckKind::BlockCommentShouldStartWithSpace,
+                Range {
+                    location: start,
+                    end_location: end,
+                },
+            );
+            if autofix {
+                check.amend(Fix::replacement(
+                    format!("# {}", text.trim_start_matches('#').trim_start()),
+                    start,
+                    end,
+                ));
+            }
+            checks.push(check);
+        }
+    }
+
+    checks
+}
+
+/// W505
+pub fn doc_line_too_long(
+    locator: &SourceCodeLocator,
+    start: &Location,
+    end: &Location,
+    max_doc_length: usize,
+) -> Vec<Check> {
+    let mut checks = vec![];
+
+    let text = locator.slice_source_code_range(&Range {
+        location: *start,
+        end_location: *end,
+    });
+
+    for (row_offset, line) in text.lines().enumerate() {
+        let length = if row_offset == 0 {
+            start.column() + line.chars().count()
+        } else {
+            line.chars().count()
+        };
+        if length > max_doc_length {
+            let location = Location::new(start.row() + row_offset, 0);
+            let end_location = Location::new(start.row() + row_offset, length);
+            checks.push(Check::new(
+                CheckKind::DocLineTooLong(length, max_doc_length),
+                Range {
+                    location,
+                    end_location,
+                },
+            ));
+        }
+    }
+
+    checks
+}
+
 fn is_ambiguous_name(name: &str) -> bool {
     name == "l" || name == "I" || name == "O"
 }
@@ -56,32 +260,65 @@ pub fn do_not_assign_lambda(value: &Expr, location: Range) -> Option<Check> {
 }
 
 /// E713, E714
+#[allow(clippy::too_many_arguments)]
 pub fn not_tests(
+    expr: &Expr,
     op: &Unaryop,
     operand: &Expr,
     check_not_in: bool,
     check_not_is: bool,
+    locator: &SourceCodeLocator,
+    autofix: bool,
 ) -> Vec<Check> {
     let mut checks: Vec<Check> = vec![];
 
     if matches!(op, Unaryop::Not) {
-        if let ExprKind::Compare { ops, .. } = &operand.node {
-            for op in ops {
+        if let ExprKind::Compare {
+            left,
+            ops,
+            comparators,
+        } = &operand.node
+        {
+            // Autofixing a chained comparison (e.g. `not a in b in c`) would require
+            // rewriting more than the single operator that triggered the check, so
+            // it's only attempted for the common, unambiguous case of one operator.
+            let can_fix = ops.len() == 1;
+            for (index, op) in ops.iter().enumerate() {
                 match op {
                     Cmpop::In => {
                         if check_not_in {
-                            checks.push(Check::new(
-                                CheckKind::NotInTest,
-                                Range::from_located(operand),
-                            ));
+                            let mut check =
+                                Check::new(CheckKind::NotInTest, Range::from_located(operand));
+                            if autofix && can_fix {
+                                match fixes::fix_not_in_test(
+                                    locator,
+                                    Range::from_located(expr),
+                                    left,
+                                    &comparators[index],
+                                ) {
+                                    Ok(fix) => check.amend(fix),
+                                    Err(e) => error!("Failed to fix `not in` test: {e}"),
+                                }
+                            }
+                            checks.push(check);
                         }
                     }
                     Cmpop::Is => {
                         if check_not_is {
-                            checks.push(Check::new(
-                                CheckKind::NotIsTest,
-                                Range::from_located(operand),
-                            ));
+                            let mut check =
+                                Check::new(CheckKind::NotIsTest, Range::from_located(operand));
+                            if autofix && can_fix {
+                                match fixes::fix_not_is_test(
+                                    locator,
+                                    Range::from_located(expr),
+                                    left,
+                                    &comparators[index],
+                                ) {
+                                    Ok(fix) => check.amend(fix),
+                                    Err(e) => error!("Failed to fix `not is` test: {e}"),
+                                }
+                            }
+                            checks.push(check);
                         }
                     }
                     _ => {}
@@ -93,18 +330,45 @@ pub fn not_tests(
     checks
 }
 
+/// Attempt to fix a `left op right` literal comparison, logging (rather than
+/// propagating) any failure to slice or reparse the underlying source.
+fn try_fix_literal_comparison(
+    locator: &SourceCodeLocator,
+    rejected: RejectedCmpop,
+    left: &Expr,
+    right: &Expr,
+) -> Option<Fix> {
+    let gap = Range {
+        location: left
+            .end_location
+            .expect("AST nodes should have end_location."),
+        end_location: right.location,
+    };
+    match fixes::fix_literal_comparison(locator, &rejected, gap) {
+        Ok(fix) => Some(fix),
+        Err(e) => {
+            error!("Failed to fix literal comparison: {e}");
+            None
+        }
+    }
+}
+
 /// E711, E712
+#[allow(clippy::too_many_arguments)]
 pub fn literal_comparisons(
     left: &Expr,
     ops: &[Cmpop],
     comparators: &[Expr],
     check_none_comparisons: bool,
     check_true_false_comparisons: bool,
+    locator: &SourceCodeLocator,
+    autofix: bool,
 ) -> Vec<Check> {
     let mut checks: Vec<Check> = vec![];
 
     let op = ops.first().unwrap();
     let comparator = left;
+    let right = comparators.first().unwrap();
 
     // Check `left`.
     if check_none_comparisons
@@ -117,16 +381,32 @@ pub fn literal_comparisons(
         )
     {
         if matches!(op, Cmpop::Eq) {
-            checks.push(Check::new(
+            let mut check = Check::new(
                 CheckKind::NoneComparison(RejectedCmpop::Eq),
                 Range::from_located(comparator),
-            ));
+            );
+            if autofix {
+                if let Some(fix) =
+                    try_fix_literal_comparison(locator, RejectedCmpop::Eq, left, right)
+                {
+                    check.amend(fix);
+                }
+            }
+            checks.push(check);
         }
         if matches!(op, Cmpop::NotEq) {
-            checks.push(Check::new(
+            let mut check = Check::new(
                 CheckKind::NoneComparison(RejectedCmpop::NotEq),
                 Range::from_located(comparator),
-            ));
+            );
+            if autofix {
+                if let Some(fix) =
+                    try_fix_literal_comparison(locator, RejectedCmpop::NotEq, left, right)
+                {
+                    check.amend(fix);
+                }
+            }
+            checks.push(check);
         }
     }
 
@@ -137,21 +417,38 @@ pub fn literal_comparisons(
         } = comparator.node
         {
             if matches!(op, Cmpop::Eq) {
-                checks.push(Check::new(
+                let mut check = Check::new(
                     CheckKind::TrueFalseComparison(value, RejectedCmpop::Eq),
                     Range::from_located(comparator),
-                ));
+                );
+                if autofix {
+                    if let Some(fix) =
+                        try_fix_literal_comparison(locator, RejectedCmpop::Eq, left, right)
+                    {
+                        check.amend(fix);
+                    }
+                }
+                checks.push(check);
             }
             if matches!(op, Cmpop::NotEq) {
-                checks.push(Check::new(
+                let mut check = Check::new(
                     CheckKind::TrueFalseComparison(value, RejectedCmpop::NotEq),
                     Range::from_located(comparator),
-                ));
+                );
+                if autofix {
+                    if let Some(fix) =
+                        try_fix_literal_comparison(locator, RejectedCmpop::NotEq, left, right)
+                    {
+                        check.amend(fix);
+                    }
+                }
+                checks.push(check);
             }
         }
     }
 
     // Check each comparator in order.
+    let mut prev = left;
     for (op, comparator) in izip!(ops, comparators) {
         if check_none_comparisons
             && matches!(
@@ -163,16 +460,32 @@ pub fn literal_comparisons(
             )
         {
             if matches!(op, Cmpop::Eq) {
-                checks.push(Check::new(
+                let mut check = Check::new(
                     CheckKind::NoneComparison(RejectedCmpop::Eq),
                     Range::from_located(comparator),
-                ));
+                );
+                if autofix {
+                    if let Some(fix) =
+                        try_fix_literal_comparison(locator, RejectedCmpop::Eq, prev, comparator)
+                    {
+                        check.amend(fix);
+                    }
+                }
+                checks.push(check);
             }
             if matches!(op, Cmpop::NotEq) {
-                checks.push(Check::new(
+                let mut check = Check::new(
                     CheckKind::NoneComparison(RejectedCmpop::NotEq),
                     Range::from_located(comparator),
-                ));
+                );
+                if autofix {
+                    if let Some(fix) =
+                        try_fix_literal_comparison(locator, RejectedCmpop::NotEq, prev, comparator)
+                    {
+                        check.amend(fix);
+                    }
+                }
+                checks.push(check);
             }
         }
 
@@ -183,19 +496,37 @@ pub fn literal_comparisons(
             } = comparator.node
             {
                 if matches!(op, Cmpop::Eq) {
-                    checks.push(Check::new(
+                    let mut check = Check::new(
                         CheckKind::TrueFalseComparison(value, RejectedCmpop::Eq),
                         Range::from_located(comparator),
-                    ));
+                    );
+                    if autofix {
+                        if let Some(fix) =
+                            try_fix_literal_comparison(locator, RejectedCmpop::Eq, prev, comparator)
+                        {
+                            check.amend(fix);
+                        }
+                    }
+                    checks.push(check);
                 }
                 if matches!(op, Cmpop::NotEq) {
-                    checks.push(Check::new(
+                    let mut check = Check::new(
                         CheckKind::TrueFalseComparison(value, RejectedCmpop::NotEq),
                         Range::from_located(comparator),
-                    ));
+                    );
+                    if autofix {
+                        if let Some(fix) =
+                            try_fix_literal_comparison(locator, RejectedCmpop::NotEq, prev, comparator)
+                        {
+                            check.amend(fix);
+                        }
+                    }
+                    checks.push(check);
                 }
             }
         }
+
+        prev = comparator;
     }
 
     checks
@@ -0,0 +1,91 @@
+use anyhow::{anyhow, Result};
+use rustpython_ast::{Expr, Location};
+
+use crate::ast::types::Range;
+use crate::autofix::Fix;
+use crate::checks::RejectedCmpop;
+use crate::source_code_locator::SourceCodeLocator;
+
+/// The AST location reported for a parenthesized expression stops short of the
+/// closing parenthesis (e.g. the operand of `not (X in Y)` is reported as `X
+/// in Y`). Since the fix below discards the (now-unneeded) opening
+/// parenthesis, extend `location` to also swallow a single immediately
+/// trailing `)`, if any, so the two stay balanced.
+fn expand_over_trailing_rparen(locator: &SourceCodeLocator, location: Range) -> Range {
+    let next = Location::new(
+        location.end_location.row(),
+        location.end_location.column() + 1,
+    );
+    let peek = locator.slice_source_code_range(&Range {
+        location: location.end_location,
+        end_location: next,
+    });
+    if peek.as_ref() == ")" {
+        Range {
+            location: location.location,
+            end_location: next,
+        }
+    } else {
+        location
+    }
+}
+
+/// Generate a Fix to rewrite a `==`/`!=` comparison against a singleton (`None`,
+/// `True`, or `False`) as the equivalent `is`/`is not` comparison, preserving the
+/// whitespace already surrounding the operator.
+pub fn fix_literal_comparison(
+    locator: &SourceCodeLocator,
+    op: &RejectedCmpop,
+    gap: Range,
+) -> Result<Fix> {
+    let text = locator.slice_source_code_range(&gap);
+    let content = match op {
+        RejectedCmpop::Eq => {
+            if !text.contains("==") {
+                return Err(anyhow!("Expected `==` between the two operands"));
+            }
+            text.replacen("==", "is", 1)
+        }
+        RejectedCmpop::NotEq => {
+            if !text.contains("!=") {
+                return Err(anyhow!("Expected `!=` between the two operands"));
+            }
+            text.replacen("!=", "is not", 1)
+        }
+    };
+    Ok(Fix::replacement(content, gap.location, gap.end_location))
+}
+
+/// Generate a Fix to rewrite `not {left} in {right}` as `{left} not in {right}`.
+pub fn fix_not_in_test(
+    locator: &SourceCodeLocator,
+    location: Range,
+    left: &Expr,
+    right: &Expr,
+) -> Result<Fix> {
+    let left_text = locator.slice_source_code_range(&Range::from_located(left));
+    let right_text = locator.slice_source_code_range(&Range::from_located(right));
+    let location = expand_over_trailing_rparen(locator, location);
+    Ok(Fix::replacement(
+        format!("{left_text} not in {right_text}"),
+        location.location,
+        location.end_location,
+    ))
+}
+
+/// Generate a Fix to rewrite `not {left} is {right}` as `{left} is not {right}`.
+pub fn fix_not_is_test(
+    locator: &SourceCodeLocator,
+    location: Range,
+    left: &Expr,
+    right: &Expr,
+) -> Result<Fix> {
+    let left_text = locator.slice_source_code_range(&Range::from_located(left));
+    let right_text = locator.slice_source_code_range(&Range::from_located(right));
+    let location = expand_over_trailing_rparen(locator, location);
+    Ok(Fix::replacement(
+        format!("{left_text} is not {right_text}"),
+        location.location,
+        location.end_location,
+    ))
+}
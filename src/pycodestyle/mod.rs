@@ -1 +1,3 @@
 pub mod checks;
+pub mod fixes;
+pub mod settings;
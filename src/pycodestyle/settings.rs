@@ -0,0 +1,25 @@
+//! Settings for the `pycodestyle` plugin.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Options {
+    /// The maximum line length to allow for line-length violations within
+    /// comments and docstrings, in lieu of `line-length`.
+    pub max_doc_length: Option<usize>,
+}
+
+#[derive(Debug, Hash, Default)]
+pub struct Settings {
+    pub max_doc_length: Option<usize>,
+}
+
+impl Settings {
+    pub fn from_options(options: Options) -> Self {
+        Self {
+            max_doc_length: options.max_doc_length,
+        }
+    }
+}
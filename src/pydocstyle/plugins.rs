@@ -3,7 +3,7 @@ use std::collections::BTreeSet;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use rustpython_ast::{Arg, Constant, ExprKind, Location, StmtKind};
+use rustpython_ast::{Arg, Constant, Expr, ExprKind, Location, StmtKind};
 
 use crate::ast::types::Range;
 use crate::autofix::Fix;
@@ -418,7 +418,10 @@ pub fn indent(checker: &mut Checker, definition: &Definition) {
                             CheckKind::NoUnderIndentation,
                             Range {
                                 location: Location::new(docstring.location.row() + i, 0),
-                                end_location: Location::new(docstring.location.row() + i, 0),
+                                end_location: Location::new(
+                                    docstring.location.row() + i,
+                                    line_indent.len(),
+                                ),
                             },
                         );
                         if checker.patch() {
@@ -468,7 +471,10 @@ pub fn indent(checker: &mut Checker, definition: &Definition) {
                                 CheckKind::NoOverIndentation,
                                 Range {
                                     location: Location::new(docstring.location.row() + i, 0),
-                                    end_location: Location::new(docstring.location.row() + i, 0),
+                                    end_location: Location::new(
+                                        docstring.location.row() + i,
+                                        line_indent.len(),
+                                    ),
                                 },
                             );
                             if checker.patch() {
@@ -492,7 +498,10 @@ pub fn indent(checker: &mut Checker, definition: &Definition) {
                             CheckKind::NoOverIndentation,
                             Range {
                                 location: Location::new(docstring.location.row() + i, 0),
-                                end_location: Location::new(docstring.location.row() + i, 0),
+                                end_location: Location::new(
+                                    docstring.location.row() + i,
+                                    line_indent.len(),
+                                ),
                             },
                         );
                         if checker.patch() {
@@ -678,16 +687,58 @@ pub fn triple_quotes(checker: &mut Checker, definition: &Definition) {
                         || first_line.starts_with("ur\"\"\"")
                 };
                 if !starts_with_triple {
-                    checker.add_check(Check::new(
+                    let mut check = Check::new(
                         CheckKind::UsesTripleQuotes,
                         Range::from_located(docstring),
-                    ));
+                    );
+                    if checker.patch() {
+                        let target_quote = if string.contains("\"\"\"") {
+                            "'''"
+                        } else {
+                            "\"\"\""
+                        };
+                        if let Some(fix) = use_triple_quotes(checker, docstring, target_quote) {
+                            check.amend(fix);
+                        }
+                    }
+                    checker.add_check(check);
                 }
             }
         }
     }
 }
 
+/// Rewrite a docstring's quote delimiters to `target_quote` (a triple-quote
+/// sequence), preserving any `u`/`r` prefix and the docstring's contents.
+fn use_triple_quotes(checker: &Checker, docstring: &Expr, target_quote: &str) -> Option<Fix> {
+    let contents = checker
+        .locator
+        .slice_source_code_range(&Range::from_located(docstring));
+    let lower = contents.to_lowercase();
+
+    let (old_prefix, quote_len) = helpers::TRIPLE_QUOTE_PREFIXES
+        .iter()
+        .find(|prefix| lower.starts_with(**prefix))
+        .map(|prefix| (*prefix, 3))
+        .or_else(|| {
+            helpers::SINGLE_QUOTE_PREFIXES
+                .iter()
+                .find(|prefix| lower.starts_with(**prefix))
+                .map(|prefix| (*prefix, 1))
+        })?;
+
+    let modifier = &contents[..old_prefix.len() - quote_len];
+    let body = &contents[old_prefix.len()..contents.len() - quote_len];
+    let new_contents = format!("{modifier}{target_quote}{body}{target_quote}");
+
+    let range = Range::from_located(docstring);
+    Some(Fix::replacement(
+        new_contents,
+        range.location,
+        range.end_location,
+    ))
+}
+
 /// D400
 pub fn ends_with_period(checker: &mut Checker, definition: &Definition) {
     if let Some(docstring) = definition.docstring {
@@ -698,16 +749,69 @@ pub fn ends_with_period(checker: &mut Checker, definition: &Definition) {
         {
             if let Some(string) = string.lines().next() {
                 if !string.ends_with('.') {
-                    checker.add_check(Check::new(
+                    let mut check = Check::new(
                         CheckKind::EndsInPeriod,
                         Range::from_located(docstring),
-                    ));
+                    );
+                    if checker.patch() {
+                        if let Some(at) = first_line_end(checker, docstring, |line| {
+                            line.ends_with('.')
+                        }) {
+                            check.amend(Fix::insertion(".".to_string(), at));
+                        }
+                    }
+                    checker.add_check(check);
                 }
             }
         }
     }
 }
 
+/// Locate the end of the first line of a docstring's contents, i.e. where a
+/// missing closing punctuation mark should be inserted. Returns `None` if
+/// the trimmed line already satisfies `is_punctuated` (the violation is due
+/// to trailing whitespace, not a missing punctuation mark, and D210 already
+/// covers trimming that whitespace).
+fn first_line_end(
+    checker: &Checker,
+    docstring: &Expr,
+    is_punctuated: impl Fn(&str) -> bool,
+) -> Option<Location> {
+    let contents = checker
+        .locator
+        .slice_source_code_range(&Range::from_located(docstring));
+    let lower = contents.to_lowercase();
+
+    let (prefix, quote_len) = helpers::TRIPLE_QUOTE_PREFIXES
+        .iter()
+        .find(|prefix| lower.starts_with(**prefix))
+        .map(|prefix| (*prefix, 3))
+        .or_else(|| {
+            helpers::SINGLE_QUOTE_PREFIXES
+                .iter()
+                .find(|prefix| lower.starts_with(**prefix))
+                .map(|prefix| (*prefix, 1))
+        })?;
+
+    let is_one_liner = contents.lines().count() == 1;
+    let first_line = contents.lines().next()?;
+    let after_prefix = &first_line[prefix.len()..];
+    let content = if is_one_liner {
+        &after_prefix[..after_prefix.len() - quote_len]
+    } else {
+        after_prefix
+    };
+    let trimmed = content.trim_end();
+    if trimmed.is_empty() || is_punctuated(trimmed) {
+        return None;
+    }
+
+    Some(Location::new(
+        docstring.location.row(),
+        docstring.location.column() + prefix.len() + trimmed.chars().count(),
+    ))
+}
+
 /// D402
 pub fn no_signature(checker: &mut Checker, definition: &Definition) {
     if let Some(docstring) = definition.docstring {
@@ -808,10 +912,18 @@ pub fn ends_with_punctuation(checker: &mut Checker, definition: &Definition) {
         {
             if let Some(string) = string.lines().next() {
                 if !(string.ends_with('.') || string.ends_with('!') || string.ends_with('?')) {
-                    checker.add_check(Check::new(
+                    let mut check = Check::new(
                         CheckKind::EndsInPunctuation,
                         Range::from_located(docstring),
-                    ));
+                    );
+                    if checker.patch() {
+                        if let Some(at) = first_line_end(checker, docstring, |line| {
+                            line.ends_with('.') || line.ends_with('!') || line.ends_with('?')
+                        }) {
+                            check.amend(Fix::insertion(".".to_string(), at));
+                        }
+                    }
+                    checker.add_check(check);
                 }
             }
         }
@@ -63,7 +63,7 @@ pub fn unused_variables(scope: &Scope, dummy_variable_rgx: &Regex) -> Vec<Check>
 
     for (name, binding) in scope.values.iter() {
         if binding.used.is_none()
-            && matches!(binding.kind, BindingKind::Assignment)
+            && matches!(binding.kind, BindingKind::Assignment(_))
             && !dummy_variable_rgx.is_match(name)
             && name != "__tracebackhide__"
             && name != "__traceback_info__"
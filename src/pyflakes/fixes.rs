@@ -1,9 +1,10 @@
 use anyhow::Result;
 use libcst_native::{
-    Codegen, CompOp, Comparison, ComparisonTarget, Expr, Expression, ImportNames, NameOrAttribute,
+    AsName, AssignTargetExpression, Codegen, CompOp, Comparison, ComparisonTarget, Expr,
+    Expression, ImportNames, Name, NameOrAttribute, ParenthesizableWhitespace, SimpleWhitespace,
     SmallStatement, Statement,
 };
-use rustpython_ast::Stmt;
+use rustpython_ast::{ExprKind, Location, Stmt, StmtKind};
 
 use crate::ast::types::Range;
 use crate::autofix::{helpers, Fix};
@@ -11,6 +12,31 @@ use crate::cst::helpers::compose_module_path;
 use crate::cst::matchers::{match_expr, match_module};
 use crate::source_code_locator::SourceCodeLocator;
 
+/// As `helpers::remove_stmt`, but when the statement is deleted outright
+/// (rather than replaced with `pass`), also swallows any fully-blank lines
+/// immediately following it. Without this, removing the last import out of
+/// a block that a blank line used to separate from the rest of the file
+/// leaves that blank line behind with nothing left to separate.
+fn remove_stmt_and_blank_lines(
+    locator: &SourceCodeLocator,
+    stmt: &Stmt,
+    parent: Option<&Stmt>,
+    deleted: &[&Stmt],
+) -> Result<Fix> {
+    let fix = helpers::remove_stmt(stmt, parent, deleted)?;
+    if !fix.patch.content.is_empty() {
+        // Replaced with `pass` to avoid an empty body; there's no line to
+        // swallow anything after.
+        return Ok(fix);
+    }
+    let rest = locator.slice_source_code_at(&fix.patch.end_location);
+    let blank_lines = rest.lines().take_while(|line| line.trim().is_empty()).count();
+    Ok(Fix::deletion(
+        fix.patch.location,
+        Location::new(fix.patch.end_location.row() + blank_lines, 0),
+    ))
+}
+
 /// Generate a Fix to remove any unused imports from an `import` statement.
 pub fn remove_unused_imports(
     locator: &SourceCodeLocator,
@@ -56,7 +82,7 @@ pub fn remove_unused_imports(
     }
 
     if aliases.is_empty() {
-        helpers::remove_stmt(stmt, parent, deleted)
+        remove_stmt_and_blank_lines(locator, stmt, parent, deleted)
     } else {
         let mut state = Default::default();
         tree.codegen(&mut state);
@@ -129,7 +155,7 @@ pub fn remove_unused_import_froms(
     }
 
     if aliases.is_empty() {
-        helpers::remove_stmt(stmt, parent, deleted)
+        remove_stmt_and_blank_lines(locator, stmt, parent, deleted)
     } else {
         let mut state = Default::default();
         tree.codegen(&mut state);
@@ -142,6 +168,126 @@ pub fn remove_unused_import_froms(
     }
 }
 
+/// An `as name` clause that just repeats `name`, turning a plain import into
+/// the `import x as x` / `from foo import x as x` idiom that marks `x` as an
+/// intentional re-export rather than dead code. `name` is reused by
+/// reference, so it must already live in the tree being edited (e.g. as
+/// returned by `NameOrAttribute`'s own fields), not a freshly allocated
+/// `String`.
+fn redundant_alias(name: &str) -> AsName {
+    AsName {
+        name: AssignTargetExpression::Name(Box::new(Name {
+            value: name,
+            lpar: vec![],
+            rpar: vec![],
+        })),
+        whitespace_before_as: ParenthesizableWhitespace::SimpleWhitespace(SimpleWhitespace(" ")),
+        whitespace_after_as: ParenthesizableWhitespace::SimpleWhitespace(SimpleWhitespace(" ")),
+    }
+}
+
+/// Generate a Fix that turns every unused, non-dotted import in `full_names`
+/// into an explicit `import x as x` re-export, for `__init__.py`'s "this
+/// name is part of the package's public API" convention. Dotted imports
+/// (`import foo.bar`) are left untouched: aliasing to anything but `foo.bar`
+/// itself would change which object the binding actually exposes.
+pub fn add_redundant_aliases(locator: &SourceCodeLocator, full_names: &[&str], stmt: &Stmt) -> Result<Fix> {
+    let module_text = locator.slice_source_code_range(&Range::from_located(stmt));
+    let mut tree = match_module(&module_text)?;
+
+    let body = if let Some(Statement::Simple(body)) = tree.body.first_mut() {
+        body
+    } else {
+        return Err(anyhow::anyhow!("Expected node to be: Statement::Simple"));
+    };
+    let body = if let Some(SmallStatement::Import(body)) = body.body.first_mut() {
+        body
+    } else {
+        return Err(anyhow::anyhow!("Expected node to be: SmallStatement::Import"));
+    };
+
+    for alias in body.names.iter_mut() {
+        if alias.asname.is_some() {
+            continue;
+        }
+        let full_name = compose_module_path(&alias.name);
+        if full_name.contains('.') || !full_names.contains(&full_name.as_str()) {
+            continue;
+        }
+        let NameOrAttribute::N(name) = &alias.name else {
+            continue;
+        };
+        alias.asname = Some(redundant_alias(name.value));
+    }
+
+    let mut state = Default::default();
+    tree.codegen(&mut state);
+
+    Ok(Fix::replacement(
+        state.to_string(),
+        stmt.location,
+        stmt.end_location.unwrap(),
+    ))
+}
+
+/// As `add_redundant_aliases`, but for `from foo import x` statements, where
+/// every imported name is a plain identifier and can always be re-exported
+/// as itself.
+pub fn add_redundant_aliases_from(
+    locator: &SourceCodeLocator,
+    full_names: &[&str],
+    stmt: &Stmt,
+) -> Result<Fix> {
+    let module_text = locator.slice_source_code_range(&Range::from_located(stmt));
+    let mut tree = match_module(&module_text)?;
+
+    let body = if let Some(Statement::Simple(body)) = tree.body.first_mut() {
+        body
+    } else {
+        return Err(anyhow::anyhow!("Expected node to be: Statement::Simple"));
+    };
+    let body = if let Some(SmallStatement::ImportFrom(body)) = body.body.first_mut() {
+        body
+    } else {
+        return Err(anyhow::anyhow!(
+            "Expected node to be: SmallStatement::ImportFrom"
+        ));
+    };
+
+    let aliases = if let ImportNames::Aliases(aliases) = &mut body.names {
+        aliases
+    } else {
+        return Err(anyhow::anyhow!("Expected node to be: Aliases"));
+    };
+
+    let module_name = body.module.as_ref().map(compose_module_path);
+    for alias in aliases.iter_mut() {
+        if alias.asname.is_some() {
+            continue;
+        }
+        let NameOrAttribute::N(name) = &alias.name else {
+            continue;
+        };
+        let full_name = module_name
+            .as_ref()
+            .map(|module_name| format!("{module_name}.{}", name.value))
+            .unwrap_or_else(|| name.value.to_string());
+        if !full_names.contains(&full_name.as_str()) {
+            continue;
+        }
+        alias.asname = Some(redundant_alias(name.value));
+    }
+
+    let mut state = Default::default();
+    tree.codegen(&mut state);
+
+    Ok(Fix::replacement(
+        state.to_string(),
+        stmt.location,
+        stmt.end_location.unwrap(),
+    ))
+}
+
 fn match_comparison<'a, 'b>(expr: &'a mut Expr<'b>) -> Result<&'a mut Comparison<'b>> {
     if let Expression::Comparison(comparison) = &mut expr.value {
         Ok(comparison)
@@ -204,3 +350,29 @@ pub fn fix_invalid_literal_comparison(locator: &SourceCodeLocator, location: Ran
         location.end_location,
     ))
 }
+
+/// (F841) Remove an unused variable's assignment statement outright, for a
+/// simple `name = value` assignment. Unlike `remove_unused_imports`, this
+/// can change runtime behavior if `value` has side effects that the
+/// statement's removal would silently drop, so the fix is marked
+/// `Applicability::Unsafe`: `--fix` only applies it when the user opts in
+/// with `--unsafe-fixes`.
+pub fn remove_unused_variable(
+    locator: &SourceCodeLocator,
+    stmt: &Stmt,
+    parent: Option<&Stmt>,
+    deleted: &[&Stmt],
+) -> Result<Option<Fix>> {
+    let StmtKind::Assign { targets, .. } = &stmt.node else {
+        return Ok(None);
+    };
+    let [target] = targets.as_slice() else {
+        return Ok(None);
+    };
+    if !matches!(target.node, ExprKind::Name { .. }) {
+        return Ok(None);
+    }
+    Ok(Some(
+        remove_stmt_and_blank_lines(locator, stmt, parent, deleted)?.unsafe_(),
+    ))
+}
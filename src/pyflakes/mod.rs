@@ -1,3 +1,4 @@
 pub mod checks;
 pub mod fixes;
+pub mod module_resolver;
 pub mod plugins;
@@ -0,0 +1,51 @@
+use std::fs;
+use std::path::Path;
+
+use rustpython_parser::ast::{ExprKind, Mod, StmtKind};
+use rustpython_parser::{lexer, parser};
+
+use crate::ast::operations::extract_all_names;
+use crate::ast::types::{Scope, ScopeKind};
+
+pub(crate) use crate::fs::resolve_module;
+
+/// Parse `path` and return the names it would export via `import *`: its
+/// `__all__` list, if any, otherwise every module-level name that doesn't
+/// start with an underscore.
+pub fn public_names(path: &Path) -> Option<Vec<String>> {
+    let contents = fs::read_to_string(path).ok()?;
+    let source_path = path.to_string_lossy();
+    let lxr = lexer::make_tokenizer(&contents).collect();
+    let python_ast = match parser::parse_tokens(lxr, parser::Mode::Module, &source_path).ok()? {
+        Mod::Module { body, .. } => body,
+        _ => return None,
+    };
+    let module_scope = Scope::new(ScopeKind::Module);
+
+    let mut names = vec![];
+    for stmt in &python_ast {
+        match &stmt.node {
+            StmtKind::FunctionDef { name, .. }
+            | StmtKind::AsyncFunctionDef { name, .. }
+            | StmtKind::ClassDef { name, .. } => names.push(name.to_string()),
+            StmtKind::Assign { targets, .. } => {
+                for target in targets {
+                    if let ExprKind::Name { id, .. } = &target.node {
+                        if id == "__all__" {
+                            return Some(extract_all_names(stmt, &module_scope));
+                        }
+                        names.push(id.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some(
+        names
+            .into_iter()
+            .filter(|name| !name.starts_with('_'))
+            .collect(),
+    )
+}
@@ -0,0 +1,258 @@
+use rustpython_ast::{Arguments, ExcepthandlerKind, Expr, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::check_ast::Checker;
+use crate::checks::{Check, CheckKind};
+
+/// PLR0913
+pub fn too_many_arguments(checker: &mut Checker, stmt: &Stmt, arguments: &Arguments) {
+    let num_args = arguments.posonlyargs.len() + arguments.args.len() + arguments.kwonlyargs.len();
+    if num_args > checker.settings.pylint.max_args {
+        checker.add_check(Check::new(
+            CheckKind::TooManyArguments(num_args, checker.settings.pylint.max_args),
+            Range::from_located(stmt),
+        ));
+    }
+}
+
+/// PLR0911
+pub fn too_many_return_statements(checker: &mut Checker, stmt: &Stmt, body: &[Stmt]) {
+    let returns = count_returns(body);
+    if returns > checker.settings.pylint.max_returns {
+        checker.add_check(Check::new(
+            CheckKind::TooManyReturnStatements(returns, checker.settings.pylint.max_returns),
+            Range::from_located(stmt),
+        ));
+    }
+}
+
+/// PLR0912
+pub fn too_many_branches(checker: &mut Checker, stmt: &Stmt, body: &[Stmt]) {
+    let branches = count_branches(body);
+    if branches > checker.settings.pylint.max_branches {
+        checker.add_check(Check::new(
+            CheckKind::TooManyBranches(branches, checker.settings.pylint.max_branches),
+            Range::from_located(stmt),
+        ));
+    }
+}
+
+/// PLR0915
+pub fn too_many_statements(checker: &mut Checker, stmt: &Stmt, body: &[Stmt]) {
+    let statements = count_statements(body);
+    if statements > checker.settings.pylint.max_statements {
+        checker.add_check(Check::new(
+            CheckKind::TooManyStatements(statements, checker.settings.pylint.max_statements),
+            Range::from_located(stmt),
+        ));
+    }
+}
+
+/// PLR1702
+pub fn too_many_nested_blocks(checker: &mut Checker, stmt: &Stmt, body: &[Stmt]) {
+    let depth = max_nesting_depth(body);
+    if depth > checker.settings.pylint.max_nested_blocks {
+        checker.add_check(Check::new(
+            CheckKind::TooManyNestedBlocks(depth, checker.settings.pylint.max_nested_blocks),
+            Range::from_located(stmt),
+        ));
+    }
+}
+
+/// PLR0916
+pub fn too_many_boolean_expressions(checker: &mut Checker, stmt: &Stmt, test: &Expr) {
+    let count = count_bool_expressions(test);
+    if count > checker.settings.pylint.max_bool_expr {
+        checker.add_check(Check::new(
+            CheckKind::TooManyBooleanExpressions(count, checker.settings.pylint.max_bool_expr),
+            Range::from_located(stmt),
+        ));
+    }
+}
+
+fn count_returns(body: &[Stmt]) -> usize {
+    body.iter().map(count_returns_stmt).sum()
+}
+
+fn count_returns_stmt(stmt: &Stmt) -> usize {
+    match &stmt.node {
+        StmtKind::Return { .. } => 1,
+        StmtKind::If { body, orelse, .. } => count_returns(body) + count_returns(orelse),
+        StmtKind::For { body, orelse, .. } | StmtKind::AsyncFor { body, orelse, .. } => {
+            count_returns(body) + count_returns(orelse)
+        }
+        StmtKind::While { body, orelse, .. } => count_returns(body) + count_returns(orelse),
+        StmtKind::Try {
+            body,
+            handlers,
+            orelse,
+            finalbody,
+        } => {
+            count_returns(body)
+                + handlers
+                    .iter()
+                    .map(|handler| {
+                        let ExcepthandlerKind::ExceptHandler { body, .. } =
+                            &handler.node;
+                        count_returns(body)
+                    })
+                    .sum::<usize>()
+                + count_returns(orelse)
+                + count_returns(finalbody)
+        }
+        StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => count_returns(body),
+        StmtKind::FunctionDef { .. }
+        | StmtKind::AsyncFunctionDef { .. }
+        | StmtKind::ClassDef { .. } => 0,
+        _ => 0,
+    }
+}
+
+/// Counts branch points, mirroring pylint's own `too-many-branches`: each
+/// `if`/`elif`, each `except` handler, and each loop adds one branch, without
+/// the nesting weight `RUF010`'s cognitive-complexity metric applies.
+fn count_branches(body: &[Stmt]) -> usize {
+    body.iter().map(count_branches_stmt).sum()
+}
+
+fn count_branches_stmt(stmt: &Stmt) -> usize {
+    match &stmt.node {
+        StmtKind::If { body, orelse, .. } => {
+            1 + count_branches(body)
+                + match orelse.as_slice() {
+                    [] => 0,
+                    [Stmt {
+                        node: StmtKind::If { .. },
+                        ..
+                    }] => count_branches(orelse),
+                    _ => 1 + count_branches(orelse),
+                }
+        }
+        StmtKind::For { body, orelse, .. } | StmtKind::AsyncFor { body, orelse, .. } => {
+            1 + count_branches(body) + count_branches(orelse)
+        }
+        StmtKind::While { body, orelse, .. } => 1 + count_branches(body) + count_branches(orelse),
+        StmtKind::Try {
+            body,
+            handlers,
+            orelse,
+            finalbody,
+        } => {
+            count_branches(body)
+                + handlers
+                    .iter()
+                    .map(|handler| {
+                        let ExcepthandlerKind::ExceptHandler { body, .. } =
+                            &handler.node;
+                        1 + count_branches(body)
+                    })
+                    .sum::<usize>()
+                + count_branches(orelse)
+                + count_branches(finalbody)
+        }
+        StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => count_branches(body),
+        StmtKind::FunctionDef { .. }
+        | StmtKind::AsyncFunctionDef { .. }
+        | StmtKind::ClassDef { .. } => 0,
+        _ => 0,
+    }
+}
+
+/// Counts statements recursively, the way pylint's own `too-many-statements`
+/// does: every statement counts once, regardless of how deeply it's nested,
+/// but statements inside a nested function or class are scored against that
+/// definition instead.
+fn count_statements(body: &[Stmt]) -> usize {
+    body.iter().map(count_statements_stmt).sum()
+}
+
+fn count_statements_stmt(stmt: &Stmt) -> usize {
+    1 + match &stmt.node {
+        StmtKind::If { body, orelse, .. } => count_statements(body) + count_statements(orelse),
+        StmtKind::For { body, orelse, .. } | StmtKind::AsyncFor { body, orelse, .. } => {
+            count_statements(body) + count_statements(orelse)
+        }
+        StmtKind::While { body, orelse, .. } => count_statements(body) + count_statements(orelse),
+        StmtKind::Try {
+            body,
+            handlers,
+            orelse,
+            finalbody,
+        } => {
+            count_statements(body)
+                + handlers
+                    .iter()
+                    .map(|handler| {
+                        let ExcepthandlerKind::ExceptHandler { body, .. } =
+                            &handler.node;
+                        count_statements(body)
+                    })
+                    .sum::<usize>()
+                + count_statements(orelse)
+                + count_statements(finalbody)
+        }
+        StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => count_statements(body),
+        StmtKind::FunctionDef { .. }
+        | StmtKind::AsyncFunctionDef { .. }
+        | StmtKind::ClassDef { .. } => 0,
+        _ => 0,
+    }
+}
+
+/// The deepest number of nested blocks (`if`, `for`, `while`, `try`, `with`)
+/// reachable from `body`, the way pylint's own `too-many-nested-blocks`
+/// counts depth.
+fn max_nesting_depth(body: &[Stmt]) -> usize {
+    body.iter().map(max_nesting_depth_stmt).max().unwrap_or(0)
+}
+
+fn max_nesting_depth_stmt(stmt: &Stmt) -> usize {
+    match &stmt.node {
+        StmtKind::If { body, orelse, .. } => {
+            1 + max_nesting_depth(body).max(max_nesting_depth(orelse))
+        }
+        StmtKind::For { body, orelse, .. } | StmtKind::AsyncFor { body, orelse, .. } => {
+            1 + max_nesting_depth(body).max(max_nesting_depth(orelse))
+        }
+        StmtKind::While { body, orelse, .. } => {
+            1 + max_nesting_depth(body).max(max_nesting_depth(orelse))
+        }
+        StmtKind::Try {
+            body,
+            handlers,
+            orelse,
+            finalbody,
+        } => {
+            let handlers_depth = handlers
+                .iter()
+                .map(|handler| {
+                    let ExcepthandlerKind::ExceptHandler { body, .. } =
+                        &handler.node;
+                    max_nesting_depth(body)
+                })
+                .max()
+                .unwrap_or(0);
+            1 + max_nesting_depth(body)
+                .max(handlers_depth)
+                .max(max_nesting_depth(orelse))
+                .max(max_nesting_depth(finalbody))
+        }
+        StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => {
+            1 + max_nesting_depth(body)
+        }
+        StmtKind::FunctionDef { .. }
+        | StmtKind::AsyncFunctionDef { .. }
+        | StmtKind::ClassDef { .. } => 0,
+        _ => 0,
+    }
+}
+
+fn count_bool_expressions(expr: &Expr) -> usize {
+    match &expr.node {
+        ExprKind::BoolOp { values, .. } => {
+            values.len().saturating_sub(1)
+                + values.iter().map(count_bool_expressions).sum::<usize>()
+        }
+        _ => 0,
+    }
+}
@@ -0,0 +1,69 @@
+//! Settings for the `pylint` plugin.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const MAX_ARGS: usize = 5;
+const MAX_BRANCHES: usize = 12;
+const MAX_RETURNS: usize = 6;
+const MAX_STATEMENTS: usize = 50;
+const MAX_BOOL_EXPR: usize = 5;
+const MAX_NESTED_BLOCKS: usize = 5;
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Options {
+    /// The maximum number of arguments a function may accept, per
+    /// `PLR0913`.
+    pub max_args: Option<usize>,
+    /// The maximum number of branches a function may have, per `PLR0912`.
+    pub max_branches: Option<usize>,
+    /// The maximum number of return statements a function may have, per
+    /// `PLR0911`.
+    pub max_returns: Option<usize>,
+    /// The maximum number of statements a function may have, per
+    /// `PLR0915`.
+    pub max_statements: Option<usize>,
+    /// The maximum number of boolean expressions a single `if` statement
+    /// may combine, per `PLR0916`.
+    pub max_bool_expr: Option<usize>,
+    /// The maximum number of nested blocks a function may contain, per
+    /// `PLR1702`.
+    pub max_nested_blocks: Option<usize>,
+}
+
+#[derive(Debug, Hash)]
+pub struct Settings {
+    pub max_args: usize,
+    pub max_branches: usize,
+    pub max_returns: usize,
+    pub max_statements: usize,
+    pub max_bool_expr: usize,
+    pub max_nested_blocks: usize,
+}
+
+impl Settings {
+    pub fn from_options(options: Options) -> Self {
+        Self {
+            max_args: options.max_args.unwrap_or(MAX_ARGS),
+            max_branches: options.max_branches.unwrap_or(MAX_BRANCHES),
+            max_returns: options.max_returns.unwrap_or(MAX_RETURNS),
+            max_statements: options.max_statements.unwrap_or(MAX_STATEMENTS),
+            max_bool_expr: options.max_bool_expr.unwrap_or(MAX_BOOL_EXPR),
+            max_nested_blocks: options.max_nested_blocks.unwrap_or(MAX_NESTED_BLOCKS),
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_args: MAX_ARGS,
+            max_branches: MAX_BRANCHES,
+            max_returns: MAX_RETURNS,
+            max_statements: MAX_STATEMENTS,
+            max_bool_expr: MAX_BOOL_EXPR,
+            max_nested_blocks: MAX_NESTED_BLOCKS,
+        }
+    }
+}
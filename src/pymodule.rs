@@ -0,0 +1,31 @@
+//! A PyO3 extension module exposing `ruff.check(...)` as an importable
+//! Python function, for pytest plugins and other in-process Python tooling
+//! that wants to call the linter without a subprocess. Built via `cargo
+//! build --features extension-module` (typically driven by `maturin`), not
+//! part of the default CLI build.
+
+use std::path::PathBuf;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
+
+/// Run Ruff over `contents` (as if it were the file at `path`) and return
+/// each violation as a `(code, message)` tuple, mirroring `crate::check`
+/// but translated into types PyO3 can hand back to Python directly.
+#[pyfunction]
+fn check(path: &str, contents: &str, autofix: bool) -> PyResult<Vec<(String, String)>> {
+    let checks = crate::check(&PathBuf::from(path), contents, autofix)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(checks
+        .into_iter()
+        .map(|check| (check.kind.code().as_ref().to_string(), check.kind.body()))
+        .collect())
+}
+
+/// The `ruff` Python extension module: `import ruff; ruff.check(path, contents, autofix)`.
+#[pymodule]
+fn ruff(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(check, m)?)?;
+    Ok(())
+}
@@ -210,16 +210,18 @@ pub enum SubscriptKind {
 pub fn match_annotated_subscript(
     expr: &Expr,
     from_imports: &FnvHashMap<&str, FnvHashSet<&str>>,
+    typing_modules: &[String],
 ) -> Option<SubscriptKind> {
     match &expr.node {
         ExprKind::Attribute { attr, value, .. } => {
             if let ExprKind::Name { id, .. } = &value.node {
-                // If `id` is `typing` and `attr` is `Union`, verify that `typing.Union` is an
-                // annotated subscript.
+                // If `id` is `typing` (or a user-declared typing re-export) and `attr` is
+                // `Union`, verify that `typing.Union` is an annotated subscript.
                 if IMPORTED_SUBSCRIPTS
                     .get(&attr.as_str())
                     .map(|imports| imports.contains(&id.as_str()))
                     .unwrap_or_default()
+                    || typing_modules.iter().any(|module| module == id)
                 {
                     return if is_pep593_annotated_subscript(attr) {
                         Some(SubscriptKind::PEP593AnnotatedSubscript)
@@ -235,8 +237,13 @@ pub fn match_annotated_subscript(
                 return Some(SubscriptKind::AnnotatedSubscript);
             }
 
-            // Verify that, e.g., `Union` is a reference to `typing.Union`.
+            // Verify that, e.g., `Union` is a reference to `typing.Union` (or to a
+            // user-declared typing re-export, e.g. `from my_project.compat.typing import Union`).
             if let Some(modules) = IMPORTED_SUBSCRIPTS.get(&id.as_str()) {
+                let modules = modules
+                    .iter()
+                    .copied()
+                    .chain(typing_modules.iter().map(String::as_str));
                 for module in modules {
                     if from_imports
                         .get(module)
@@ -260,20 +267,29 @@ pub fn match_annotated_subscript(
 /// Returns `true` if `Expr` represents a reference to a typing object with a
 /// PEP 585 built-in. Note that none of the PEP 585 built-ins are in
 /// `typing_extensions`.
-pub fn is_pep585_builtin(expr: &Expr, from_imports: &FnvHashMap<&str, FnvHashSet<&str>>) -> bool {
+pub fn is_pep585_builtin(
+    expr: &Expr,
+    from_imports: &FnvHashMap<&str, FnvHashSet<&str>>,
+    typing_modules: &[String],
+) -> bool {
     match &expr.node {
         ExprKind::Attribute { attr, value, .. } => {
             if let ExprKind::Name { id, .. } = &value.node {
-                id == "typing" && PEP_585_BUILTINS_ELIGIBLE.contains(&attr.as_str())
+                (id == "typing" || typing_modules.iter().any(|module| module == id))
+                    && PEP_585_BUILTINS_ELIGIBLE.contains(&attr.as_str())
             } else {
                 false
             }
         }
         ExprKind::Name { id, .. } => {
-            from_imports
-                .get("typing")
-                .map(|imports| imports.contains(&id.as_str()) || imports.contains("*"))
-                .unwrap_or_default()
+            std::iter::once("typing")
+                .chain(typing_modules.iter().map(String::as_str))
+                .any(|module| {
+                    from_imports
+                        .get(module)
+                        .map(|imports| imports.contains(&id.as_str()) || imports.contains("*"))
+                        .unwrap_or_default()
+                })
                 && PEP_585_BUILTINS_ELIGIBLE.contains(&id.as_str())
         }
         _ => false,
@@ -1,10 +1,19 @@
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 use once_cell::sync::Lazy;
-use rustpython_ast::Location;
+use rustpython_ast::{
+    Arg, Arguments, Constant, ExcepthandlerKind, Expr, ExprContext, ExprKind, Location, Operator,
+    Stmt, StmtKind,
+};
 
+use crate::ast::helpers::{compose_call_path, match_call_path, match_module_member};
 use crate::ast::types::Range;
 use crate::autofix::Fix;
+use crate::check_ast::Checker;
 use crate::checks::CheckKind;
+use crate::code_gen::SourceGenerator;
+use crate::fs::resolve_module;
+use crate::isort::categorize::{categorize, ImportType};
+use crate::settings::types::PythonVersion;
 use crate::source_code_locator::SourceCodeLocator;
 use crate::Check;
 
@@ -1606,6 +1615,7 @@ pub fn ambiguous_unicode_character(
     start: &Location,
     end: &Location,
     context: Context,
+    allowed_confusables: &FnvHashSet<char>,
     fix: bool,
 ) -> Vec<Check> {
     let mut checks = vec![];
@@ -1618,41 +1628,45 @@ pub fn ambiguous_unicode_character(
     let mut col_offset = 0;
     let mut row_offset = 0;
     for current_char in text.chars() {
-        // Search for confusing characters.
-        if let Some(representant) = CONFUSABLES.get(&(current_char as u32)) {
-            if let Some(representant) = char::from_u32(*representant) {
-                let location = if row_offset == 0 {
-                    Location::new(start.row() + row_offset, start.column() + col_offset)
-                } else {
-                    Location::new(start.row() + row_offset, col_offset)
-                };
-                let end_location = Location::new(location.row(), location.column() + 1);
-                let mut check = Check::new(
-                    match context {
-                        Context::String => {
-                            CheckKind::AmbiguousUnicodeCharacterString(current_char, representant)
-                        }
-                        Context::Docstring => CheckKind::AmbiguousUnicodeCharacterDocstring(
-                            current_char,
-                            representant,
-                        ),
-                        Context::Comment => {
-                            CheckKind::AmbiguousUnicodeCharacterComment(current_char, representant)
-                        }
-                    },
-                    Range {
-                        location,
-                        end_location,
-                    },
-                );
-                if fix {
-                    check.amend(Fix::replacement(
-                        representant.to_string(),
-                        location,
-                        end_location,
-                    ));
+        // Search for confusing characters, skipping any the user has explicitly allowed.
+        if !allowed_confusables.contains(&current_char) {
+            if let Some(representant) = CONFUSABLES.get(&(current_char as u32)) {
+                if let Some(representant) = char::from_u32(*representant) {
+                    let location = if row_offset == 0 {
+                        Location::new(start.row() + row_offset, start.column() + col_offset)
+                    } else {
+                        Location::new(start.row() + row_offset, col_offset)
+                    };
+                    let end_location = Location::new(location.row(), location.column() + 1);
+                    let mut check = Check::new(
+                        match context {
+                            Context::String => CheckKind::AmbiguousUnicodeCharacterString(
+                                current_char,
+                                representant,
+                            ),
+                            Context::Docstring => CheckKind::AmbiguousUnicodeCharacterDocstring(
+                                current_char,
+                                representant,
+                            ),
+                            Context::Comment => CheckKind::AmbiguousUnicodeCharacterComment(
+                                current_char,
+                                representant,
+                            ),
+                        },
+                        Range {
+                            location,
+                            end_location,
+                        },
+                    );
+                    if fix {
+                        check.amend(Fix::replacement(
+                            representant.to_string(),
+                            location,
+                            end_location,
+                        ));
+                    }
+                    checks.push(check);
                 }
-                checks.push(check);
             }
         }
 
@@ -1667,3 +1681,322 @@ pub fn ambiguous_unicode_character(
 
     checks
 }
+
+/// RUF004
+pub fn mutable_dataclass_default(
+    decorator_list: &[Expr],
+    body: &[Stmt],
+    from_imports: &FnvHashMap<&str, FnvHashSet<&str>>,
+) -> Vec<Check> {
+    let is_dataclass = decorator_list
+        .iter()
+        .any(|expr| match_module_member(expr, "dataclasses.dataclass", from_imports));
+    if !is_dataclass {
+        return vec![];
+    }
+
+    body.iter()
+        .filter_map(|stmt| {
+            let StmtKind::AnnAssign { value: Some(value), .. } = &stmt.node else {
+                return None;
+            };
+            match &value.node {
+                ExprKind::List { .. }
+                | ExprKind::Dict { .. }
+                | ExprKind::Set { .. }
+                | ExprKind::ListComp { .. }
+                | ExprKind::DictComp { .. }
+                | ExprKind::SetComp { .. } => Some(Check::new(
+                    CheckKind::MutableDataclassDefault,
+                    Range::from_located(value.as_ref()),
+                )),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn is_const_none(expr: &Expr) -> bool {
+    matches!(
+        expr.node,
+        ExprKind::Constant {
+            value: Constant::None,
+            ..
+        }
+    )
+}
+
+/// Returns `true` if `annotation` already permits `None` (e.g. `Optional[T]`, `Union[T, None]`,
+/// or `T | None`), so an implicit-`Optional` default doesn't need to be flagged.
+fn is_optional_annotation(checker: &Checker, annotation: &Expr) -> bool {
+    match &annotation.node {
+        ExprKind::Subscript { value, slice, .. } => {
+            if checker.match_typing_module(value, "Optional") {
+                return true;
+            }
+            if checker.match_typing_module(value, "Union") {
+                return match &slice.node {
+                    ExprKind::Tuple { elts, .. } => elts.iter().any(is_const_none),
+                    _ => is_const_none(slice),
+                };
+            }
+            false
+        }
+        ExprKind::BinOp {
+            left,
+            op: Operator::BitOr,
+            right,
+        } => is_const_none(left) || is_const_none(right),
+        _ => false,
+    }
+}
+
+/// Build `Optional[<annotation>]`.
+fn optional(annotation: &Expr) -> Expr {
+    Expr::new(
+        Default::default(),
+        Default::default(),
+        ExprKind::Subscript {
+            value: Box::new(Expr::new(
+                Default::default(),
+                Default::default(),
+                ExprKind::Name {
+                    id: "Optional".to_string(),
+                    ctx: ExprContext::Load,
+                },
+            )),
+            slice: Box::new(annotation.clone()),
+            ctx: ExprContext::Load,
+        },
+    )
+}
+
+/// Build `<annotation> | None`.
+fn optional_union(annotation: &Expr) -> Expr {
+    Expr::new(
+        Default::default(),
+        Default::default(),
+        ExprKind::BinOp {
+            left: Box::new(annotation.clone()),
+            op: Operator::BitOr,
+            right: Box::new(Expr::new(
+                Default::default(),
+                Default::default(),
+                ExprKind::Constant {
+                    value: Constant::None,
+                    kind: None,
+                },
+            )),
+        },
+    )
+}
+
+fn check_implicit_optional(checker: &mut Checker, arg: &Arg, default: &Expr) {
+    let Some(annotation) = &arg.node.annotation else {
+        return;
+    };
+    if !is_const_none(default) || is_optional_annotation(checker, annotation) {
+        return;
+    }
+
+    let mut check = Check::new(
+        CheckKind::ImplicitOptional,
+        Range::from_located(annotation.as_ref()),
+    );
+    if checker.patch() {
+        let target = if checker.settings.target_version >= PythonVersion::Py310 {
+            optional_union(annotation)
+        } else {
+            optional(annotation)
+        };
+        let mut generator = SourceGenerator::new();
+        if generator.unparse_expr(&target, 0).is_ok() {
+            if let Ok(content) = generator.generate() {
+                check.amend(Fix::replacement(
+                    content,
+                    annotation.location,
+                    annotation.end_location.unwrap(),
+                ));
+            }
+        }
+    }
+    checker.add_check(check);
+}
+
+/// RUF005
+pub fn implicit_optional(checker: &mut Checker, arguments: &Arguments) {
+    let defaults_start =
+        arguments.posonlyargs.len() + arguments.args.len() - arguments.defaults.len();
+    for (i, arg) in arguments
+        .posonlyargs
+        .iter()
+        .chain(&arguments.args)
+        .enumerate()
+    {
+        if let Some(i) = i.checked_sub(defaults_start) {
+            check_implicit_optional(checker, arg, &arguments.defaults[i]);
+        }
+    }
+
+    let defaults_start = arguments.kwonlyargs.len() - arguments.kw_defaults.len();
+    for (i, arg) in arguments.kwonlyargs.iter().enumerate() {
+        if let Some(i) = i.checked_sub(defaults_start) {
+            check_implicit_optional(checker, arg, &arguments.kw_defaults[i]);
+        }
+    }
+}
+
+const DANGLING_TASK_FUNCS: [&str; 2] = ["asyncio.create_task", "asyncio.ensure_future"];
+
+/// RUF006
+pub fn asyncio_dangling_task(checker: &mut Checker, expr: &Expr) {
+    let ExprKind::Call { func, .. } = &expr.node else {
+        return;
+    };
+    let is_dangling_task = compose_call_path(func)
+        .map(|call_path| {
+            DANGLING_TASK_FUNCS
+                .iter()
+                .any(|target| match_call_path(&call_path, target, &checker.from_imports))
+        })
+        .unwrap_or(false);
+    if is_dangling_task {
+        checker.add_check(Check::new(
+            CheckKind::AsyncioDanglingTask,
+            Range::from_located(expr),
+        ));
+    }
+}
+
+/// RUF007
+pub fn unresolved_import(checker: &mut Checker, stmt: &Stmt, module: &str, level: Option<usize>) {
+    if checker.settings.site_packages.is_empty() {
+        // Without a search path to resolve against, every ordinary third-party import
+        // would look identical to a typo'd one.
+        return;
+    }
+
+    let base = module.split('.').next().unwrap_or(module);
+    let import_type = categorize(
+        base,
+        &level,
+        &checker.settings.src,
+        &checker.settings.isort.known_first_party,
+        &checker.settings.isort.known_third_party,
+        &checker.settings.isort.extra_standard_library,
+    );
+    if import_type != ImportType::ThirdParty {
+        return;
+    }
+
+    if resolve_module(&checker.settings.site_packages, module).is_none()
+        && resolve_module(&checker.settings.site_packages, base).is_none()
+    {
+        checker.add_check(Check::new(
+            CheckKind::UnresolvedImport(module.to_string()),
+            Range::from_located(stmt),
+        ));
+    }
+}
+
+/// RUF010
+pub fn function_is_too_complex(
+    checker: &mut Checker,
+    stmt: &Stmt,
+    name: &str,
+    body: &[Stmt],
+    max_complexity: usize,
+) {
+    let complexity = cognitive_complexity(body, 0);
+    if complexity > max_complexity {
+        checker.add_check(Check::new(
+            CheckKind::FunctionIsTooComplex(name.to_string(), complexity, max_complexity),
+            Range::from_located(stmt),
+        ));
+    }
+}
+
+/// The cognitive complexity of a sequence of statements, per SonarSource's
+/// nesting-weighted metric: each branching construct adds one point plus one
+/// per level of nesting it occurs at, and each additional operator in a
+/// boolean sequence adds a point of its own. Nested function and class
+/// definitions are scored independently when they're visited in their own
+/// right, so they don't inflate their enclosing function's score.
+fn cognitive_complexity(body: &[Stmt], nesting: usize) -> usize {
+    body.iter()
+        .map(|stmt| cognitive_complexity_stmt(stmt, nesting))
+        .sum()
+}
+
+fn cognitive_complexity_stmt(stmt: &Stmt, nesting: usize) -> usize {
+    match &stmt.node {
+        StmtKind::If {
+            test, body, orelse, ..
+        } => {
+            let mut score = 1 + nesting + boolop_increment(test);
+            score += cognitive_complexity(body, nesting + 1);
+            score += match orelse.as_slice() {
+                [] => 0,
+                // An `elif` reads as a continuation of the same decision, so it's
+                // scored as a sibling branch rather than an additional level of
+                // nesting.
+                [Stmt {
+                    node: StmtKind::If { .. },
+                    ..
+                }] => cognitive_complexity(orelse, nesting),
+                _ => 1 + cognitive_complexity(orelse, nesting + 1),
+            };
+            score
+        }
+        StmtKind::For { body, orelse, .. } | StmtKind::AsyncFor { body, orelse, .. } => {
+            1 + nesting
+                + cognitive_complexity(body, nesting + 1)
+                + cognitive_complexity(orelse, nesting)
+        }
+        StmtKind::While { test, body, orelse } => {
+            1 + nesting
+                + boolop_increment(test)
+                + cognitive_complexity(body, nesting + 1)
+                + cognitive_complexity(orelse, nesting)
+        }
+        StmtKind::Try {
+            body,
+            handlers,
+            orelse,
+            finalbody,
+        } => {
+            let handlers_score: usize = handlers
+                .iter()
+                .map(|handler| {
+                    let ExcepthandlerKind::ExceptHandler { body, .. } = &handler.node;
+                    1 + nesting + cognitive_complexity(body, nesting + 1)
+                })
+                .sum();
+            cognitive_complexity(body, nesting)
+                + handlers_score
+                + cognitive_complexity(orelse, nesting)
+                + cognitive_complexity(finalbody, nesting)
+        }
+        StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => {
+            cognitive_complexity(body, nesting)
+        }
+        // Nested definitions are scored on their own terms when the visitor
+        // reaches them, not folded into the enclosing function's score.
+        StmtKind::FunctionDef { .. }
+        | StmtKind::AsyncFunctionDef { .. }
+        | StmtKind::ClassDef { .. } => 0,
+        _ => 0,
+    }
+}
+
+/// Each additional operator in a boolean sequence (e.g. the second `and` in
+/// `a and b and c`) adds a point of its own, since it's another place the
+/// reader has to track a short-circuiting branch.
+fn boolop_increment(expr: &Expr) -> usize {
+    match &expr.node {
+        ExprKind::BoolOp { values, .. } => {
+            values.len().saturating_sub(1) + values.iter().map(boolop_increment).sum::<usize>()
+        }
+        _ => 0,
+    }
+}
@@ -1,3 +1,17 @@
 //! Module for Ruff-specific rules.
 
 pub mod checks;
+
+// TODO(charlie): A dynamic third-party-rule interface (a `Rule`/`Violation`
+// trait object plus a registration mechanism, so organizations can ship
+// private rules that participate in `CheckCode` selection, noqa, and the fix
+// pipeline) has been requested. `CheckCode`/`CheckKind` in `checks.rs` are
+// closed enums threaded through selection, `noqa`, snapshotting, and
+// `checks_gen.rs`'s prefix machinery — a third-party rule can't produce a
+// `CheckCode` it doesn't already have a variant for. Checked for an existing
+// extension point (`dyn`/trait-object dispatch, a registry) anywhere in the
+// crate and there isn't one; every check still flows through the closed
+// enum. Supporting this for real means an open, non-enum representation for
+// check identity across all of that plumbing, which is a much bigger
+// redesign than fits in one change. Deferred until we're ready to take that
+// on deliberately.
@@ -0,0 +1,279 @@
+//! A Language Server Protocol server, run over stdio via `--server`, that
+//! publishes diagnostics for open documents and offers quick-fix code
+//! actions built from the same `Fix`es `--fix` would apply on disk. Unlike
+//! `daemon`, which lints files already on disk, this module lints whatever
+//! buffer the editor hands it, so edits are linted before they're saved.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use lsp_server::{
+    Connection, ExtractError, Message as RpcMessage, Notification, Request, RequestId, Response,
+};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+    Notification as _, PublishDiagnostics,
+};
+use lsp_types::request::{CodeActionRequest, Request as _};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+    InitializeParams, Position, PublishDiagnosticsParams, Range, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::linter::lint_source;
+use crate::message::{Message, Severity};
+use crate::settings::Settings;
+
+/// The parsed text and most recent diagnostics for one open document. Kept
+/// warm so `textDocument/codeAction` doesn't need to re-lint on every
+/// request; it's refreshed on `didOpen`/`didChange`/`didSave` instead.
+struct Document {
+    text: String,
+    messages: Vec<Message>,
+}
+
+/// Run the LSP server over stdio until the client disconnects.
+pub fn run(settings: &Settings) -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        ..Default::default()
+    };
+    let initialization_params = connection.initialize(serde_json::to_value(capabilities)?)?;
+    let _params: InitializeParams = serde_json::from_value(initialization_params)?;
+
+    main_loop(&connection, settings)?;
+    io_threads.join()?;
+    Ok(())
+}
+
+fn main_loop(connection: &Connection, settings: &Settings) -> Result<()> {
+    let mut documents: HashMap<Url, Document> = HashMap::new();
+
+    for message in &connection.receiver {
+        match message {
+            RpcMessage::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                if let Ok((id, params)) = cast_request::<CodeActionRequest>(request) {
+                    let actions = code_actions(&documents, &params);
+                    let response = Response::new_ok(id, actions);
+                    connection.sender.send(RpcMessage::Response(response))?;
+                }
+            }
+            RpcMessage::Notification(notification) => {
+                handle_notification(connection, settings, &mut documents, notification)?;
+            }
+            RpcMessage::Response(_) => {}
+        }
+    }
+    Ok(())
+}
+
+fn handle_notification(
+    connection: &Connection,
+    settings: &Settings,
+    documents: &mut HashMap<Url, Document>,
+    notification: Notification,
+) -> Result<()> {
+    match notification.method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: DidOpenTextDocumentParams = cast_notification(notification)?;
+            update_document(
+                connection,
+                settings,
+                documents,
+                params.text_document.uri,
+                params.text_document.text,
+            )?;
+        }
+        DidChangeTextDocument::METHOD => {
+            let mut params: DidChangeTextDocumentParams = cast_notification(notification)?;
+            // Full sync: the last change event carries the whole document.
+            if let Some(change) = params.content_changes.pop() {
+                update_document(connection, settings, documents, params.text_document.uri, change.text)?;
+            }
+        }
+        DidSaveTextDocument::METHOD => {
+            let params: DidSaveTextDocumentParams = cast_notification(notification)?;
+            if let Some(text) = params.text {
+                update_document(connection, settings, documents, params.text_document.uri, text)?;
+            }
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: DidCloseTextDocumentParams = cast_notification(notification)?;
+            documents.remove(&params.text_document.uri);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Re-lint `uri` against its new `text`, store the result, and publish
+/// fresh diagnostics to the client.
+fn update_document(
+    connection: &Connection,
+    settings: &Settings,
+    documents: &mut HashMap<Url, Document>,
+    uri: Url,
+    text: String,
+) -> Result<()> {
+    let messages = lint_source(&text, settings)?;
+    let diagnostics = messages.iter().map(|message| to_diagnostic(message, &text)).collect();
+    documents.insert(uri.clone(), Document { text, messages });
+
+    let params = PublishDiagnosticsParams {
+        uri,
+        diagnostics,
+        version: None,
+    };
+    let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+    connection.sender.send(RpcMessage::Notification(notification))?;
+    Ok(())
+}
+
+/// Recode a 1-based, `char`-indexed row/column (Ruff's native location unit)
+/// into a 0-based-line, UTF-16-code-unit `lsp_types::Position`. Mirrors the
+/// arithmetic `message.rs` uses privately for `--column-encoding=utf16`, but
+/// reads the line out of `text` directly instead of going through
+/// `message.rs`'s on-disk `source_cache`, since these documents may be
+/// unsaved or may not exist on disk at all.
+fn to_position(text: &str, row: usize, column: usize) -> Position {
+    let line = text.lines().nth(row.saturating_sub(1)).unwrap_or("");
+    let character = line
+        .chars()
+        .take(column.saturating_sub(1))
+        .map(char::len_utf16)
+        .sum::<usize>();
+    Position::new(row.saturating_sub(1) as u32, character as u32)
+}
+
+fn to_diagnostic(message: &Message, text: &str) -> Diagnostic {
+    let range = Range::new(
+        to_position(text, message.location.row(), message.location.column()),
+        to_position(text, message.end_location.row(), message.end_location.column()),
+    );
+    Diagnostic {
+        range,
+        severity: Some(match message.severity {
+            Severity::Error => DiagnosticSeverity::ERROR,
+            Severity::Warning => DiagnosticSeverity::WARNING,
+        }),
+        code: Some(lsp_types::NumberOrString::String(
+            message.kind.code().as_ref().to_string(),
+        )),
+        source: Some("ruff".to_string()),
+        message: message.kind.body(),
+        ..Default::default()
+    }
+}
+
+/// Build the quick-fix code actions offered for `params.range`, plus a
+/// single "fix all" source action bundling every fixable message in the
+/// document. As with `fixer.rs`'s own fix application, overlapping edits
+/// aren't reconciled here — each message contributes its own `TextEdit`,
+/// and it's up to the client (or a subsequent re-lint) to surface any
+/// conflict.
+fn code_actions(
+    documents: &HashMap<Url, Document>,
+    params: &CodeActionParams,
+) -> Vec<CodeActionOrCommand> {
+    let uri = &params.text_document.uri;
+    let Some(document) = documents.get(uri) else {
+        return Vec::new();
+    };
+
+    let fixable: Vec<(&Message, Range, TextEdit)> = document
+        .messages
+        .iter()
+        .filter_map(|message| {
+            let fix = message.fix.as_ref()?;
+            let range = Range::new(
+                to_position(&document.text, fix.patch.location.row(), fix.patch.location.column()),
+                to_position(
+                    &document.text,
+                    fix.patch.end_location.row(),
+                    fix.patch.end_location.column(),
+                ),
+            );
+            Some((
+                message,
+                range,
+                TextEdit {
+                    range,
+                    new_text: fix.patch.content.clone(),
+                },
+            ))
+        })
+        .collect();
+
+    let mut actions: Vec<CodeActionOrCommand> = fixable
+        .iter()
+        .filter(|(_, range, _)| ranges_overlap(*range, params.range))
+        .map(|(message, range, edit)| quick_fix(uri, message.kind.body(), *range, edit.clone()))
+        .collect();
+
+    if !fixable.is_empty() {
+        let mut changes = HashMap::new();
+        changes.insert(
+            uri.clone(),
+            fixable.iter().map(|(_, _, edit)| edit.clone()).collect(),
+        );
+        actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+            title: "Fix all auto-fixable problems".to_string(),
+            kind: Some(CodeActionKind::SOURCE_FIX_ALL),
+            edit: Some(WorkspaceEdit {
+                changes: Some(changes),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+    }
+
+    actions
+}
+
+fn quick_fix(uri: &Url, title: String, range: Range, edit: TextEdit) -> CodeActionOrCommand {
+    let mut changes = HashMap::new();
+    changes.insert(uri.clone(), vec![edit]);
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }),
+        diagnostics: None,
+        ..Default::default()
+    })
+}
+
+fn ranges_overlap(a: Range, b: Range) -> bool {
+    position_le(a.start, b.end) && position_le(b.start, a.end)
+}
+
+fn position_le(a: Position, b: Position) -> bool {
+    (a.line, a.character) <= (b.line, b.character)
+}
+
+fn cast_request<R>(request: Request) -> Result<(RequestId, R::Params), ExtractError<Request>>
+where
+    R: lsp_types::request::Request,
+{
+    request.extract(R::METHOD)
+}
+
+fn cast_notification<N>(notification: Notification) -> Result<N::Params>
+where
+    N: lsp_types::notification::Notification,
+{
+    notification
+        .extract(N::METHOD)
+        .map_err(|err| anyhow!("malformed {} notification: {err}", N::METHOD))
+}
@@ -10,30 +10,53 @@ use path_absolutize::path_dedot;
 use regex::Regex;
 
 use crate::checks_gen::CheckCodePrefix;
+use crate::settings::options::Options;
 use crate::settings::pyproject::load_options;
-use crate::settings::types::{FilePattern, PerFileIgnore, PythonVersion};
-use crate::{flake8_annotations, flake8_bugbear, flake8_quotes, fs, isort, pep8_naming};
+use crate::settings::types::{Compat, FilePattern, PerFileIgnore, PythonVersion};
+use crate::{
+    flake8_annotations, flake8_bugbear, flake8_quotes, fs, isort, pep8_naming, pycodestyle, pylint,
+};
 
 #[derive(Debug)]
 pub struct Configuration {
+    pub allowed_confusables: Vec<char>,
+    pub builtins: Vec<String>,
+    pub cache_dir: PathBuf,
+    pub compat: Option<Compat>,
     pub dummy_variable_rgx: Regex,
     pub exclude: Vec<FilePattern>,
     pub extend_exclude: Vec<FilePattern>,
     pub extend_ignore: Vec<CheckCodePrefix>,
     pub extend_select: Vec<CheckCodePrefix>,
     pub fix: bool,
+    pub follow_symlinks: bool,
     pub ignore: Vec<CheckCodePrefix>,
+    pub ignore_init_module_imports: bool,
     pub line_length: usize,
+    pub tab_size: usize,
+    pub max_cognitive_complexity: usize,
+    pub max_file_size: u64,
+    pub max_violations_per_file: usize,
+    pub min_duplicate_lines: usize,
     pub per_file_ignores: Vec<PerFileIgnore>,
+    pub preview: bool,
     pub select: Vec<CheckCodePrefix>,
+    pub site_packages: Vec<PathBuf>,
     pub src: Vec<PathBuf>,
     pub target_version: PythonVersion,
+    /// The number of threads to lint files in parallel with. `0` leaves
+    /// Rayon's own default (the number of logical cores) in place.
+    pub threads: usize,
+    pub typing_modules: Vec<String>,
+    pub warnings: Vec<CheckCodePrefix>,
     // Plugins
     pub flake8_annotations: flake8_annotations::settings::Settings,
     pub flake8_bugbear: flake8_bugbear::settings::Settings,
     pub flake8_quotes: flake8_quotes::settings::Settings,
     pub isort: isort::settings::Settings,
     pub pep8_naming: pep8_naming::settings::Settings,
+    pub pycodestyle: pycodestyle::settings::Settings,
+    pub pylint: pylint::settings::Settings,
 }
 
 static DEFAULT_EXCLUDE: Lazy<Vec<FilePattern>> = Lazy::new(|| {
@@ -63,13 +86,46 @@ static DEFAULT_EXCLUDE: Lazy<Vec<FilePattern>> = Lazy::new(|| {
 static DEFAULT_DUMMY_VARIABLE_RGX: Lazy<Regex> =
     Lazy::new(|| Regex::new("^(_+|(_+[a-zA-Z0-9_]*[a-zA-Z0-9]+?))$").unwrap());
 
+/// 10 MiB. Generous enough for handwritten source, small enough to keep a
+/// full-repo run from parsing a stray multi-hundred-MB generated file.
+const DEFAULT_MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Generous enough that it never trips on handwritten source, but bounds how
+/// many diagnostics a single pathological (e.g. generated) file can dump into
+/// a run.
+const DEFAULT_MAX_VIOLATIONS_PER_FILE: usize = 1000;
+
+/// Matches pylint's default `min-similarity-lines`.
+const DEFAULT_MIN_DUPLICATE_LINES: usize = 4;
+
+/// The conventional terminal/editor tab stop.
+const DEFAULT_TAB_SIZE: usize = 8;
+
+/// Matches SonarSource's own default threshold for its cognitive complexity
+/// metric.
+const DEFAULT_MAX_COGNITIVE_COMPLEXITY: usize = 15;
+
 impl Configuration {
     pub fn from_pyproject(
         pyproject: &Option<PathBuf>,
         project_root: &Option<PathBuf>,
     ) -> Result<Self> {
-        let options = load_options(pyproject)?;
+        Self::from_options(load_options(pyproject)?, project_root)
+    }
+
+    /// Build a `Configuration` directly from an already-deserialized
+    /// `Options`, without reading a pyproject.toml from disk. Used by
+    /// `from_pyproject` above, and by any caller (e.g. the `wasm` module)
+    /// that sources its `Options` from somewhere other than the filesystem.
+    pub fn from_options(options: Options, project_root: &Option<PathBuf>) -> Result<Self> {
         Ok(Configuration {
+            allowed_confusables: options.allowed_confusables.unwrap_or_default(),
+            builtins: options.builtins.unwrap_or_default(),
+            cache_dir: options
+                .cache_dir
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from("./.ruff_cache")),
+            compat: options.compat,
             dummy_variable_rgx: match options.dummy_variable_rgx {
                 Some(pattern) => Regex::new(&pattern)
                     .map_err(|e| anyhow!("Invalid dummy-variable-rgx value: {e}"))?,
@@ -94,7 +150,20 @@ impl Configuration {
                         None => path_dedot::CWD.clone(),
                     }]
                 }),
+            site_packages: options
+                .site_packages
+                .unwrap_or_default()
+                .iter()
+                .map(|path| {
+                    let path = Path::new(path);
+                    match project_root {
+                        Some(project_root) => fs::normalize_path_to(path, project_root),
+                        None => fs::normalize_path(path),
+                    }
+                })
+                .collect(),
             target_version: options.target_version.unwrap_or(PythonVersion::Py310),
+            threads: options.threads.unwrap_or_default(),
             exclude: options
                 .exclude
                 .map(|paths| {
@@ -111,13 +180,29 @@ impl Configuration {
                 .map(|path| FilePattern::from_user(path, project_root))
                 .collect(),
             extend_ignore: options.extend_ignore.unwrap_or_default(),
+            preview: options.preview.unwrap_or_default(),
             select: options
                 .select
                 .unwrap_or_else(|| vec![CheckCodePrefix::E, CheckCodePrefix::F]),
             extend_select: options.extend_select.unwrap_or_default(),
             fix: options.fix.unwrap_or_default(),
+            follow_symlinks: options.follow_symlinks.unwrap_or_default(),
             ignore: options.ignore.unwrap_or_default(),
+            ignore_init_module_imports: options.ignore_init_module_imports.unwrap_or_default(),
             line_length: options.line_length.unwrap_or(88),
+            tab_size: options.tab_size.unwrap_or(DEFAULT_TAB_SIZE),
+            max_cognitive_complexity: options
+                .max_cognitive_complexity
+                .unwrap_or(DEFAULT_MAX_COGNITIVE_COMPLEXITY),
+            max_file_size: options.max_file_size.unwrap_or(DEFAULT_MAX_FILE_SIZE),
+            max_violations_per_file: options
+                .max_violations_per_file
+                .unwrap_or(DEFAULT_MAX_VIOLATIONS_PER_FILE),
+            min_duplicate_lines: options
+                .min_duplicate_lines
+                .unwrap_or(DEFAULT_MIN_DUPLICATE_LINES),
+            typing_modules: options.typing_modules.unwrap_or_default(),
+            warnings: options.warnings.unwrap_or_default(),
             per_file_ignores: options
                 .per_file_ignores
                 .map(|per_file_ignores| {
@@ -150,6 +235,148 @@ impl Configuration {
                 .pep8_naming
                 .map(pep8_naming::settings::Settings::from_options)
                 .unwrap_or_default(),
+            pycodestyle: options
+                .pycodestyle
+                .map(pycodestyle::settings::Settings::from_options)
+                .unwrap_or_default(),
+            pylint: options
+                .pylint
+                .map(pylint::settings::Settings::from_options)
+                .unwrap_or_default(),
         })
     }
+
+    /// Layer `options` (e.g. from a subdirectory's `pyproject.toml`) on top
+    /// of this configuration. A field the child sets outright replaces the
+    /// parent's value; the `extend_*` lists (and `per_file_ignores`) are
+    /// additive instead, so a nested config can only add ignores/selects on
+    /// top of what it inherits, never silently drop ones the parent already
+    /// applied. Plugin settings tables are replaced wholesale rather than
+    /// merged field-by-field, matching how a single `pyproject.toml`'s
+    /// plugin tables already work.
+    pub fn merge(mut self, options: Options, project_root: &Option<PathBuf>) -> Result<Self> {
+        if let Some(allowed_confusables) = options.allowed_confusables {
+            self.allowed_confusables = allowed_confusables;
+        }
+        if let Some(builtins) = options.builtins {
+            self.builtins = builtins;
+        }
+        if let Some(cache_dir) = options.cache_dir {
+            self.cache_dir = PathBuf::from(cache_dir);
+        }
+        if let Some(compat) = options.compat {
+            self.compat = Some(compat);
+        }
+        if let Some(pattern) = options.dummy_variable_rgx {
+            self.dummy_variable_rgx = Regex::new(&pattern)
+                .map_err(|e| anyhow!("Invalid dummy-variable-rgx value: {e}"))?;
+        }
+        if let Some(src) = options.src {
+            self.src = src
+                .iter()
+                .map(|path| {
+                    let path = Path::new(path);
+                    match project_root {
+                        Some(project_root) => fs::normalize_path_to(path, project_root),
+                        None => fs::normalize_path(path),
+                    }
+                })
+                .collect();
+        }
+        if let Some(target_version) = options.target_version {
+            self.target_version = target_version;
+        }
+        if let Some(threads) = options.threads {
+            self.threads = threads;
+        }
+        if let Some(exclude) = options.exclude {
+            self.exclude = exclude
+                .iter()
+                .map(|path| FilePattern::from_user(path, project_root))
+                .collect();
+        }
+        self.extend_exclude.extend(
+            options
+                .extend_exclude
+                .unwrap_or_default()
+                .iter()
+                .map(|path| FilePattern::from_user(path, project_root)),
+        );
+        self.extend_ignore
+            .extend(options.extend_ignore.unwrap_or_default());
+        if let Some(preview) = options.preview {
+            self.preview = preview;
+        }
+        if let Some(select) = options.select {
+            self.select = select;
+        }
+        self.extend_select
+            .extend(options.extend_select.unwrap_or_default());
+        if let Some(fix) = options.fix {
+            self.fix = fix;
+        }
+        if let Some(follow_symlinks) = options.follow_symlinks {
+            self.follow_symlinks = follow_symlinks;
+        }
+        if let Some(ignore) = options.ignore {
+            self.ignore = ignore;
+        }
+        if let Some(ignore_init_module_imports) = options.ignore_init_module_imports {
+            self.ignore_init_module_imports = ignore_init_module_imports;
+        }
+        if let Some(line_length) = options.line_length {
+            self.line_length = line_length;
+        }
+        if let Some(tab_size) = options.tab_size {
+            self.tab_size = tab_size;
+        }
+        if let Some(max_cognitive_complexity) = options.max_cognitive_complexity {
+            self.max_cognitive_complexity = max_cognitive_complexity;
+        }
+        if let Some(max_file_size) = options.max_file_size {
+            self.max_file_size = max_file_size;
+        }
+        if let Some(max_violations_per_file) = options.max_violations_per_file {
+            self.max_violations_per_file = max_violations_per_file;
+        }
+        if let Some(min_duplicate_lines) = options.min_duplicate_lines {
+            self.min_duplicate_lines = min_duplicate_lines;
+        }
+        if let Some(typing_modules) = options.typing_modules {
+            self.typing_modules = typing_modules;
+        }
+        if let Some(warnings) = options.warnings {
+            self.warnings = warnings;
+        }
+        if let Some(per_file_ignores) = options.per_file_ignores {
+            self.per_file_ignores.extend(
+                per_file_ignores
+                    .iter()
+                    .map(|(pattern, prefixes)| PerFileIgnore::new(pattern, prefixes, project_root)),
+            );
+        }
+        if let Some(sub_options) = options.flake8_annotations {
+            self.flake8_annotations =
+                flake8_annotations::settings::Settings::from_options(sub_options);
+        }
+        if let Some(sub_options) = options.flake8_bugbear {
+            self.flake8_bugbear = flake8_bugbear::settings::Settings::from_options(sub_options);
+        }
+        if let Some(sub_options) = options.flake8_quotes {
+            self.flake8_quotes = flake8_quotes::settings::Settings::from_options(sub_options);
+        }
+        if let Some(sub_options) = options.isort {
+            self.isort = isort::settings::Settings::from_options(sub_options);
+        }
+        if let Some(sub_options) = options.pep8_naming {
+            self.pep8_naming = pep8_naming::settings::Settings::from_options(sub_options);
+        }
+        if let Some(sub_options) = options.pycodestyle {
+            self.pycodestyle = pycodestyle::settings::Settings::from_options(sub_options);
+        }
+        if let Some(sub_options) = options.pylint {
+            self.pylint = pylint::settings::Settings::from_options(sub_options);
+        }
+        Ok(self)
+    }
 }
@@ -0,0 +1,163 @@
+//! Minimal `.editorconfig` support. Reads the `max_line_length` and
+//! `indent_style` properties that apply to Python files, so a monorepo with
+//! mixed per-directory conventions (but no per-directory `pyproject.toml`)
+//! still gets the right `E501` and `W191` behavior.
+//!
+//! Only covers the two properties with an existing Ruff equivalent. Other
+//! EditorConfig properties (`indent_size`, `end_of_line`, `charset`, ...)
+//! are parsed as part of the file but have no corresponding check to feed,
+//! so they're dropped rather than stored for no consumer. Section patterns
+//! are matched with `glob::Pattern`, which covers the common `[*]` and
+//! `[*.py]` cases but not the brace-expansion (`[*.{py,pyi}]`) extension
+//! some `.editorconfig` files use.
+use std::path::{Path, PathBuf};
+
+use common_path::common_path_all;
+use glob::Pattern;
+use path_absolutize::Absolutize;
+
+use crate::fs;
+use crate::settings::types::IndentStyle;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EditorConfig {
+    pub max_line_length: Option<usize>,
+    pub indent_style: Option<IndentStyle>,
+}
+
+impl EditorConfig {
+    fn merge(&mut self, other: Self) {
+        if other.max_line_length.is_some() {
+            self.max_line_length = other.max_line_length;
+        }
+        if other.indent_style.is_some() {
+            self.indent_style = other.indent_style;
+        }
+    }
+}
+
+/// Parse a single `.editorconfig` file, returning the merged properties of
+/// every section whose glob matches a representative Python filename
+/// (`[*]`, `[*.py]`, etc.), and whether this file declared `root = true`.
+fn parse_editorconfig(contents: &str) -> (EditorConfig, bool) {
+    let mut config = EditorConfig::default();
+    let mut is_root = false;
+    let mut section_applies = true;
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(pattern) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            section_applies = Pattern::new(pattern).map_or(false, |p| p.matches("example.py"));
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_lowercase();
+        let value = value.trim();
+        if key == "root" && section_applies {
+            is_root = value.eq_ignore_ascii_case("true");
+        } else if section_applies && key == "max_line_length" {
+            config.max_line_length = value.parse().ok();
+        } else if section_applies && key == "indent_style" {
+            config.indent_style = match value.to_lowercase().as_str() {
+                "space" => Some(IndentStyle::Space),
+                "tab" => Some(IndentStyle::Tab),
+                _ => None,
+            };
+        }
+    }
+    (config, is_root)
+}
+
+/// Search upward from the deepest directory common to `sources`, collecting
+/// every `.editorconfig` found, and stopping (inclusively) at the first one
+/// that declares `root = true`, or at the filesystem root otherwise. Mirrors
+/// `pyproject::find_nested_pyprojects` in spirit: a single, run-wide
+/// resolution rather than a true per-file lookup.
+fn find_editorconfigs(sources: &[PathBuf]) -> Vec<PathBuf> {
+    let absolute_sources: Vec<PathBuf> = sources
+        .iter()
+        .flat_map(|source| source.absolutize().map(|path| path.to_path_buf()))
+        .collect();
+    let Some(start) = common_path_all(absolute_sources.iter().map(PathBuf::as_path)) else {
+        return Vec::new();
+    };
+    let start = if start.is_dir() {
+        start
+    } else {
+        start.parent().map_or(start.clone(), Path::to_path_buf)
+    };
+
+    let mut found = Vec::new();
+    for directory in start.ancestors() {
+        let candidate = directory.join(".editorconfig");
+        if candidate.is_file() {
+            found.push(candidate);
+        }
+    }
+    found
+}
+
+/// Resolve the effective `.editorconfig` properties for a run over `sources`,
+/// applying the closest (innermost) directory's settings with the highest
+/// priority, same as `.editorconfig`'s own directory-nesting rules.
+pub fn resolve(sources: &[PathBuf]) -> EditorConfig {
+    let mut resolved = EditorConfig::default();
+    // `find_editorconfigs` returns innermost-first; apply outermost-first so
+    // the closest directory's settings win.
+    for path in find_editorconfigs(sources).into_iter().rev() {
+        if let Ok(contents) = fs::read_file(&path) {
+            let (config, is_root) = parse_editorconfig(&contents);
+            resolved.merge(config);
+            if is_root {
+                break;
+            }
+        }
+    }
+    resolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_editorconfig, EditorConfig};
+    use crate::settings::types::IndentStyle;
+
+    #[test]
+    fn parses_relevant_properties() {
+        let (config, is_root) = parse_editorconfig(
+            r#"
+root = true
+
+[*]
+indent_style = tab
+
+[*.py]
+max_line_length = 100
+indent_style = space
+"#,
+        );
+        assert!(is_root);
+        assert_eq!(
+            config,
+            EditorConfig {
+                max_line_length: Some(100),
+                indent_style: Some(IndentStyle::Space),
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_sections_that_dont_match_python_files() {
+        let (config, is_root) = parse_editorconfig(
+            r#"
+[*.md]
+max_line_length = 80
+"#,
+        );
+        assert!(!is_root);
+        assert_eq!(config, EditorConfig::default());
+    }
+}
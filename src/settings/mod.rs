@@ -5,17 +5,24 @@
 use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 
+use anyhow::Result;
 use fnv::FnvHashSet;
+use log::warn;
 use path_absolutize::path_dedot;
 use regex::Regex;
 
 use crate::checks::CheckCode;
 use crate::checks_gen::{CheckCodePrefix, PrefixSpecificity};
+use crate::flake8_quotes::settings::Quote;
 use crate::settings::configuration::Configuration;
-use crate::settings::types::{FilePattern, PerFileIgnore, PythonVersion};
-use crate::{flake8_annotations, flake8_bugbear, flake8_quotes, isort, pep8_naming};
+use crate::settings::options::Options;
+use crate::settings::types::{Compat, FilePattern, PerFileIgnore, PythonVersion};
+use crate::{
+    flake8_annotations, flake8_bugbear, flake8_quotes, isort, pep8_naming, pycodestyle, pylint,
+};
 
 pub mod configuration;
+pub mod editorconfig;
 pub mod options;
 pub mod pyproject;
 pub mod types;
@@ -23,126 +30,367 @@ pub mod user;
 
 #[derive(Debug)]
 pub struct Settings {
+    /// Characters that `RUF001`, `RUF002`, and `RUF003` should not flag as
+    /// ambiguous. See `Options::allowed_confusables`.
+    pub allowed_confusables: FnvHashSet<char>,
+    /// Additional names to treat as builtins, on top of the interpreter's
+    /// own. See `Options::builtins`.
+    pub builtins: Vec<String>,
     pub dummy_variable_rgx: Regex,
     pub enabled: FnvHashSet<CheckCode>,
     pub exclude: Vec<FilePattern>,
     pub extend_exclude: Vec<FilePattern>,
+    pub follow_symlinks: bool,
+    /// Whether `F401` is suppressed entirely for `__init__.py` files, rather
+    /// than reported with a fix that converts the import into an explicit
+    /// re-export. See `Options::ignore_init_module_imports`.
+    pub ignore_init_module_imports: bool,
     pub line_length: usize,
+    /// The number of columns a tab character occupies when computing line
+    /// length (`E501`) and that check's reported column. See
+    /// `Options::tab_size`.
+    pub tab_size: usize,
+    /// The maximum cognitive complexity a function may have before `RUF010`
+    /// flags it. See `Options::max_cognitive_complexity`.
+    pub max_cognitive_complexity: usize,
+    /// Files larger than this, in bytes, are skipped (and reported via
+    /// `CheckKind::IOError` if `E902` is enabled) rather than parsed.
+    pub max_file_size: u64,
+    /// Once a file accrues more violations than this, the rest are collapsed
+    /// into a single `CheckKind::TooManyViolations` (if `RUF008` is enabled).
+    pub max_violations_per_file: usize,
+    /// The minimum size, in normalized lines, of a duplicated block that
+    /// `RUF009` will flag. See `Options::min_duplicate_lines`.
+    pub min_duplicate_lines: usize,
     pub per_file_ignores: Vec<PerFileIgnore>,
+    /// Directories to search for installed third-party packages. See
+    /// `Options::site_packages`.
+    pub site_packages: Vec<PathBuf>,
     pub src: Vec<PathBuf>,
     pub target_version: PythonVersion,
+    /// Modules whose members should be treated as equivalent to `typing`'s.
+    /// See `Options::typing_modules`.
+    pub typing_modules: Vec<String>,
+    /// Codes that should be reported as warnings rather than errors: they're
+    /// still printed and still fixable, but don't contribute to a non-zero
+    /// exit code.
+    pub warnings: FnvHashSet<CheckCode>,
     // Plugins
     pub flake8_annotations: flake8_annotations::settings::Settings,
     pub flake8_bugbear: flake8_bugbear::settings::Settings,
     pub flake8_quotes: flake8_quotes::settings::Settings,
     pub isort: isort::settings::Settings,
     pub pep8_naming: pep8_naming::settings::Settings,
+    pub pycodestyle: pycodestyle::settings::Settings,
+    pub pylint: pylint::settings::Settings,
 }
 
 impl Settings {
     pub fn from_configuration(config: Configuration) -> Self {
+        let mut enabled = resolve_codes(
+            &config.select,
+            &config.extend_select,
+            &config.ignore,
+            &config.extend_ignore,
+            config.preview,
+        );
+        let mut flake8_quotes = config.flake8_quotes;
+        apply_black_compat(config.compat, &mut enabled, &mut flake8_quotes);
         Self {
+            allowed_confusables: FnvHashSet::from_iter(config.allowed_confusables),
+            builtins: config.builtins,
             dummy_variable_rgx: config.dummy_variable_rgx,
-            enabled: resolve_codes(
-                &config.select,
-                &config.extend_select,
-                &config.ignore,
-                &config.extend_ignore,
-            ),
+            enabled,
             exclude: config.exclude,
             extend_exclude: config.extend_exclude,
+            follow_symlinks: config.follow_symlinks,
+            ignore_init_module_imports: config.ignore_init_module_imports,
             flake8_annotations: config.flake8_annotations,
             flake8_bugbear: config.flake8_bugbear,
-            flake8_quotes: config.flake8_quotes,
+            flake8_quotes,
             isort: config.isort,
             line_length: config.line_length,
+            tab_size: config.tab_size,
+            max_cognitive_complexity: config.max_cognitive_complexity,
+            max_file_size: config.max_file_size,
+            max_violations_per_file: config.max_violations_per_file,
+            min_duplicate_lines: config.min_duplicate_lines,
             pep8_naming: config.pep8_naming,
+            pycodestyle: config.pycodestyle,
+            pylint: config.pylint,
             per_file_ignores: config.per_file_ignores,
+            site_packages: config.site_packages,
             src: config.src,
             target_version: config.target_version,
+            typing_modules: config.typing_modules,
+            warnings: FnvHashSet::from_iter(
+                config.warnings.iter().flat_map(CheckCodePrefix::codes),
+            ),
         }
     }
 
     pub fn for_rule(check_code: CheckCode) -> Self {
         Self {
+            allowed_confusables: Default::default(),
+            builtins: Default::default(),
             dummy_variable_rgx: Regex::new("^(_+|(_+[a-zA-Z0-9_]*[a-zA-Z0-9]+?))$").unwrap(),
             enabled: FnvHashSet::from_iter([check_code]),
             exclude: Default::default(),
             extend_exclude: Default::default(),
+            follow_symlinks: false,
+            ignore_init_module_imports: false,
             line_length: 88,
+            tab_size: 8,
+            max_cognitive_complexity: 15,
+            max_file_size: 10 * 1024 * 1024,
+            max_violations_per_file: 1000,
+            min_duplicate_lines: 4,
             per_file_ignores: Default::default(),
+            site_packages: Default::default(),
             src: vec![path_dedot::CWD.clone()],
             target_version: PythonVersion::Py310,
+            typing_modules: Default::default(),
+            warnings: Default::default(),
             flake8_annotations: Default::default(),
             flake8_bugbear: Default::default(),
             flake8_quotes: Default::default(),
             isort: Default::default(),
             pep8_naming: Default::default(),
+            pycodestyle: Default::default(),
+            pylint: Default::default(),
         }
     }
 
     pub fn for_rules(check_codes: Vec<CheckCode>) -> Self {
         Self {
+            allowed_confusables: Default::default(),
+            builtins: Default::default(),
             dummy_variable_rgx: Regex::new("^(_+|(_+[a-zA-Z0-9_]*[a-zA-Z0-9]+?))$").unwrap(),
             enabled: FnvHashSet::from_iter(check_codes),
             exclude: Default::default(),
             extend_exclude: Default::default(),
+            follow_symlinks: false,
+            ignore_init_module_imports: false,
             line_length: 88,
+            tab_size: 8,
+            max_cognitive_complexity: 15,
+            max_file_size: 10 * 1024 * 1024,
+            max_violations_per_file: 1000,
+            min_duplicate_lines: 4,
             per_file_ignores: Default::default(),
+            site_packages: Default::default(),
             src: vec![path_dedot::CWD.clone()],
             target_version: PythonVersion::Py310,
+            typing_modules: Default::default(),
+            warnings: Default::default(),
             flake8_annotations: Default::default(),
             flake8_bugbear: Default::default(),
             flake8_quotes: Default::default(),
             isort: Default::default(),
             pep8_naming: Default::default(),
+            pycodestyle: Default::default(),
+            pylint: Default::default(),
         }
     }
 }
 
+/// Builds a `Settings` for library embedders, without requiring callers to
+/// assemble a `Configuration` or point at a pyproject.toml themselves.
+#[derive(Debug, Default)]
+pub struct SettingsBuilder {
+    options: Options,
+}
+
+impl SettingsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn select(mut self, codes: Vec<CheckCodePrefix>) -> Self {
+        self.options.select = Some(codes);
+        self
+    }
+
+    pub fn allowed_confusables(mut self, confusables: Vec<char>) -> Self {
+        self.options.allowed_confusables = Some(confusables);
+        self
+    }
+
+    pub fn builtins(mut self, builtins: Vec<String>) -> Self {
+        self.options.builtins = Some(builtins);
+        self
+    }
+
+    pub fn ignore(mut self, codes: Vec<CheckCodePrefix>) -> Self {
+        self.options.ignore = Some(codes);
+        self
+    }
+
+    pub fn warnings(mut self, codes: Vec<CheckCodePrefix>) -> Self {
+        self.options.warnings = Some(codes);
+        self
+    }
+
+    pub fn line_length(mut self, line_length: usize) -> Self {
+        self.options.line_length = Some(line_length);
+        self
+    }
+
+    pub fn tab_size(mut self, tab_size: usize) -> Self {
+        self.options.tab_size = Some(tab_size);
+        self
+    }
+
+    pub fn max_cognitive_complexity(mut self, max_cognitive_complexity: usize) -> Self {
+        self.options.max_cognitive_complexity = Some(max_cognitive_complexity);
+        self
+    }
+
+    pub fn max_file_size(mut self, max_file_size: u64) -> Self {
+        self.options.max_file_size = Some(max_file_size);
+        self
+    }
+
+    pub fn max_violations_per_file(mut self, max_violations_per_file: usize) -> Self {
+        self.options.max_violations_per_file = Some(max_violations_per_file);
+        self
+    }
+
+    pub fn min_duplicate_lines(mut self, min_duplicate_lines: usize) -> Self {
+        self.options.min_duplicate_lines = Some(min_duplicate_lines);
+        self
+    }
+
+    pub fn follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.options.follow_symlinks = Some(follow_symlinks);
+        self
+    }
+
+    pub fn target_version(mut self, target_version: PythonVersion) -> Self {
+        self.options.target_version = Some(target_version);
+        self
+    }
+
+    pub fn typing_modules(mut self, typing_modules: Vec<String>) -> Self {
+        self.options.typing_modules = Some(typing_modules);
+        self
+    }
+
+    pub fn build(self) -> Result<Settings> {
+        Ok(Settings::from_configuration(Configuration::from_options(
+            self.options,
+            &None,
+        )?))
+    }
+}
+
 impl Hash for Settings {
     fn hash<H: Hasher>(&self, state: &mut H) {
         // Add base properties in alphabetical order.
+        for value in self.allowed_confusables.iter() {
+            value.hash(state);
+        }
+        self.builtins.hash(state);
         self.dummy_variable_rgx.as_str().hash(state);
         for value in self.enabled.iter() {
             value.hash(state);
         }
+        for value in self.exclude.iter() {
+            value.hash(state);
+        }
+        for value in self.extend_exclude.iter() {
+            value.hash(state);
+        }
+        self.follow_symlinks.hash(state);
+        self.ignore_init_module_imports.hash(state);
         self.line_length.hash(state);
+        self.max_cognitive_complexity.hash(state);
+        self.max_file_size.hash(state);
+        self.max_violations_per_file.hash(state);
+        self.min_duplicate_lines.hash(state);
         for value in self.per_file_ignores.iter() {
             value.hash(state);
         }
         self.target_version.hash(state);
+        self.typing_modules.hash(state);
+        for value in self.warnings.iter() {
+            value.hash(state);
+        }
         // Add plugin properties in alphabetical order.
         self.flake8_annotations.hash(state);
+        self.flake8_bugbear.hash(state);
         self.flake8_quotes.hash(state);
         self.isort.hash(state);
         self.pep8_naming.hash(state);
+        self.pycodestyle.hash(state);
+        self.pylint.hash(state);
+    }
+}
+
+/// Under `compat = "black"`, retune or disable whichever implemented rules
+/// conflict with Black's own formatting, logging each change so a user
+/// relying on `compat` can see what it did. Black enforces its own line
+/// length (with its own, different notion of an unsplittable line), so a
+/// separately-enforced `E501` just flags lines the user can't reformat
+/// without fighting their formatter; Black also always produces
+/// double-quoted strings, so a non-default `flake8-quotes` style would
+/// never actually match its output.
+fn apply_black_compat(
+    compat: Option<Compat>,
+    enabled: &mut FnvHashSet<CheckCode>,
+    flake8_quotes: &mut flake8_quotes::settings::Settings,
+) {
+    let Some(Compat::Black) = compat else {
+        return;
+    };
+    if enabled.remove(&CheckCode::E501) {
+        warn!("compat = \"black\": disabled E501 (line-too-long), since Black enforces its own line length");
+    }
+    for (name, quotes) in [
+        ("inline-quotes", &mut flake8_quotes.inline_quotes),
+        ("multiline-quotes", &mut flake8_quotes.multiline_quotes),
+        ("docstring-quotes", &mut flake8_quotes.docstring_quotes),
+    ] {
+        if *quotes != Quote::Double {
+            *quotes = Quote::Double;
+            warn!(
+                "compat = \"black\": set flake8-quotes.{name} to \"double\", since Black always produces double-quoted strings"
+            );
+        }
     }
 }
 
 /// Given a set of selected and ignored prefixes, resolve the set of enabled
-/// error codes.
+/// error codes. `preview` controls whether codes still under active
+/// development are included when enabled via `CheckCodePrefix::All`; more
+/// specific selects can still opt into a preview code explicitly regardless
+/// of `preview`, since `All` is the least specific tier.
 fn resolve_codes(
     select: &[CheckCodePrefix],
     extend_select: &[CheckCodePrefix],
     ignore: &[CheckCodePrefix],
     extend_ignore: &[CheckCodePrefix],
+    preview: bool,
 ) -> FnvHashSet<CheckCode> {
     let mut codes: FnvHashSet<CheckCode> = FnvHashSet::default();
     for specificity in [
+        PrefixSpecificity::All,
         PrefixSpecificity::Category,
         PrefixSpecificity::Hundreds,
         PrefixSpecificity::Tens,
         PrefixSpecificity::Explicit,
     ] {
+        let allow_preview = |code: &CheckCode| {
+            preview || specificity != PrefixSpecificity::All || !code.is_preview()
+        };
         for prefix in select {
             if prefix.specificity() == specificity {
-                codes.extend(prefix.codes());
+                codes.extend(prefix.codes().into_iter().filter(allow_preview));
             }
         }
         for prefix in extend_select {
             if prefix.specificity() == specificity {
-                codes.extend(prefix.codes());
+                codes.extend(prefix.codes().into_iter().filter(allow_preview));
             }
         }
         for prefix in ignore {
@@ -173,20 +421,43 @@ mod tests {
 
     #[test]
     fn resolver() {
-        let actual = resolve_codes(&[CheckCodePrefix::W], &[], &[], &[]);
-        let expected = FnvHashSet::from_iter([CheckCode::W292, CheckCode::W605]);
+        let actual = resolve_codes(&[CheckCodePrefix::W], &[], &[], &[], false);
+        let expected =
+            FnvHashSet::from_iter([CheckCode::W292, CheckCode::W505, CheckCode::W605]);
         assert_eq!(actual, expected);
 
-        let actual = resolve_codes(&[CheckCodePrefix::W6], &[], &[], &[]);
+        let actual = resolve_codes(&[CheckCodePrefix::W6], &[], &[], &[], false);
         let expected = FnvHashSet::from_iter([CheckCode::W605]);
         assert_eq!(actual, expected);
 
-        let actual = resolve_codes(&[CheckCodePrefix::W], &[], &[CheckCodePrefix::W292], &[]);
+        let actual = resolve_codes(
+            &[CheckCodePrefix::W],
+            &[],
+            &[CheckCodePrefix::W292, CheckCodePrefix::W505],
+            &[],
+            false,
+        );
         let expected = FnvHashSet::from_iter([CheckCode::W605]);
         assert_eq!(actual, expected);
 
-        let actual = resolve_codes(&[CheckCodePrefix::W605], &[], &[CheckCodePrefix::W605], &[]);
+        let actual = resolve_codes(
+            &[CheckCodePrefix::W605],
+            &[],
+            &[CheckCodePrefix::W605],
+            &[],
+            false,
+        );
         let expected = FnvHashSet::from_iter([]);
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn resolver_all_excludes_preview_codes_by_default() {
+        let actual = resolve_codes(&[CheckCodePrefix::All], &[], &[], &[], false);
+        assert!(!actual.contains(&CheckCode::RUF001));
+        assert!(actual.contains(&CheckCode::E501));
+
+        let actual = resolve_codes(&[CheckCodePrefix::All], &[], &[], &[], true);
+        assert!(actual.contains(&CheckCode::RUF001));
+    }
 }
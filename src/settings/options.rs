@@ -2,32 +2,135 @@
 
 use std::collections::BTreeMap;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::checks_gen::CheckCodePrefix;
-use crate::settings::types::PythonVersion;
-use crate::{flake8_annotations, flake8_bugbear, flake8_quotes, isort, pep8_naming};
+use crate::settings::types::{deserialize_check_code_prefixes, Compat, PythonVersion};
+use crate::{
+    flake8_annotations, flake8_bugbear, flake8_quotes, isort, pep8_naming, pycodestyle, pylint,
+};
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 pub struct Options {
+    /// A list of Unicode characters that `RUF001`, `RUF002`, and `RUF003`
+    /// should not flag as ambiguous, even though they're confusable with an
+    /// ASCII character (e.g. a Cyrillic name intentionally embedded in a
+    /// string literal).
+    pub allowed_confusables: Option<Vec<char>>,
+    /// A list of additional names to treat as builtins, on top of the
+    /// interpreter's own (e.g. globals injected by `gettext`, pytest
+    /// fixtures, or Airflow macros). Consulted by undefined-name (`F821`)
+    /// so these names aren't flagged as undefined, and by builtin-shadowing
+    /// (`A001`, `A002`, `A003`) so redefining one of them is still caught.
+    pub builtins: Option<Vec<String>>,
+    /// The directory in which to write cached lint results, relative to the
+    /// current working directory.
+    pub cache_dir: Option<String>,
+    /// A formatter to avoid fighting. Currently only `"black"` is supported:
+    /// it disables or retunes whichever implemented rules conflict with
+    /// Black's own output (e.g. `E501`, and `flake8-quotes`'s quote-style
+    /// settings), and logs each change it makes.
+    pub compat: Option<Compat>,
+    /// A regular expression used to identify "dummy" variables, or those which
+    /// should be ignored when enforcing (e.g.) unused-variable rules.
     pub dummy_variable_rgx: Option<String>,
+    /// A list of file patterns to exclude from linting.
     pub exclude: Option<Vec<String>>,
+    /// A list of file patterns to exclude from linting, in addition to those
+    /// specified by `exclude`.
     pub extend_exclude: Option<Vec<String>>,
+    /// A list of check code prefixes to ignore, in addition to those specified
+    /// by `ignore`.
+    #[serde(default, deserialize_with = "deserialize_check_code_prefixes")]
     pub extend_ignore: Option<Vec<CheckCodePrefix>>,
+    /// A list of check code prefixes to enable, in addition to those specified
+    /// by `select`.
+    #[serde(default, deserialize_with = "deserialize_check_code_prefixes")]
     pub extend_select: Option<Vec<CheckCodePrefix>>,
+    /// Whether to automatically fix lint violations.
     pub fix: Option<bool>,
+    /// Whether to follow symlinks when discovering files to check. Off by
+    /// default, since following symlinked directories can pull in files
+    /// well outside the project (or, without the walker's own cycle
+    /// detection, loop forever on a symlink cycle).
+    pub follow_symlinks: Option<bool>,
+    /// A list of check code prefixes to ignore.
+    #[serde(default, deserialize_with = "deserialize_check_code_prefixes")]
     pub ignore: Option<Vec<CheckCodePrefix>>,
+    /// Whether to suppress `F401` (unused import) entirely for `__init__.py`
+    /// files, rather than reporting it with a fix that converts the import
+    /// into an explicit re-export. Some projects prefer `__init__.py` to
+    /// stay free of re-export boilerplate and rely on `__all__` (or nothing)
+    /// to declare their public API instead.
+    pub ignore_init_module_imports: Option<bool>,
+    /// The line length to use when enforcing line-length violations.
     pub line_length: Option<usize>,
+    /// The number of columns a tab character is treated as occupying when
+    /// computing line length (`E501`) and that check's reported column. Files
+    /// that indent with tabs would otherwise have every tab counted as a
+    /// single column, understating how wide the line actually renders.
+    pub tab_size: Option<usize>,
+    /// The maximum cognitive complexity (nesting-weighted, per SonarSource's
+    /// metric) a function may have before `RUF010` flags it. Teams that find
+    /// cyclomatic complexity penalizes flat, early-return code unfairly often
+    /// prefer gating on this instead.
+    pub max_cognitive_complexity: Option<usize>,
+    /// The minimum number of consecutive, normalized source lines a
+    /// duplicated block must span before `RUF009` flags it. A lower value
+    /// catches smaller copy-pasted snippets at the cost of more false
+    /// positives on boilerplate.
+    pub min_duplicate_lines: Option<usize>,
+    /// The maximum size, in bytes, of a file Ruff will parse. Larger files
+    /// (e.g. generated code) are skipped with a warning rather than parsed,
+    /// to avoid exhausting memory on multi-hundred-MB inputs.
+    pub max_file_size: Option<u64>,
+    /// The maximum number of violations Ruff will report for a single file.
+    /// Once exceeded, the remainder are collapsed into a single `RUF008`
+    /// summarizing how many were suppressed, so a generated file riddled
+    /// with (say) `E501`s doesn't flood the terminal or inflate memory use.
+    pub max_violations_per_file: Option<usize>,
+    /// Whether to enable rules that are still under active development and
+    /// selected via `"ALL"`. Has no effect on rules enabled explicitly or via
+    /// a more specific prefix.
+    pub preview: Option<bool>,
+    /// A list of check code prefixes to enable.
+    #[serde(default, deserialize_with = "deserialize_check_code_prefixes")]
     pub select: Option<Vec<CheckCodePrefix>>,
+    /// A list of directories in which to search for installed third-party
+    /// packages (e.g. a virtualenv's `site-packages`), consulted by
+    /// `RUF007` to distinguish an installed-but-unresolved import from a
+    /// typo'd module name.
+    pub site_packages: Option<Vec<String>>,
+    /// The source code paths to consider, e.g., when resolving first- vs.
+    /// third-party imports.
     pub src: Option<Vec<String>>,
+    /// The minimum Python version that should be supported.
     pub target_version: Option<PythonVersion>,
+    /// The number of threads to lint files in parallel with. Defaults to the
+    /// number of logical cores; set to `1` to force deterministic sequential
+    /// linting.
+    pub threads: Option<usize>,
+    /// A list of modules whose members should be treated as equivalent to
+    /// members of `typing` (e.g. a project's own `compat.typing` shim that
+    /// re-exports `typing.Optional`, `typing.Union`, etc., for older Python
+    /// support). Consulted by the PEP 585/604 upgrade rules, `ANN401`, and
+    /// other checks that special-case `typing` members.
+    pub typing_modules: Option<Vec<String>>,
+    /// A list of check code prefixes to report as warnings rather than
+    /// errors. Warnings are still printed and still fixable, but don't cause
+    /// a non-zero exit code.
+    #[serde(default, deserialize_with = "deserialize_check_code_prefixes")]
+    pub warnings: Option<Vec<CheckCodePrefix>>,
     // Plugins
     pub flake8_annotations: Option<flake8_annotations::settings::Options>,
     pub flake8_bugbear: Option<flake8_bugbear::settings::Options>,
     pub flake8_quotes: Option<flake8_quotes::settings::Options>,
     pub isort: Option<isort::settings::Options>,
     pub pep8_naming: Option<pep8_naming::settings::Options>,
+    pub pycodestyle: Option<pycodestyle::settings::Options>,
+    pub pylint: Option<pylint::settings::Options>,
     // Tables are required to go last.
     pub per_file_ignores: Option<BTreeMap<String, Vec<CheckCodePrefix>>>,
 }
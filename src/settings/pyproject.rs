@@ -10,15 +10,24 @@ use serde::{Deserialize, Serialize};
 
 use crate::fs;
 use crate::settings::options::Options;
+use crate::settings::types::PythonVersion;
 
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 struct Tools {
     ruff: Option<Options>,
 }
 
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Project {
+    #[serde(rename = "requires-python")]
+    requires_python: Option<String>,
+}
+
 #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Pyproject {
     tool: Option<Tools>,
+    #[serde(default)]
+    project: Option<Project>,
 }
 
 impl Pyproject {
@@ -27,10 +36,42 @@ impl Pyproject {
             tool: Some(Tools {
                 ruff: Some(options),
             }),
+            project: None,
         }
     }
 }
 
+/// Infer a minimum `target-version` from a PEP 621 `[project.requires-python]`
+/// specifier (e.g. `">=3.8"`), so that projects that already declare their
+/// supported Python versions don't need to duplicate that in `[tool.ruff]`.
+///
+/// Only a leading `>=` lower-bound clause is honored, since that's the only
+/// clause that implies a *minimum* version; upper bounds, exclusions, and
+/// other operators don't.
+fn target_version_from_requires_python(requires_python: &str) -> Option<PythonVersion> {
+    let lower_bound = requires_python
+        .split(',')
+        .map(str::trim)
+        .find_map(|clause| clause.strip_prefix(">="))?;
+
+    let mut parts = lower_bound.trim().splitn(2, '.');
+    let major: u32 = parts.next()?.parse().ok()?;
+    let minor: u32 = parts.next().unwrap_or("0").parse().ok()?;
+
+    match (major, minor) {
+        (3, 3) => Some(PythonVersion::Py33),
+        (3, 4) => Some(PythonVersion::Py34),
+        (3, 5) => Some(PythonVersion::Py35),
+        (3, 6) => Some(PythonVersion::Py36),
+        (3, 7) => Some(PythonVersion::Py37),
+        (3, 8) => Some(PythonVersion::Py38),
+        (3, 9) => Some(PythonVersion::Py39),
+        (3, 10) => Some(PythonVersion::Py310),
+        (3, minor) if minor >= 11 => Some(PythonVersion::Py311),
+        _ => None,
+    }
+}
+
 fn parse_pyproject_toml(path: &Path) -> Result<Pyproject> {
     let contents = fs::read_file(path)?;
     toml::from_str(&contents).map_err(|e| e.into())
@@ -80,12 +121,49 @@ pub fn find_project_root(sources: &[PathBuf]) -> Option<PathBuf> {
     None
 }
 
+/// Collect any `pyproject.toml` files strictly between `project_root` and the
+/// deepest directory common to `sources`, ordered from outermost (closest to
+/// `project_root`) to innermost, so a caller can layer them onto the root
+/// configuration in that order and let the deepest override win. `project_root`
+/// itself is excluded, since its `pyproject.toml` is already loaded as the
+/// base configuration.
+pub fn find_nested_pyprojects(project_root: &Path, sources: &[PathBuf]) -> Vec<PathBuf> {
+    let absolute_sources: Vec<PathBuf> = sources
+        .iter()
+        .flat_map(|source| source.absolutize().map(|path| path.to_path_buf()))
+        .collect();
+    let Some(target) = common_path_all(absolute_sources.iter().map(PathBuf::as_path)) else {
+        return Vec::new();
+    };
+
+    let mut found: Vec<PathBuf> = target
+        .ancestors()
+        .take_while(|directory| *directory != project_root)
+        .filter(|directory| directory.starts_with(project_root))
+        .filter_map(|directory| {
+            let candidate = directory.join("pyproject.toml");
+            candidate.is_file().then_some(candidate)
+        })
+        .collect();
+    found.reverse();
+    found
+}
+
 pub fn load_options(pyproject: &Option<PathBuf>) -> Result<Options> {
     match pyproject {
-        Some(pyproject) => Ok(parse_pyproject_toml(pyproject)?
-            .tool
-            .and_then(|tool| tool.ruff)
-            .unwrap_or_default()),
+        Some(pyproject) => {
+            let pyproject = parse_pyproject_toml(pyproject)?;
+            let mut options = pyproject.tool.and_then(|tool| tool.ruff).unwrap_or_default();
+            if options.target_version.is_none() {
+                options.target_version = pyproject
+                    .project
+                    .and_then(|project| project.requires_python)
+                    .and_then(|requires_python| {
+                        target_version_from_requires_python(&requires_python)
+                    });
+            }
+            Ok(options)
+        }
         None => {
             debug!("No pyproject.toml found.");
             debug!("Falling back to default configuration...");
@@ -106,11 +184,30 @@ mod tests {
     use crate::checks_gen::CheckCodePrefix;
     use crate::flake8_quotes::settings::Quote;
     use crate::settings::pyproject::{
-        find_project_root, find_pyproject_toml, parse_pyproject_toml, Options, Pyproject, Tools,
+        find_nested_pyprojects, find_project_root, find_pyproject_toml, parse_pyproject_toml,
+        target_version_from_requires_python, Options, Pyproject, Tools,
     };
-    use crate::settings::types::PatternPrefixPair;
+    use crate::settings::types::{PatternPrefixPair, PythonVersion};
     use crate::{flake8_bugbear, flake8_quotes, pep8_naming};
 
+    #[test]
+    fn requires_python() {
+        assert_eq!(
+            target_version_from_requires_python(">=3.8"),
+            Some(PythonVersion::Py38)
+        );
+        assert_eq!(
+            target_version_from_requires_python(">=3.10,<4"),
+            Some(PythonVersion::Py310)
+        );
+        assert_eq!(
+            target_version_from_requires_python(">=3.7"),
+            Some(PythonVersion::Py37)
+        );
+        assert_eq!(target_version_from_requires_python("<4"), None);
+        assert_eq!(target_version_from_requires_python("~=3.9"), None);
+    }
+
     #[test]
     fn deserialize() -> Result<()> {
         let pyproject: Pyproject = toml::from_str(r#""#)?;
@@ -133,8 +230,13 @@ mod tests {
             pyproject.tool,
             Some(Tools {
                 ruff: Some(Options {
+                    allowed_confusables: None,
+                    builtins: None,
                     line_length: None,
+                    tab_size: None,
                     fix: None,
+                    follow_symlinks: None,
+                    ignore_init_module_imports: None,
                     exclude: None,
                     extend_exclude: None,
                     select: None,
@@ -143,13 +245,24 @@ mod tests {
                     extend_ignore: None,
                     per_file_ignores: None,
                     dummy_variable_rgx: None,
+                    site_packages: None,
                     src: None,
                     target_version: None,
+                    typing_modules: None,
                     flake8_annotations: None,
                     flake8_bugbear: None,
                     flake8_quotes: None,
                     isort: None,
                     pep8_naming: None,
+                    pycodestyle: None,
+                    pylint: None,
+                    cache_dir: None,
+                    preview: None,
+                    warnings: None,
+                    max_cognitive_complexity: None,
+                    max_file_size: None,
+                    max_violations_per_file: None,
+                    min_duplicate_lines: None,
                 })
             })
         );
@@ -165,8 +278,13 @@ line-length = 79
             pyproject.tool,
             Some(Tools {
                 ruff: Some(Options {
+                    allowed_confusables: None,
+                    builtins: None,
                     line_length: Some(79),
+                    tab_size: None,
                     fix: None,
+                    follow_symlinks: None,
+                    ignore_init_module_imports: None,
                     exclude: None,
                     extend_exclude: None,
                     select: None,
@@ -175,13 +293,24 @@ line-length = 79
                     extend_ignore: None,
                     per_file_ignores: None,
                     dummy_variable_rgx: None,
+                    site_packages: None,
                     src: None,
                     target_version: None,
+                    typing_modules: None,
                     flake8_annotations: None,
                     flake8_bugbear: None,
                     flake8_quotes: None,
                     isort: None,
                     pep8_naming: None,
+                    pycodestyle: None,
+                    pylint: None,
+                    cache_dir: None,
+                    preview: None,
+                    warnings: None,
+                    max_cognitive_complexity: None,
+                    max_file_size: None,
+                    max_violations_per_file: None,
+                    min_duplicate_lines: None,
                 })
             })
         );
@@ -197,8 +326,13 @@ exclude = ["foo.py"]
             pyproject.tool,
             Some(Tools {
                 ruff: Some(Options {
+                    allowed_confusables: None,
+                    builtins: None,
                     line_length: None,
+                    tab_size: None,
                     fix: None,
+                    follow_symlinks: None,
+                    ignore_init_module_imports: None,
                     exclude: Some(vec!["foo.py".to_string()]),
                     extend_exclude: None,
                     select: None,
@@ -207,13 +341,24 @@ exclude = ["foo.py"]
                     extend_ignore: None,
                     per_file_ignores: None,
                     dummy_variable_rgx: None,
+                    site_packages: None,
                     src: None,
                     target_version: None,
+                    typing_modules: None,
                     flake8_annotations: None,
                     flake8_bugbear: None,
                     flake8_quotes: None,
                     isort: None,
                     pep8_naming: None,
+                    pycodestyle: None,
+                    pylint: None,
+                    cache_dir: None,
+                    preview: None,
+                    warnings: None,
+                    max_cognitive_complexity: None,
+                    max_file_size: None,
+                    max_violations_per_file: None,
+                    min_duplicate_lines: None,
                 })
             })
         );
@@ -229,8 +374,13 @@ select = ["E501"]
             pyproject.tool,
             Some(Tools {
                 ruff: Some(Options {
+                    allowed_confusables: None,
+                    builtins: None,
                     line_length: None,
+                    tab_size: None,
                     fix: None,
+                    follow_symlinks: None,
+                    ignore_init_module_imports: None,
                     exclude: None,
                     extend_exclude: None,
                     select: Some(vec![CheckCodePrefix::E501]),
@@ -239,13 +389,24 @@ select = ["E501"]
                     extend_ignore: None,
                     per_file_ignores: None,
                     dummy_variable_rgx: None,
+                    site_packages: None,
                     src: None,
                     target_version: None,
+                    typing_modules: None,
                     flake8_annotations: None,
                     flake8_bugbear: None,
                     flake8_quotes: None,
                     isort: None,
                     pep8_naming: None,
+                    pycodestyle: None,
+                    pylint: None,
+                    cache_dir: None,
+                    preview: None,
+                    warnings: None,
+                    max_cognitive_complexity: None,
+                    max_file_size: None,
+                    max_violations_per_file: None,
+                    min_duplicate_lines: None,
                 })
             })
         );
@@ -254,7 +415,7 @@ select = ["E501"]
             r#"
 [tool.black]
 [tool.ruff]
-extend-select = ["M001"]
+extend-select = ["RUF100"]
 ignore = ["E501"]
 "#,
         )?;
@@ -262,23 +423,39 @@ ignore = ["E501"]
             pyproject.tool,
             Some(Tools {
                 ruff: Some(Options {
+                    allowed_confusables: None,
+                    builtins: None,
                     line_length: None,
+                    tab_size: None,
                     fix: None,
+                    follow_symlinks: None,
+                    ignore_init_module_imports: None,
                     exclude: None,
                     extend_exclude: None,
                     select: None,
-                    extend_select: Some(vec![CheckCodePrefix::M001]),
+                    extend_select: Some(vec![CheckCodePrefix::RUF100]),
                     ignore: Some(vec![CheckCodePrefix::E501]),
                     extend_ignore: None,
                     per_file_ignores: None,
                     dummy_variable_rgx: None,
+                    site_packages: None,
                     src: None,
                     target_version: None,
+                    typing_modules: None,
                     flake8_annotations: None,
                     flake8_bugbear: None,
                     flake8_quotes: None,
                     isort: None,
                     pep8_naming: None,
+                    pycodestyle: None,
+                    pylint: None,
+                    cache_dir: None,
+                    preview: None,
+                    warnings: None,
+                    max_cognitive_complexity: None,
+                    max_file_size: None,
+                    max_violations_per_file: None,
+                    min_duplicate_lines: None,
                 })
             })
         );
@@ -334,8 +511,13 @@ other-attribute = 1
         assert_eq!(
             config,
             Options {
+                allowed_confusables: None,
+                builtins: None,
                 line_length: Some(88),
+                tab_size: None,
                 fix: None,
+                follow_symlinks: None,
+                ignore_init_module_imports: None,
                 exclude: None,
                 extend_exclude: Some(vec![
                     "excluded_file.py".to_string(),
@@ -351,8 +533,10 @@ other-attribute = 1
                     vec![CheckCodePrefix::F401]
                 ),])),
                 dummy_variable_rgx: None,
+                site_packages: None,
                 src: None,
                 target_version: None,
+                typing_modules: None,
                 flake8_annotations: None,
                 flake8_bugbear: Some(flake8_bugbear::settings::Options {
                     extend_immutable_calls: Some(vec![
@@ -385,12 +569,48 @@ other-attribute = 1
                     classmethod_decorators: Some(vec!["classmethod".to_string()]),
                     staticmethod_decorators: Some(vec!["staticmethod".to_string()]),
                 }),
+                pycodestyle: None,
+                pylint: None,
+                cache_dir: None,
+                preview: None,
+                warnings: None,
+                max_cognitive_complexity: None,
+                    max_file_size: None,
+                max_violations_per_file: None,
+                min_duplicate_lines: None,
             }
         );
 
         Ok(())
     }
 
+    #[test]
+    fn nested_pyprojects() -> Result<()> {
+        let cwd = current_dir()?;
+        let project_root = cwd.join("resources/test/fixtures/nested_pyproject");
+
+        let nested = find_nested_pyprojects(
+            &project_root,
+            &[PathBuf::from(
+                "resources/test/fixtures/nested_pyproject/tests/example.py",
+            )],
+        );
+        assert_eq!(
+            nested,
+            vec![project_root.join("tests").join("pyproject.toml")]
+        );
+
+        let nested = find_nested_pyprojects(
+            &project_root,
+            &[PathBuf::from(
+                "resources/test/fixtures/nested_pyproject/example.py",
+            )],
+        );
+        assert_eq!(nested, Vec::<PathBuf>::new());
+
+        Ok(())
+    }
+
     #[test]
     fn str_check_code_pair_strings() {
         let result = PatternPrefixPair::from_str("foo:E501");
@@ -2,16 +2,108 @@ use std::collections::BTreeSet;
 use std::hash::Hash;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Mutex;
 
 use anyhow::{anyhow, Result};
 use glob::Pattern;
+use log::warn;
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
 use serde::{de, Deserialize, Deserializer, Serialize};
+use strum::IntoEnumIterator;
 
 use crate::checks::CheckCode;
 use crate::checks_gen::CheckCodePrefix;
 use crate::fs;
 
-#[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Eq, Serialize, Deserialize, Hash)]
+/// Old, renamed, or flake8-plugin-native prefixes that should still resolve
+/// to their current `CheckCodePrefix` equivalent (with a warning), rather
+/// than erroring out or silently selecting nothing.
+static PREFIX_REDIRECTS: &[(&str, &str)] = &[];
+
+/// Codes already warned about as unknown this run, so a typo or
+/// unimplemented plugin code repeated across `select`, `ignore`, and
+/// multiple merged `pyproject.toml` files is only reported once.
+static WARNED_UNKNOWN_CODES: Lazy<Mutex<BTreeSet<String>>> =
+    Lazy::new(|| Mutex::new(BTreeSet::new()));
+
+/// Parse a `CheckCodePrefix`, redirecting any prefix in `PREFIX_REDIRECTS` to
+/// its current equivalent and warning about the rename, and falling back to
+/// matching `code` against a rule's human-readable name (e.g.
+/// `unused-import` for `F401`) if it doesn't match a code prefix directly.
+pub fn parse_check_code_prefix(code: &str) -> Result<CheckCodePrefix, strum::ParseError> {
+    if let Some((_, target)) = PREFIX_REDIRECTS.iter().find(|(old, _)| *old == code) {
+        warn!("{code} has been renamed to {target}; please update your configuration");
+        return CheckCodePrefix::from_str(target);
+    }
+    CheckCodePrefix::from_str(code).or_else(|err| resolve_rule_name(code).ok_or(err))
+}
+
+/// Resolve `name` (e.g. `unused-import`) to the `CheckCodePrefix` for the
+/// single rule it names, so codes aren't the only way to select or ignore a
+/// rule on the command line or in `pyproject.toml`.
+fn resolve_rule_name(name: &str) -> Option<CheckCodePrefix> {
+    CheckCode::iter()
+        .find(|check_code| to_rule_name(check_code.kind().as_ref()) == name)
+        .and_then(|check_code| CheckCodePrefix::from_str(check_code.as_ref()).ok())
+}
+
+/// Convert a PascalCase `CheckKind` variant name (e.g. `UnusedImport`) to the
+/// kebab-case rule name used for lookups (e.g. `unused-import`). A run of
+/// capitals followed by a lowercase letter is treated as an acronym boundary
+/// (e.g. `IOError` -> `io-error`), rather than one dash per capital.
+fn to_rule_name(variant: &str) -> String {
+    let chars: Vec<char> = variant.chars().collect();
+    let mut name = String::with_capacity(variant.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev_is_lower = chars[i - 1].is_lowercase();
+            let prev_is_upper_before_lower = chars[i - 1].is_uppercase()
+                && chars.get(i + 1).map_or(false, |c| c.is_lowercase());
+            if prev_is_lower || prev_is_upper_before_lower {
+                name.push('-');
+            }
+        }
+        name.extend(c.to_lowercase());
+    }
+    name
+}
+
+/// Warn, at most once per run, that `code` matches no known or implemented
+/// rule (e.g. a typo, or a flake8 plugin ruff hasn't implemented).
+fn warn_unknown_code_once(code: &str) {
+    let mut warned = WARNED_UNKNOWN_CODES.lock().unwrap();
+    if warned.insert(code.to_string()) {
+        warn!("{code} does not match any known or implemented rule; ignoring");
+    }
+}
+
+/// Deserialize a list of check code prefixes, applying the same redirects as
+/// `parse_check_code_prefix` (e.g. for prefixes selected via pyproject.toml).
+/// Codes that don't resolve to a known rule are warned about and dropped,
+/// rather than failing the whole configuration, so a stale or typo'd entry
+/// doesn't block every other selection in the file.
+pub fn deserialize_check_code_prefixes<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<CheckCodePrefix>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let codes = Vec::<String>::deserialize(deserializer)?;
+    let prefixes = codes
+        .iter()
+        .filter_map(|code| match parse_check_code_prefix(code) {
+            Ok(prefix) => Some(prefix),
+            Err(_) => {
+                warn_unknown_code_once(code);
+                None
+            }
+        })
+        .collect();
+    Ok(Some(prefixes))
+}
+
+#[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Eq, Serialize, Deserialize, Hash, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum PythonVersion {
     Py33,
@@ -44,6 +136,34 @@ impl FromStr for PythonVersion {
     }
 }
 
+/// A formatter whose output Ruff should avoid fighting, by retuning or
+/// disabling whichever implemented rules conflict with it. Currently only
+/// `black` is supported.
+#[derive(Clone, Copy, Debug, PartialOrd, PartialEq, Eq, Serialize, Deserialize, Hash, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Compat {
+    Black,
+}
+
+impl FromStr for Compat {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "black" => Ok(Compat::Black),
+            _ => Err(anyhow!("Unknown compat target: {}", string)),
+        }
+    }
+}
+
+/// The indentation style declared for a directory by an `.editorconfig`
+/// file's `indent_style` property. See `editorconfig::resolve`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IndentStyle {
+    Space,
+    Tab,
+}
+
 #[derive(Debug, Clone, Hash)]
 pub enum FilePattern {
     Simple(&'static str),
@@ -124,7 +244,7 @@ impl FromStr for PatternPrefixPair {
             (tokens[0].trim(), tokens[1].trim())
         };
         let pattern = pattern_str.into();
-        let prefix = CheckCodePrefix::from_str(code_string)?;
+        let prefix = parse_check_code_prefix(code_string)?;
         Ok(Self { pattern, prefix })
     }
 }
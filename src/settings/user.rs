@@ -44,9 +44,11 @@ pub struct UserConfiguration {
     pub ignore: Vec<CheckCodePrefix>,
     pub line_length: usize,
     pub per_file_ignores: Vec<(Exclusion, Vec<CheckCode>)>,
+    pub preview: bool,
     pub select: Vec<CheckCodePrefix>,
     pub src: Vec<PathBuf>,
     pub target_version: PythonVersion,
+    pub warnings: Vec<CheckCodePrefix>,
     // Plugins
     pub flake8_annotations: flake8_annotations::settings::Settings,
     pub flake8_quotes: flake8_quotes::settings::Settings,
@@ -90,9 +92,11 @@ impl UserConfiguration {
                     )
                 })
                 .collect(),
+            preview: configuration.preview,
             select: configuration.select,
             src: configuration.src,
             target_version: configuration.target_version,
+            warnings: configuration.warnings,
             flake8_annotations: configuration.flake8_annotations,
             flake8_quotes: configuration.flake8_quotes,
             isort: configuration.isort,
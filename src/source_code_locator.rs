@@ -31,6 +31,20 @@ impl<'a> SourceCodeLocator<'a> {
         Cow::from(rope.slice(offset..))
     }
 
+    /// The location of the end of the (1-based) `row`th line, excluding any
+    /// trailing newline. Useful as an approximate `end_location` for
+    /// diagnostics (e.g. a syntax error) that only have a precise start
+    /// position to work from.
+    pub fn line_end(&self, row: usize) -> Location {
+        let rope = self.get_or_init_rope();
+        let line = rope.line(row - 1);
+        let mut len = line.len_chars();
+        while len > 0 && matches!(line.char(len - 1), '\n' | '\r') {
+            len -= 1;
+        }
+        Location::new(row, len)
+    }
+
     pub fn slice_source_code_range(&self, range: &Range) -> Cow<'_, str> {
         let rope = self.get_or_init_rope();
         let start = rope.line_to_char(range.location.row() - 1) + range.location.column();
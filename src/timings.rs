@@ -0,0 +1,31 @@
+//! Aggregate wall-time instrumentation for `--timings`.
+//!
+//! Most rules are dispatched inline from a single AST visitor pass rather
+//! than as independently callable units, so the finest granularity that can
+//! be measured without instrumenting every call site is the `LintSource`
+//! phase a rule belongs to (tokens, AST, imports, or lines). Recording is a
+//! no-op unless a caller opts in by calling `record`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+
+use crate::checks::LintSource;
+
+static TOTALS: Lazy<Mutex<HashMap<LintSource, Duration>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Add `duration` to the running total for `source`.
+pub fn record(source: LintSource, duration: Duration) {
+    let mut totals = TOTALS.lock().unwrap();
+    *totals.entry(source).or_insert_with(Duration::default) += duration;
+}
+
+/// Return the recorded totals, sorted by descending wall time.
+pub fn totals() -> Vec<(LintSource, Duration)> {
+    let totals = TOTALS.lock().unwrap();
+    let mut totals: Vec<_> = totals.iter().map(|(&source, &duration)| (source, duration)).collect();
+    totals.sort_by(|a, b| b.1.cmp(&a.1));
+    totals
+}
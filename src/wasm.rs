@@ -0,0 +1,26 @@
+//! A filesystem-free, JSON-in/JSON-out entry point compiled to
+//! `wasm32-unknown-unknown` (e.g. for a browser-based playground), with a
+//! `wasm-bindgen` export so `check()` is callable directly from JS.
+
+use std::path::PathBuf;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::linter::lint_source;
+use crate::settings::configuration::Configuration;
+use crate::settings::options::Options;
+use crate::settings::Settings;
+
+/// Lint `source` under the configuration described by `config_json` (the
+/// same shape as a `[tool.ruff]` pyproject.toml table, serialized as JSON),
+/// returning the resulting diagnostics as a JSON array of `Message`.
+#[wasm_bindgen]
+pub fn check(source: &str, config_json: &str) -> Result<String, String> {
+    let options: Options = serde_json::from_str(config_json).map_err(|e| e.to_string())?;
+    let configuration = Configuration::from_options(options, &Some(PathBuf::from(".")))
+        .map_err(|e| e.to_string())?;
+    let settings = Settings::from_configuration(configuration);
+
+    let messages = lint_source(source, &settings).map_err(|e| e.to_string())?;
+    serde_json::to_string(&messages).map_err(|e| e.to_string())
+}